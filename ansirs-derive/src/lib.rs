@@ -0,0 +1,120 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! # Ansirs Derive
+//!
+//! Derive macros for `ansirs`. Use them through the `derive` feature of `ansirs` instead of
+//! depending on this crate directly.
+
+#![warn(
+    clippy::pedantic,
+    clippy::all,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    rustdoc::all
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr};
+
+/// Derives a theme from a struct with named `Ansi` fields, each styled with an optional
+/// `#[style("...")]` attribute holding a style spec (see `Ansi::parse_spec`).
+///
+/// Generates:
+/// - `Default`, with every field set to its `#[style]`, or to `Ansi::new()` without one. It
+///   panics if a spec is invalid.
+/// - `From<&T> for ansirs::Theme`, keyed by field name.
+/// - `T::env_overrides(self) -> Self`, applying `ANSIRS_STYLE_<FIELD>` overrides (see
+///   `Theme::env_overrides`).
+#[proc_macro_derive(Theme, attributes(style))]
+pub fn derive_theme(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_theme(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_theme(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            input,
+            "Theme can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new_spanned(
+            input,
+            "Theme can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut names = Vec::new();
+    let mut defaults = Vec::new();
+    for field in &fields.named {
+        let Some(name) = &field.ident else {
+            continue;
+        };
+        let mut spec = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("style"))
+        {
+            if spec.is_some() {
+                return Err(Error::new_spanned(attr, "duplicate #[style] attribute"));
+            }
+            spec = Some(attr.parse_args::<LitStr>()?);
+        }
+
+        defaults.push(if let Some(spec) = spec {
+            let message = format!("invalid style for `{name}`");
+            quote! { ::ansirs::Ansi::parse_spec(#spec).expect(#message) }
+        } else {
+            quote! { ::ansirs::Ansi::new() }
+        });
+        names.push(name);
+    }
+    let keys = names.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#names: #defaults,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<&#ident #ty_generics> for ::ansirs::Theme
+            #where_clause
+        {
+            fn from(theme: &#ident #ty_generics) -> Self {
+                ::ansirs::Theme::new()
+                    #(.with(#keys, theme.#names))*
+            }
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Applies style overrides from `ANSIRS_STYLE_<FIELD>` environment variables, see
+            /// `ansirs::Theme::env_overrides`.
+            #[must_use]
+            #[allow(unused_variables)]
+            pub fn env_overrides(self) -> Self {
+                let theme = ::ansirs::Theme::from(&self).env_overrides();
+                Self {
+                    #(#names: theme.get(#keys).unwrap_or(self.#names),)*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,103 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{visible_width, Ansi, AnsiTokenizer, Color, Token};
+
+/// Gets the distinct colors used in `text`, e.g. the captured output of another program, the
+/// most used first. See [`color_counts`] for how colors are counted.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{analyze, Ansi, Color};
+/// let output = format!(
+///     "{} {}",
+///     Ansi::from_fg((0, 255, 0)).paint_text("ok"),
+///     Ansi::new().fg_indexed(1).paint_text("failed")
+/// );
+/// assert_eq!(
+///     analyze::extract_palette(&output),
+///     [Color::from_rgb(128, 0, 0), Color::from_rgb(0, 255, 0)]
+/// );
+/// ```
+#[must_use]
+pub fn extract_palette(text: &str) -> Vec<Color> {
+    color_counts(text)
+        .into_iter()
+        .map(|(color, _)| color)
+        .collect()
+}
+
+/// Counts how many visible columns of `text` each color is used for, the most used first.
+/// Colors used equally often are listed in the order they first appear.
+///
+/// Foreground, background and underline colors all count, so a column with a colored
+/// foreground and background counts for both. Indexed colors are reported with their
+/// standard xterm values, and text in the terminal's default colors is not counted.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{analyze, Color};
+/// let counts = analyze::color_counts("\x1b[31;44mab\x1b[0m c\x1b[34md");
+/// let blue = Color::from_rgb(0, 0, 128);
+/// assert_eq!(counts, [(blue, 3), (Color::from_rgb(128, 0, 0), 2)]);
+/// ```
+#[must_use]
+pub fn color_counts(text: &str) -> Vec<(Color, usize)> {
+    let mut counts: Vec<(Color, usize)> = Vec::new();
+    let mut active = Ansi::new();
+    for token in AnsiTokenizer::new(text) {
+        let Token::Text(text) = token else {
+            if let Some(params) = token.sgr_params() {
+                active.apply_sgr_params(params);
+            }
+            continue;
+        };
+
+        let width = visible_width(text);
+        if width == 0 {
+            continue;
+        }
+        let colors = [
+            active.foreground(),
+            active.background(),
+            active.underline_color(),
+        ];
+        for color in colors.into_iter().flatten().map(crate::AnsiColor::to_color) {
+            match counts.iter_mut().find(|(seen, _)| *seen == color) {
+                Some((_, count)) => *count += width,
+                None => counts.push((color, width)),
+            }
+        }
+    }
+    // A stable sort keeps the order of first appearance for equal counts.
+    counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn counts() {
+        assert!(color_counts("plain text").is_empty());
+        assert!(extract_palette("\x1b[31m\x1b[0m").is_empty());
+
+        let text = "\x1b[38;2;1;2;3mab\x1b[4;58;5;196mc\x1b[39;49m\nd\x1b]8;;x\x1b\\e";
+        assert_eq!(
+            color_counts(text),
+            [
+                (Color::from_rgb(1, 2, 3), 3),
+                (Color::from_rgb(255, 0, 0), 3),
+            ]
+        );
+        assert_eq!(
+            extract_palette("\x1b[32ma\x1b[31mb\x1b[32mc"),
+            [Color::from_rgb(0, 128, 0), Color::from_rgb(128, 0, 0)]
+        );
+    }
+}
@@ -4,7 +4,10 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::{AnsiFlags, Color, ToColor};
+use super::sgr::{parse_sgr, Sgr};
+use crate::{
+    AnsiColor, AnsiFlags, AnsiParseError, AnsiTokenizer, BlendSpace, CodeOrder, ToColor, Token,
+};
 
 /// Type for storing the configuration of an ANSI color code.
 ///
@@ -39,12 +42,24 @@ use crate::{AnsiFlags, Color, ToColor};
 /// # assert_eq!(style1.to_string(), "\x1b[4;38;2;100;200;100m");
 /// # assert_eq!(style2.to_string(), "\x1b[3;9;48;2;0;0;75m");
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// ### Debugging:
+/// The alternate [`Debug`](std::fmt::Debug) format (`{:#?}`) describes the style in words,
+/// the same way as [`debug_escapes`](crate::debug_escapes).
+/// ```
+/// # use ansirs::Ansi;
+/// let style = Ansi::from_fg((255, 0, 0)).bold();
+/// assert_eq!(format!("{style:#?}"), "Ansi⟨bold #ff0000⟩");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ansi {
-    fg: Option<Color>,
-    bg: Option<Color>,
+    fg: Option<AnsiColor>,
+    bg: Option<AnsiColor>,
+    ul: Option<AnsiColor>,
     flags: AnsiFlags,
+    #[cfg_attr(feature = "serde", serde(default))]
+    font: u8,
 }
 
 // "Static" Methods
@@ -58,7 +73,9 @@ impl Ansi {
         Self {
             fg: None,
             bg: None,
+            ul: None,
             flags: AnsiFlags::empty(),
+            font: 0,
         }
     }
 
@@ -68,9 +85,11 @@ impl Ansi {
     #[cfg_attr(feature = "trace", tracing::instrument)]
     pub fn from_fg<C: ToColor>(fg: C) -> Self {
         Self {
-            fg: Some(fg.to_color()),
+            fg: Some(AnsiColor::Rgb(fg.to_color())),
             bg: None,
+            ul: None,
             flags: AnsiFlags::empty(),
+            font: 0,
         }
     }
 
@@ -81,8 +100,10 @@ impl Ansi {
     pub fn from_bg<C: ToColor>(bg: C) -> Self {
         Self {
             fg: None,
-            bg: Some(bg.to_color()),
+            bg: Some(AnsiColor::Rgb(bg.to_color())),
+            ul: None,
             flags: AnsiFlags::empty(),
+            font: 0,
         }
     }
 
@@ -113,6 +134,96 @@ impl Ansi {
         "\x1b[0m"
     }
 
+    /// Parses a string made up of zero or more SGR escape sequences (as produced by this type's
+    /// [`Display`](std::fmt::Display) implementation) into an [`Ansi`]. The sequences are
+    /// applied in order, the same way a terminal would, so resets and `22`-`29` / `39` / `49` /
+    /// `59` codes are understood. Basic (`31`), bright (`91`), 256-color (`38;5;n`) and
+    /// truecolor (`38;2;r;g;b`) colors are all accepted, for the foreground, background and
+    /// underline.
+    ///
+    /// For any `style`, `Ansi::parse(&style.to_string())` returns `style` again.
+    ///
+    /// ## Errors
+    /// - [`AnsiParseError`] if the input contains anything besides SGR sequences, or a code
+    ///   that an `Ansi` cannot represent.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Ansi, AnsiColor};
+    /// let style = Ansi::from_fg((255, 128, 0)).bg_indexed(236).ul_indexed(1).underline();
+    /// assert_eq!(Ansi::parse(&style.to_string()), Ok(style));
+    ///
+    /// let parsed = Ansi::parse("\x1b[1;31m").unwrap();
+    /// assert_eq!(parsed, Ansi::new().bold().fg_indexed(1));
+    /// assert!(Ansi::parse("\x1b[1mtext").is_err());
+    /// ```
+    #[cfg_attr(feature = "trace", tracing::instrument)]
+    pub fn parse(input: &str) -> Result<Ansi, AnsiParseError> {
+        let mut ansi = Self::new();
+        for token in AnsiTokenizer::new(input) {
+            match (token, token.sgr_params()) {
+                (_, Some(params)) => {
+                    for op in parse_sgr(params) {
+                        ansi.apply_sgr(op)?;
+                    }
+                }
+                (Token::Text(text), _) => {
+                    return Err(AnsiParseError::UnexpectedText(text.to_string()))
+                }
+                (Token::Esc(seq), _)
+                    if seq == "\x1b" || seq.starts_with("\x1b[") || seq.starts_with("\x1b]") =>
+                {
+                    return Err(AnsiParseError::Incomplete)
+                }
+                (sequence, None) => {
+                    return Err(AnsiParseError::UnsupportedSequence(
+                        sequence.as_str().to_string(),
+                    ))
+                }
+            }
+        }
+        Ok(ansi)
+    }
+
+    /// Applies a single SGR operation to this `Ansi`.
+    pub(super) fn apply_sgr(&mut self, op: Sgr) -> Result<(), AnsiParseError> {
+        match op {
+            Sgr::Unknown(u16::MAX) => Err(AnsiParseError::InvalidParameter),
+            Sgr::Unknown(code) => Err(AnsiParseError::UnsupportedCode(code)),
+            op => {
+                *self = self.with_sgr(op);
+                Ok(())
+            }
+        }
+    }
+
+    /// Applies a single SGR operation to this `Ansi` and returns the result, in a `const fn`.
+    /// Unknown operations are ignored.
+    pub(super) const fn with_sgr(mut self, op: Sgr) -> Self {
+        match op {
+            Sgr::Reset => self = Self::new(),
+            Sgr::Set(flags) => self.flags = self.flags.insert_to(flags),
+            Sgr::Unset(flags) => self.flags = self.flags.remove_to(flags),
+            Sgr::Fg(color) => self.fg = Some(color),
+            Sgr::Bg(color) => self.bg = Some(color),
+            Sgr::UnderlineColor(color) => self.ul = Some(color),
+            Sgr::DefaultFg => self.fg = None,
+            Sgr::DefaultBg => self.bg = None,
+            Sgr::DefaultUnderlineColor => self.ul = None,
+            Sgr::Font(font) => self.font = font,
+            Sgr::Unknown(_) => {}
+        }
+        self
+    }
+
+    /// Applies the parameters of one SGR sequence to this `Ansi`, the way a terminal would.
+    /// Codes that an `Ansi` cannot represent are skipped.
+    pub(crate) fn apply_sgr_params(&mut self, params: &str) {
+        for op in parse_sgr(params) {
+            let _ = self.apply_sgr(op);
+        }
+    }
+
     /// Simple parser implementation which accepts a string containing ansi escape codes
     /// ***OR*** text surrounded by ansi escape codes, and attempts to extract the styling
     /// into an [`Ansi`] instance.
@@ -156,7 +267,7 @@ impl Ansi {
                 } else if *after == 5 {
                     // This is a single digit 256-color code
                     if let Some(code) = ansi_nums.get(fg + 2) {
-                        ansi = ansi.fg(Color::ansi_256_to_color(*code));
+                        ansi = ansi.fg_indexed(*code);
                         // Remove the 38, the 5, and the color code
                         let _removed = ansi_nums.drain(fg..fg + 3);
                     } else {
@@ -191,7 +302,7 @@ impl Ansi {
                 } else if *after == 5 {
                     // This is a single digit 256-color code
                     if let Some(code) = ansi_nums.get(bg + 2) {
-                        ansi = ansi.bg(Color::ansi_256_to_color(*code));
+                        ansi = ansi.bg_indexed(*code);
                         // Remove the 48, the 5, and the color code.
                         let _removed = ansi_nums.drain(bg..bg + 3);
                     } else {
@@ -212,10 +323,12 @@ impl Ansi {
         for num in ansi_nums {
             match num {
                 1 => ansi = ansi.bold(),
+                2 => ansi = ansi.dim(),
                 3 => ansi = ansi.italic(),
                 4 => ansi = ansi.underline(),
                 5 => ansi = ansi.blink(),
                 7 => ansi = ansi.reverse(),
+                8 => ansi = ansi.conceal(),
                 9 => ansi = ansi.strike(),
                 _ => eprintln!("Unknown ANSI flag: {num}"),
             }
@@ -229,20 +342,55 @@ impl Ansi {
 impl Ansi {
     /// Clear the Ansi object entirely.
     #[must_use]
-    #[allow(clippy::needless_update)]
     pub const fn clear(self) -> Self {
         Self {
             fg: None,
             bg: None,
+            ul: None,
             flags: AnsiFlags::empty(),
-            ..self
+            font: 0,
         }
     }
 
+    /// Gets the foreground color of this `Ansi`, if any.
+    #[must_use]
+    pub const fn foreground(&self) -> Option<AnsiColor> {
+        self.fg
+    }
+
+    /// Gets the background color of this `Ansi`, if any.
+    #[must_use]
+    pub const fn background(&self) -> Option<AnsiColor> {
+        self.bg
+    }
+
+    /// Gets the underline color of this `Ansi`, if any.
+    #[must_use]
+    pub const fn underline_color(&self) -> Option<AnsiColor> {
+        self.ul
+    }
+
+    /// Gets the flags (bold, italic, etc.) of this `Ansi`.
+    #[must_use]
+    pub const fn flags(&self) -> AnsiFlags {
+        self.flags
+    }
+
+    /// Gets the font of this `Ansi`: `0` for the primary (default) font, `1..=9` for the
+    /// alternate fonts and `10` for Fraktur.
+    #[must_use]
+    pub const fn font_number(&self) -> u8 {
+        self.font
+    }
+
     /// Returns `true` if this `Ansi` has no styling.
     #[must_use]
     pub const fn is_default(&self) -> bool {
-        self.fg.is_none() && self.bg.is_none() && self.flags.is_empty()
+        self.fg.is_none()
+            && self.bg.is_none()
+            && self.ul.is_none()
+            && self.flags.is_empty()
+            && self.font == 0
     }
 
     /// Builder function to set the foreground color.
@@ -251,7 +399,16 @@ impl Ansi {
     #[cfg_attr(feature = "trace", tracing::instrument)]
     pub fn fg<C: ToColor>(self, fg: C) -> Self {
         Self {
-            fg: Some(fg.to_color()),
+            fg: Some(AnsiColor::Rgb(fg.to_color())),
+            ..self
+        }
+    }
+
+    /// Builder function to set the foreground to one of the terminal's 256 indexed colors.
+    #[must_use]
+    pub const fn fg_indexed(self, idx: u8) -> Self {
+        Self {
+            fg: Some(AnsiColor::Indexed(idx)),
             ..self
         }
     }
@@ -268,7 +425,16 @@ impl Ansi {
     #[cfg_attr(feature = "trace", tracing::instrument)]
     pub fn bg<C: ToColor>(self, bg: C) -> Self {
         Self {
-            bg: Some(bg.to_color()),
+            bg: Some(AnsiColor::Rgb(bg.to_color())),
+            ..self
+        }
+    }
+
+    /// Builder function to set the background to one of the terminal's 256 indexed colors.
+    #[must_use]
+    pub const fn bg_indexed(self, idx: u8) -> Self {
+        Self {
+            bg: Some(AnsiColor::Indexed(idx)),
             ..self
         }
     }
@@ -279,6 +445,33 @@ impl Ansi {
         Self { bg: None, ..self }
     }
 
+    /// Builder function to set the underline color. Terminals that do not support colored
+    /// underlines ignore it and keep using the foreground color.
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    #[cfg_attr(feature = "trace", tracing::instrument)]
+    pub fn ul<C: ToColor>(self, ul: C) -> Self {
+        Self {
+            ul: Some(AnsiColor::Rgb(ul.to_color())),
+            ..self
+        }
+    }
+
+    /// Builder function to set the underline color to one of the terminal's 256 indexed colors.
+    #[must_use]
+    pub const fn ul_indexed(self, idx: u8) -> Self {
+        Self {
+            ul: Some(AnsiColor::Indexed(idx)),
+            ..self
+        }
+    }
+
+    /// Builder function to clear the underline color.
+    #[must_use]
+    pub const fn clear_ul(self) -> Self {
+        Self { ul: None, ..self }
+    }
+
     /// Builder function to toggle whether the color is bold.
     #[must_use]
     pub const fn bold(self) -> Self {
@@ -288,6 +481,15 @@ impl Ansi {
         }
     }
 
+    /// Builder function to toggle whether the color is dimmed / faint.
+    #[must_use]
+    pub const fn dim(self) -> Self {
+        Self {
+            flags: self.flags.toggle_to(AnsiFlags::DIM),
+            ..self
+        }
+    }
+
     /// Builder function to toggle whether the color is underlined.
     #[must_use]
     pub const fn underline(self) -> Self {
@@ -333,100 +535,261 @@ impl Ansi {
         }
     }
 
-    /// Creates a string from this `Ansi` using a `String` to store temporary data.
+    /// Builder function to toggle concealed (hidden) text. Terminals that support it draw the
+    /// text invisibly while keeping it selectable and copyable, see
+    /// [`secret`](crate::secret) for a helper built on it.
     #[must_use]
-    #[cfg_attr(feature = "trace", tracing::instrument)]
-    fn build_ansi_string(&self) -> String {
-        use std::fmt::Write;
-
-        if self.is_default() {
-            return String::new();
+    pub const fn conceal(self) -> Self {
+        Self {
+            flags: self.flags.toggle_to(AnsiFlags::CONCEAL),
+            ..self
         }
+    }
 
-        let mut modified = false;
-        let mut ansi = String::with_capacity(20);
+    /// Builder function to replace all the flags (bold, italic, etc.) of this `Ansi`.
+    #[must_use]
+    pub const fn with_flags(self, flags: AnsiFlags) -> Self {
+        Self { flags, ..self }
+    }
 
-        if self.flags.contains(AnsiFlags::BOLD) {
-            ansi.push('1');
-            modified = true;
+    /// Builder function to select a font (SGR `10` to `20`): `0` is the primary font, `1..=9`
+    /// are the alternate fonts and `10` is Fraktur. Larger values are treated as `10`.
+    ///
+    /// Few terminals implement font switching, most simply ignore it.
+    ///
+    /// ```
+    /// # use ansirs::Ansi;
+    /// assert_eq!(Ansi::new().font(3).to_string(), "\x1b[13m");
+    /// assert_eq!(Ansi::new().fraktur().to_string(), "\x1b[20m");
+    /// assert_eq!(Ansi::parse("\x1b[1;13m"), Ok(Ansi::new().bold().font(3)));
+    /// ```
+    #[must_use]
+    pub const fn font(self, font: u8) -> Self {
+        Self {
+            font: if font > 10 { 10 } else { font },
+            ..self
         }
+    }
 
-        if self.flags.contains(AnsiFlags::ITALIC) {
-            if modified {
-                ansi.push_str(";3");
-            } else {
-                ansi.push('3');
-                modified = true;
+    /// Builder function to select the Fraktur font, same as `font(10)`.
+    #[must_use]
+    pub const fn fraktur(self) -> Self {
+        self.font(10)
+    }
+
+    /// Layers `top` over this style: colors set in `top` replace the ones in this style, unset
+    /// ones are kept, and the flags of both are combined.
+    ///
+    /// ```
+    /// # use ansirs::Ansi;
+    /// let base = Ansi::from_fg((100, 100, 100)).bg((0, 0, 0)).dim();
+    /// assert_eq!(
+    ///     base.merge(Ansi::red().bold()),
+    ///     Ansi::red().bg((0, 0, 0)).dim().bold()
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn merge(self, top: Ansi) -> Self {
+        const fn pick(base: Option<AnsiColor>, top: Option<AnsiColor>) -> Option<AnsiColor> {
+            match top {
+                Some(_) => top,
+                None => base,
             }
         }
 
-        if self.flags.contains(AnsiFlags::UNDERLINE) {
-            if modified {
-                ansi.push_str(";4");
-            } else {
-                ansi.push('4');
-                modified = true;
+        Self {
+            fg: pick(self.fg, top.fg),
+            bg: pick(self.bg, top.bg),
+            ul: pick(self.ul, top.ul),
+            flags: self.flags.union(top.flags),
+            font: if top.font == 0 { self.font } else { top.font },
+        }
+    }
+
+    /// Interpolates between this style and `other`, where `t` is clamped to `0.0..=1.0`.
+    ///
+    /// Colors are blended (as RGB) when both styles have them; when only one side has a color,
+    /// it switches (like the flags) at `t = 0.5`.
+    #[must_use]
+    pub fn lerp(&self, other: &Ansi, t: f32) -> Self {
+        self.lerp_in(other, t, BlendSpace::Srgb)
+    }
+
+    /// Like [`Ansi::lerp`], but blending colors in the given color `space`.
+    #[must_use]
+    pub fn lerp_in(&self, other: &Ansi, t: f32, space: BlendSpace) -> Self {
+        let blend = |a: Option<AnsiColor>, b: Option<AnsiColor>, t: f32| match (a, b) {
+            (Some(a), Some(b)) if a != b && t > 0.0 && t < 1.0 => {
+                Some(AnsiColor::Rgb(a.to_color().lerp_in(b.to_color(), t, space)))
             }
+            _ if t < 0.5 => a,
+            _ => b,
+        };
+
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        Self {
+            fg: blend(self.fg, other.fg, t),
+            bg: blend(self.bg, other.bg, t),
+            ul: blend(self.ul, other.ul, t),
+            flags: if t < 0.5 { self.flags } else { other.flags },
+            font: if t < 0.5 { self.font } else { other.font },
         }
+    }
 
-        if self.flags.contains(AnsiFlags::BLINK) {
-            if modified {
-                ansi.push_str(";5");
-            } else {
-                ansi.push('5');
-                modified = true;
+    /// Creates `count` styles fading from this style to `to`, including both ends.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn fade_steps(&self, to: &Ansi, count: usize) -> Vec<Self> {
+        match count {
+            0 => Vec::new(),
+            1 => vec![*self],
+            _ => (0..count)
+                .map(|idx| self.lerp(to, idx as f32 / (count - 1) as f32))
+                .collect(),
+        }
+    }
+
+    /// Writes the `;` separated list of SGR parameters for this `Ansi` to `out`, without the
+    /// surrounding escape prefix and suffix, in the [`code_order`](crate::code_order) of the
+    /// current thread.
+    pub(crate) fn write_codes<W: std::fmt::Write + ?Sized>(&self, out: &mut W) -> std::fmt::Result {
+        let mut modified = false;
+        match crate::code_order() {
+            CodeOrder::Canonical => {
+                self.write_attribute_codes(out, &mut modified)?;
+                self.write_color_codes(out, &mut modified)
+            }
+            CodeOrder::ColorsFirst => {
+                self.write_color_codes(out, &mut modified)?;
+                self.write_attribute_codes(out, &mut modified)
             }
         }
+    }
 
-        if self.flags.contains(AnsiFlags::REVERSE) {
-            if modified {
-                ansi.push_str(";7");
-            } else {
-                ansi.push('7');
-                modified = true;
+    /// Writes the parameters for the flags and font of this `Ansi` to `out`, preceded by a `;`
+    /// if `modified` says something was written before them.
+    fn write_attribute_codes<W: std::fmt::Write + ?Sized>(
+        &self,
+        out: &mut W,
+        modified: &mut bool,
+    ) -> std::fmt::Result {
+        const FLAG_CODES: [(AnsiFlags, &str); 8] = [
+            (AnsiFlags::BOLD, "1"),
+            (AnsiFlags::DIM, "2"),
+            (AnsiFlags::ITALIC, "3"),
+            (AnsiFlags::UNDERLINE, "4"),
+            (AnsiFlags::BLINK, "5"),
+            (AnsiFlags::REVERSE, "7"),
+            (AnsiFlags::CONCEAL, "8"),
+            (AnsiFlags::STRIKE, "9"),
+        ];
+
+        for (flag, code) in FLAG_CODES {
+            if self.flags.contains(flag) {
+                if *modified {
+                    out.write_char(';')?;
+                }
+                out.write_str(code)?;
+                *modified = true;
             }
         }
 
-        if self.flags.contains(AnsiFlags::STRIKE) {
-            if modified {
-                ansi.push_str(";9");
-            } else {
-                ansi.push('9');
-                modified = true;
+        if self.font != 0 {
+            if *modified {
+                out.write_char(';')?;
             }
+            write!(out, "{}", 10 + self.font)?;
+            *modified = true;
         }
+        Ok(())
+    }
 
-        if let Some(color) = self.fg {
-            let (r, g, b) = color.rgb();
-            if modified {
-                ansi.push_str(";38;2;");
-            } else {
-                ansi.push_str("38;2;");
+    /// Writes the parameters for the colors of this `Ansi` to `out`, preceded by a `;` if
+    /// `modified` says something was written before them.
+    fn write_color_codes<W: std::fmt::Write + ?Sized>(
+        &self,
+        out: &mut W,
+        modified: &mut bool,
+    ) -> std::fmt::Result {
+        for (color, base) in [(self.fg, 38), (self.bg, 48), (self.ul, 58)] {
+            if let Some(color) = color {
+                if *modified {
+                    out.write_char(';')?;
+                }
+                color.write_codes(base, out)?;
+                *modified = true;
             }
+        }
+        Ok(())
+    }
 
-            write!(ansi, "{r};{g};{b}").expect("Failed to write! to string");
-            // ansi.push_str(&format!("{};{};{}", r, g, b));
-            modified = true;
+    /// Writes the escape sequence for this `Ansi` to `out`, or nothing if this is the default
+    /// style.
+    pub(crate) fn write_sequence<W: std::fmt::Write + ?Sized>(
+        &self,
+        out: &mut W,
+    ) -> std::fmt::Result {
+        if self.is_default() {
+            return Ok(());
         }
+        out.write_str(Self::PREFIX)?;
+        self.write_codes(out)?;
+        out.write_str(Self::SUFFIX)
+    }
 
-        if let Some(c) = self.bg {
-            let (r, g, b) = c.rgb();
-            if modified {
-                ansi.push_str(";48;2;");
-            } else {
-                ansi.push_str("48;2;");
-            }
-            write!(ansi, "{r};{g};{b}").expect("Failed to write! to string");
-            // ansi.push_str(&format!("{};{};{}", r, g, b));
-            modified = true;
+    /// Writes the shortest escape sequence that switches the terminal from this style to `to`:
+    /// either one that only turns off and on what differs, or a reset followed by `to`. Nothing
+    /// is written when the styles are the same.
+    pub(crate) fn write_transition<W: std::fmt::Write + ?Sized>(
+        &self,
+        to: &Ansi,
+        out: &mut W,
+    ) -> std::fmt::Result {
+        if self == to {
+            return Ok(());
+        }
+        if to.is_default() {
+            return out.write_str(Self::reset());
         }
 
-        // This seems like it will be unnecessary, I can't even get the branch to hit during testing.
-        if !modified {
-            return String::new();
+        let removed = Self {
+            fg: if to.fg.is_none() { self.fg } else { None },
+            bg: if to.bg.is_none() { self.bg } else { None },
+            ul: if to.ul.is_none() { self.ul } else { None },
+            flags: self.flags - to.flags,
+            font: if to.font == 0 { self.font } else { 0 },
+        };
+        let mut added = Self {
+            fg: if to.fg == self.fg { None } else { to.fg },
+            bg: if to.bg == self.bg { None } else { to.bg },
+            ul: if to.ul == self.ul { None } else { to.ul },
+            flags: to.flags - self.flags,
+            font: if to.font == self.font { 0 } else { to.font },
+        };
+        // Bold and dim are turned off by the same code, so whichever one stays on is set again.
+        let intensity = AnsiFlags::BOLD | AnsiFlags::DIM;
+        if removed.flags.intersects(intensity) {
+            added.flags.insert(to.flags & intensity);
         }
 
-        format!("{}{}{}", Self::PREFIX, ansi, Self::SUFFIX)
+        let mut changes = String::new();
+        removed.write_undo_codes(&mut changes)?;
+        if !added.is_default() {
+            if !changes.is_empty() {
+                changes.push(';');
+            }
+            added.write_codes(&mut changes)?;
+        }
+        let mut full = String::from("0;");
+        to.write_codes(&mut full)?;
+
+        out.write_str(Self::PREFIX)?;
+        out.write_str(if changes.len() <= full.len() {
+            &changes
+        } else {
+            &full
+        })?;
+        out.write_str(Self::SUFFIX)
     }
 
     /// Convenience function that uses this [`Ansi`] to style the given [`text`],
@@ -435,11 +798,55 @@ impl Ansi {
     #[must_use]
     #[cfg_attr(feature = "trace", tracing::instrument)]
     pub fn paint_text(&self, text: &str) -> String {
-        if self.is_default() {
+        if self.is_default() || !crate::styles_enabled() {
             return text.to_string();
         }
 
-        format!("{}{}{}", self.build_ansi_string(), text, Self::reset())
+        super::with_sequence(*self, |sequence| {
+            let mut out = String::with_capacity(sequence.len() + text.len() + 4);
+            out.push_str(sequence);
+            out.push_str(text);
+            out.push_str(Self::reset());
+            out
+        })
+    }
+
+    /// Gets the escape sequence for this [`Ansi`] as bytes, the same as its
+    /// [`Display`](std::fmt::Display) output. This is empty for the default style.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Ansi;
+    /// assert_eq!(Ansi::new().bold().escape_bytes(), b"\x1b[1m");
+    /// assert!(Ansi::new().escape_bytes().is_empty());
+    /// ```
+    #[must_use]
+    pub fn escape_bytes(&self) -> Vec<u8> {
+        super::with_sequence(*self, |sequence| sequence.as_bytes().to_vec())
+    }
+
+    /// Writes `bytes` to `out` styled by this [`Ansi`], like [`Ansi::paint_text`] but for byte
+    /// streams, e.g. a PTY proxy or a logger, whose data does not have to be valid UTF-8.
+    ///
+    /// ## Errors
+    /// Any error from writing to `out`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Ansi;
+    /// let mut out = Vec::new();
+    /// Ansi::new().bold().write_bytes(&mut out, b"\xffraw")?;
+    /// assert_eq!(out, b"\x1b[1m\xffraw\x1b[0m");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn write_bytes(&self, out: &mut impl std::io::Write, bytes: &[u8]) -> std::io::Result<()> {
+        if self.is_default() || !crate::styles_enabled() || bytes.is_empty() {
+            return out.write_all(bytes);
+        }
+
+        super::with_sequence(*self, |sequence| out.write_all(sequence.as_bytes()))?;
+        out.write_all(bytes)?;
+        out.write_all(Self::reset().as_bytes())
     }
 }
 
@@ -449,15 +856,40 @@ impl Default for Ansi {
     }
 }
 
+impl std::fmt::Debug for Ansi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "Ansi⟨{}⟩", super::describe::describe_ansi(self))
+        } else {
+            f.debug_struct("Ansi")
+                .field("fg", &self.fg)
+                .field("bg", &self.bg)
+                .field("ul", &self.ul)
+                .field("flags", &self.flags)
+                .field("font", &self.font)
+                .finish()
+        }
+    }
+}
+
+impl std::str::FromStr for Ansi {
+    type Err = AnsiParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
 impl std::fmt::Display for Ansi {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.build_ansi_string())
+        self.write_sequence(f)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Color;
     use pretty_assertions::{assert_eq, assert_ne};
 
     #[test]
@@ -504,6 +936,8 @@ mod tests {
         assert_eq!(a.to_string(), "\u{1b}[5m");
         let a = Ansi::new().bold();
         assert_eq!(a.to_string(), "\u{1b}[1m");
+        let a = Ansi::new().dim();
+        assert_eq!(a.to_string(), "\u{1b}[2m");
         let a = Ansi::new().italic();
         assert_eq!(a.to_string(), "\u{1b}[3m");
         let a = Ansi::new().underline();
@@ -607,14 +1041,213 @@ mod tests {
             .underline();
 
         assert_eq!(all_but_color.to_string(), "\u{1b}[1;3;4;5;7;9m");
+        assert_eq!(
+            Ansi::parse_ansi_text("\u{1b}[1;2m"),
+            Some(Ansi::new().bold().dim())
+        );
         assert_eq!(
             Ansi::parse_ansi_text("\u{1b}[1;3;4;5;7;9m"),
             Some(all_but_color)
         );
     }
 
+    #[test]
+    fn indexed_and_underline_colors() {
+        let ansi = Ansi::new().fg_indexed(9).bg_indexed(200).ul((1, 2, 3));
+        assert_eq!(ansi.to_string(), "\u{1b}[38;5;9;48;5;200;58;2;1;2;3m");
+        assert_eq!(
+            ansi.ul_indexed(4).to_string(),
+            "\u{1b}[38;5;9;48;5;200;58;5;4m"
+        );
+        assert!(ansi.clear_ul().clear_fg().clear_bg().is_default());
+        assert!(ansi.clear().is_default());
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ansi::parse(""), Ok(Ansi::new()));
+        assert_eq!(
+            Ansi::parse("\u{1b}[1;4;31;102;58;5;3m"),
+            Ok(Ansi::new()
+                .bold()
+                .underline()
+                .fg_indexed(1)
+                .bg_indexed(10)
+                .ul_indexed(3))
+        );
+        assert_eq!(
+            Ansi::parse("\u{1b}[1;3m\u{1b}[22;38;2;1;2;3m"),
+            Ok(Ansi::from_fg((1, 2, 3)).italic())
+        );
+        assert_eq!(
+            "\u{1b}[1m\u{1b}[0m\u{1b}[3m".parse::<Ansi>(),
+            Ok(Ansi::new().italic())
+        );
+        assert_eq!(
+            Ansi::parse("\u{1b}[31;39;44;49;58;5;1;59m"),
+            Ok(Ansi::new())
+        );
+
+        assert_eq!(
+            Ansi::parse("\u{1b}[1mx"),
+            Err(AnsiParseError::UnexpectedText("x".to_string()))
+        );
+        assert_eq!(Ansi::parse("\u{1b}[1"), Err(AnsiParseError::Incomplete));
+        assert_eq!(
+            Ansi::parse("\u{1b}[2K"),
+            Err(AnsiParseError::UnsupportedSequence("\u{1b}[2K".to_string()))
+        );
+        assert_eq!(
+            Ansi::parse("\u{1b}[1;99999;3m"),
+            Err(AnsiParseError::InvalidParameter)
+        );
+        assert_eq!(
+            Ansi::parse("\u{1b}[6m"),
+            Err(AnsiParseError::UnsupportedCode(6))
+        );
+    }
+
+    #[test]
+    fn fonts() {
+        let style = Ansi::red().bold().font(2);
+        assert_eq!(style.font_number(), 2);
+        assert_eq!(style.to_string(), "\x1b[1;12;38;2;255;0;0m");
+        assert_eq!(Ansi::parse(&style.to_string()), Ok(style));
+        assert_eq!(Ansi::parse("\x1b[20;10m"), Ok(Ansi::new()));
+        assert_eq!(Ansi::new().font(99), Ansi::new().fraktur());
+        assert!(!Ansi::new().font(1).is_default());
+        assert!(Ansi::new().font(1).font(0).is_default());
+        assert_eq!(Ansi::new().font(1).clear(), Ansi::new());
+        assert_eq!(Ansi::new().font(4).merge(Ansi::new()), Ansi::new().font(4));
+        assert_eq!(format!("{:#?}", Ansi::new().font(4)), "Ansi⟨font-4⟩");
+    }
+
+    #[test]
+    fn merge() {
+        let base = Ansi::new().fg_indexed(8).ul((1, 2, 3)).italic();
+        assert_eq!(base.merge(Ansi::new()), base);
+        assert_eq!(Ansi::new().merge(base), base);
+        assert_eq!(
+            base.merge(Ansi::from_bg((4, 5, 6)).fg_indexed(9)),
+            Ansi::new()
+                .fg_indexed(9)
+                .bg((4, 5, 6))
+                .ul((1, 2, 3))
+                .italic()
+        );
+    }
+
+    #[test]
+    fn lerp() {
+        let from = Ansi::from_fg((0, 0, 0)).bg((100, 100, 100)).bold();
+        let to = Ansi::from_fg((200, 100, 0)).italic();
+
+        assert_eq!(from.lerp(&to, 0.0), from);
+        assert_eq!(from.lerp(&to, 1.0), to);
+        assert_eq!(
+            from.lerp(&to, 0.25),
+            Ansi::from_fg((50, 25, 0)).bg((100, 100, 100)).bold()
+        );
+        assert_eq!(from.lerp(&to, 0.5), Ansi::from_fg((100, 50, 0)).italic());
+        assert_eq!(from.lerp(&to, -3.0), from);
+    }
+
+    #[test]
+    fn lerp_linear() {
+        let from = Ansi::from_fg((0, 0, 0)).bg((255, 0, 0));
+        let to = Ansi::from_fg((255, 255, 255)).bg((0, 0, 255));
+        assert_eq!(
+            from.lerp_in(&to, 0.5, BlendSpace::Linear),
+            Ansi::from_fg((188, 188, 188)).bg((188, 0, 188))
+        );
+        assert_eq!(from.lerp_in(&to, 0.0, BlendSpace::Linear), from);
+    }
+
+    #[test]
+    fn fade_steps() {
+        let from = Ansi::from_fg((0, 0, 0));
+        let to = Ansi::from_fg((100, 100, 100));
+        assert!(from.fade_steps(&to, 0).is_empty());
+        assert_eq!(from.fade_steps(&to, 1), vec![from]);
+        assert_eq!(
+            from.fade_steps(&to, 3),
+            vec![from, Ansi::from_fg((50, 50, 50)), to]
+        );
+    }
+
+    #[test]
+    fn getters_and_debug() {
+        let ansi = Ansi::from_fg((1, 2, 3)).bg((4, 5, 6)).italic();
+        assert_eq!(
+            ansi.foreground(),
+            Some(AnsiColor::Rgb(Color::from_rgb(1, 2, 3)))
+        );
+        assert_eq!(
+            ansi.background(),
+            Some(AnsiColor::Rgb(Color::from_rgb(4, 5, 6)))
+        );
+        assert_eq!(ansi.underline_color(), None);
+        assert_eq!(ansi.flags(), AnsiFlags::ITALIC);
+        assert_eq!(
+            format!("{ansi:?}"),
+            "Ansi { fg: Some(Rgb(Color(1, 2, 3))), bg: Some(Rgb(Color(4, 5, 6))), ul: None, flags: ITALIC, font: 0 }"
+        );
+        assert_eq!(format!("{ansi:#?}"), "Ansi⟨italic #010203 on #040506⟩");
+        assert_eq!(format!("{:#?}", Ansi::new()), "Ansi⟨default⟩");
+    }
+
+    #[test]
+    fn bytes() {
+        let style = Ansi::from_fg((1, 2, 3)).italic();
+        assert_eq!(style.escape_bytes(), style.to_string().into_bytes());
+
+        let mut out = Vec::new();
+        style.write_bytes(&mut out, b"").unwrap();
+        Ansi::new().write_bytes(&mut out, b"a").unwrap();
+        style.write_bytes(&mut out, b"\xfe").unwrap();
+        assert_eq!(out, b"a\x1b[3;38;2;1;2;3m\xfe\x1b[0m");
+
+        let _plain = crate::suppress_styles();
+        out.clear();
+        style.write_bytes(&mut out, b"b").unwrap();
+        assert_eq!(out, b"b");
+    }
+
+    #[test]
+    fn transitions() {
+        fn transition(from: Ansi, to: Ansi) -> String {
+            let mut out = String::new();
+            from.write_transition(&to, &mut out).unwrap();
+            out
+        }
+
+        let bold = Ansi::new().bold();
+        assert_eq!(transition(bold, bold), "");
+        assert_eq!(transition(Ansi::new(), bold), "\x1b[1m");
+        assert_eq!(transition(bold, bold.italic()), "\x1b[3m");
+        assert_eq!(transition(Ansi::red().bold(), Ansi::new()), "\x1b[0m");
+        assert_eq!(transition(bold.dim(), Ansi::new().dim()), "\x1b[0;2m");
+        assert_eq!(
+            transition(bold.dim().italic(), Ansi::new().dim().italic()),
+            "\x1b[22;2m"
+        );
+        assert_eq!(
+            transition(Ansi::red().bold(), bold.fg_indexed(1).italic()),
+            "\x1b[3;38;5;1m"
+        );
+        assert_eq!(transition(bold.font(2), bold), "\x1b[10m");
+        assert_eq!(
+            transition(
+                Ansi::new().italic().underline().strike().fg_indexed(1),
+                bold
+            ),
+            "\x1b[0;1m"
+        );
+    }
+
     #[test]
     fn color_inputs() {
+        #[cfg(feature = "named-colors")]
         let _red = Ansi::from_fg(crate::Colors::Red);
         let _green = Ansi::from_fg((0, 255, 0));
         let _blue = Ansi::from_fg(Color::from_hex("#0000ff").unwrap());
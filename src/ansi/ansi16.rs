@@ -0,0 +1,334 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{Ansi, AnsiColor, AnsiFlags, CodeOrder, Color};
+
+/// One of the 16 basic terminal colors, written as `30`-`37` (`40`-`47` for backgrounds) and
+/// `90`-`97` (`100`-`107`) for the bright variants.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi16, Color};
+/// assert_eq!(Ansi16::Red.bright(), Ansi16::BrightRed);
+/// assert_eq!(Ansi16::nearest(Color::from_rgb(250, 10, 10)), Ansi16::BrightRed);
+/// ```
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Ansi16 {
+    Black = 0,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+}
+
+impl Ansi16 {
+    /// All 16 colors, in palette order.
+    pub const ALL: [Ansi16; 16] = [
+        Ansi16::Black,
+        Ansi16::Red,
+        Ansi16::Green,
+        Ansi16::Yellow,
+        Ansi16::Blue,
+        Ansi16::Magenta,
+        Ansi16::Cyan,
+        Ansi16::White,
+        Ansi16::BrightBlack,
+        Ansi16::BrightRed,
+        Ansi16::BrightGreen,
+        Ansi16::BrightYellow,
+        Ansi16::BrightBlue,
+        Ansi16::BrightMagenta,
+        Ansi16::BrightCyan,
+        Ansi16::BrightWhite,
+    ];
+
+    /// Gets the color with the given palette index, or `None` if it is not in `0..=15`.
+    #[must_use]
+    pub const fn from_index(idx: u8) -> Option<Self> {
+        if idx < 16 {
+            Some(Self::ALL[idx as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Gets the palette index (`0..=15`) of this color.
+    #[must_use]
+    pub const fn index(self) -> u8 {
+        self as u8
+    }
+
+    /// Gets the bright variant of this color. Bright colors are returned unchanged.
+    #[must_use]
+    pub const fn bright(self) -> Self {
+        Self::ALL[(self.index() | 8) as usize]
+    }
+
+    /// Gets the normal variant of this color. Normal colors are returned unchanged.
+    #[must_use]
+    pub const fn normal(self) -> Self {
+        Self::ALL[(self.index() & 7) as usize]
+    }
+
+    /// Checks if this is one of the bright colors.
+    #[must_use]
+    pub const fn is_bright(self) -> bool {
+        self.index() >= 8
+    }
+
+    /// Resolves this color to an RGB [`Color`], using the standard xterm values.
+    #[must_use]
+    pub const fn to_color(self) -> Color {
+        Color::ansi_256_to_color(self.index())
+    }
+
+    /// Finds the color closest to `color` (by distance in RGB space).
+    #[must_use]
+    pub fn nearest(color: Color) -> Self {
+        let (r, g, b) = color.rgb();
+        let distance = |candidate: &Ansi16| {
+            let (cr, cg, cb) = candidate.to_color().rgb();
+            [(r, cr), (g, cg), (b, cb)]
+                .into_iter()
+                .map(|(a, b)| u32::from(a.abs_diff(b)).pow(2))
+                .sum::<u32>()
+        };
+        Self::ALL
+            .into_iter()
+            .min_by_key(distance)
+            .unwrap_or(Ansi16::Black)
+    }
+
+    /// Writes the SGR parameter selecting this color to `out`, where `base` is `30` for the
+    /// foreground or `40` for the background.
+    fn write_code<W: std::fmt::Write + ?Sized>(self, base: u8, out: &mut W) -> std::fmt::Result {
+        if self.is_bright() {
+            write!(out, "{}", base + 60 + self.index() - 8)
+        } else {
+            write!(out, "{}", base + self.index())
+        }
+    }
+}
+
+impl From<Ansi16> for AnsiColor {
+    fn from(color: Ansi16) -> Self {
+        AnsiColor::Indexed(color.index())
+    }
+}
+
+impl From<AnsiColor> for Ansi16 {
+    fn from(color: AnsiColor) -> Self {
+        match color {
+            AnsiColor::Indexed(idx) => {
+                Ansi16::from_index(idx).unwrap_or_else(|| Ansi16::nearest(color.to_color()))
+            }
+            AnsiColor::Rgb(color) => Ansi16::nearest(color),
+        }
+    }
+}
+
+/// How bright colors are written by the 16-color renderer, see
+/// [`with_bright_policy`](crate::with_bright_policy).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BrightPolicy {
+    /// Write bright colors with their own codes, `90`-`97` and `100`-`107`.
+    #[default]
+    Codes,
+    /// Write a bright foreground as its normal color plus bold (`1;31` for bright red), for
+    /// terminals that only show bright colors that way. Bright backgrounds have no such
+    /// equivalent and are written as their normal color.
+    Bold,
+}
+
+impl Ansi {
+    /// Converts this style to one that only uses the 16 basic colors: every color is replaced
+    /// by the nearest [`Ansi16`] color and the underline color, which has no 16-color form, is
+    /// dropped.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Ansi, Ansi16};
+    /// let style = Ansi::from_fg((250, 10, 10)).bold().to_ansi16();
+    /// assert_eq!(style, Ansi::new().fg_indexed(Ansi16::BrightRed.index()).bold());
+    /// ```
+    #[must_use]
+    pub fn to_ansi16(&self) -> Ansi {
+        let mut style = self.clear_fg().clear_bg().clear_ul();
+        if let Some(fg) = self.foreground() {
+            style = style.fg_indexed(Ansi16::from(fg).index());
+        }
+        if let Some(bg) = self.background() {
+            style = style.bg_indexed(Ansi16::from(bg).index());
+        }
+        style
+    }
+
+    /// Gets the escape sequence for this style downgraded to the 16 basic colors (see
+    /// [`Ansi::to_ansi16`]), with colors written as `30`-`37`/`90`-`97` and
+    /// `40`-`47`/`100`-`107` instead of `38;5;n` and `48;5;n`. Bright colors are written
+    /// according to the [`bright_policy`](crate::bright_policy) of the current thread.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{with_bright_policy, Ansi, BrightPolicy};
+    /// let style = Ansi::from_fg((250, 10, 10)).underline();
+    /// assert_eq!(style.ansi16_sequence(), "\x1b[4;91m");
+    /// let bold = with_bright_policy(BrightPolicy::Bold, || style.ansi16_sequence());
+    /// assert_eq!(bold, "\x1b[1;4;31m");
+    /// ```
+    #[must_use]
+    pub fn ansi16_sequence(&self) -> String {
+        let shown = self.ansi16_shown();
+        let fg = shown.foreground().map(Ansi16::from);
+        let bg = shown.background().map(Ansi16::from);
+        let rest = shown.clear_fg().clear_bg();
+        if rest.is_default() && fg.is_none() && bg.is_none() {
+            return String::new();
+        }
+
+        // Writing to a `String` never fails.
+        let mut attributes = String::new();
+        let _ = rest.write_codes(&mut attributes);
+        let mut colors = String::new();
+        for (color, base) in [(fg, 30), (bg, 40)] {
+            if let Some(color) = color {
+                if !colors.is_empty() {
+                    colors.push(';');
+                }
+                let _ = color.write_code(base, &mut colors);
+            }
+        }
+
+        let (first, second) = match crate::code_order() {
+            CodeOrder::Canonical => (attributes, colors),
+            CodeOrder::ColorsFirst => (colors, attributes),
+        };
+        let separator = if first.is_empty() || second.is_empty() {
+            ""
+        } else {
+            ";"
+        };
+        format!("\x1b[{first}{separator}{second}m")
+    }
+
+    /// Like [`Ansi::paint_text`], but uses [`Ansi::ansi16_sequence`] to style the text, for
+    /// terminals that only support the 16 basic colors. The text is ended according to the
+    /// [`reset_policy`](crate::reset_policy), and returned as is when nothing of the style can
+    /// be shown with 16 colors.
+    #[must_use]
+    pub fn paint_text_16(&self, text: &str) -> String {
+        let sequence = self.ansi16_sequence();
+        if sequence.is_empty() || !crate::styles_enabled() {
+            return text.to_string();
+        }
+        let end = crate::reset_policy().end(self.ansi16_shown());
+        format!("{sequence}{text}{end}")
+    }
+
+    /// Gets the style [`Ansi::ansi16_sequence`] shows: this style downgraded to the 16 basic
+    /// colors, with bright colors replaced according to the current
+    /// [`bright_policy`](crate::bright_policy).
+    fn ansi16_shown(&self) -> Ansi {
+        let mut shown = self.to_ansi16();
+        if crate::bright_policy() != BrightPolicy::Bold {
+            return shown;
+        }
+        if let Some(fg) = shown.foreground().map(Ansi16::from) {
+            if fg.is_bright() {
+                // Set, not toggled, so a style that is already bold stays bold.
+                shown = shown
+                    .with_flags(shown.flags() | AnsiFlags::BOLD)
+                    .fg_indexed(fg.normal().index());
+            }
+        }
+        if let Some(bg) = shown.background().map(Ansi16::from) {
+            shown = shown.bg_indexed(bg.normal().index());
+        }
+        shown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn variants() {
+        for (idx, color) in Ansi16::ALL.into_iter().enumerate() {
+            assert_eq!(usize::from(color.index()), idx);
+            assert_eq!(Ansi16::from_index(color.index()), Some(color));
+            assert!(color.bright().is_bright());
+            assert!(!color.normal().is_bright());
+            assert_eq!(color.bright().normal(), color.normal());
+        }
+        assert_eq!(Ansi16::from_index(16), None);
+        assert_eq!(Ansi16::BrightBlue.bright(), Ansi16::BrightBlue);
+        assert_eq!(Ansi16::Cyan.bright(), Ansi16::BrightCyan);
+    }
+
+    #[test]
+    fn nearest() {
+        for color in Ansi16::ALL {
+            assert_eq!(Ansi16::nearest(color.to_color()), color);
+        }
+        assert_eq!(Ansi16::nearest(Color::from_rgb(10, 20, 120)), Ansi16::Blue);
+        assert_eq!(Ansi16::from(AnsiColor::Indexed(196)), Ansi16::BrightRed);
+        assert_eq!(Ansi16::from(AnsiColor::Indexed(3)), Ansi16::Yellow);
+    }
+
+    #[test]
+    fn sequences() {
+        let style = Ansi::new()
+            .fg_indexed(Ansi16::BrightGreen.index())
+            .bg_indexed(Ansi16::BrightBlack.index())
+            .ul((1, 2, 3))
+            .italic();
+        assert_eq!(style.ansi16_sequence(), "\x1b[3;92;100m");
+        assert_eq!(
+            crate::with_bright_policy(BrightPolicy::Bold, || style.ansi16_sequence()),
+            "\x1b[1;3;32;40m"
+        );
+        assert_eq!(
+            Ansi::new().fg_indexed(1).bg_indexed(4).ansi16_sequence(),
+            "\x1b[31;44m"
+        );
+        let bold_bright = Ansi::new().fg_indexed(9).bold();
+        assert_eq!(
+            crate::with_bright_policy(BrightPolicy::Bold, || bold_bright.ansi16_sequence()),
+            "\x1b[1;31m"
+        );
+        assert_eq!(Ansi::new().ul((1, 2, 3)).ansi16_sequence(), "");
+        assert_eq!(
+            Ansi::new().bg_indexed(15).paint_text_16("x"),
+            "\x1b[107mx\x1b[0m"
+        );
+        assert_eq!(Ansi::new().paint_text_16("x"), "x");
+        assert_eq!(Ansi::new().ul((1, 2, 3)).paint_text_16("x"), "x");
+        assert_eq!(
+            crate::with_reset_policy(crate::ResetPolicy::Targeted, || {
+                crate::with_bright_policy(BrightPolicy::Bold, || {
+                    Ansi::new().fg_indexed(9).ul_indexed(1).paint_text_16("x")
+                })
+            }),
+            "\x1b[1;31mx\x1b[22;39m"
+        );
+    }
+}
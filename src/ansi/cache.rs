@@ -0,0 +1,141 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::{cell::RefCell, collections::HashMap};
+
+use crate::{Ansi, CodeOrder};
+
+/// Number of styles the per-thread cache holds before it is cleared, so text with endlessly
+/// changing styles (like long gradients) cannot make it grow without bound.
+const THREAD_CACHE_LIMIT: usize = 1024;
+
+thread_local! {
+    static THREAD_CACHE: RefCell<StyleCache> = RefCell::new(StyleCache::new());
+}
+
+/// Maps [`Ansi`] styles to their escape sequences, so each distinct style is only formatted
+/// once no matter how often it is used.
+///
+/// The crate's own renderers ([`style_text`](crate::style_text),
+/// [`Ansi::paint_text`], [`CharStyles`](crate::CharStyles), diffs, gradients, progress bars,
+/// etc.) already share a per-thread cache, so this is only needed for custom renderers that
+/// write many escape sequences themselves.
+///
+/// The cache is cleared whenever the [`code_order`](crate::code_order) of the current thread
+/// differs from the one its sequences were formatted in.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi, StyleCache};
+/// let mut cache = StyleCache::new();
+/// let error = Ansi::red().bold();
+/// assert_eq!(cache.sequence(error), "\x1b[1;38;2;255;0;0m");
+/// assert_eq!(cache.sequence(error), error.to_string());
+/// assert_eq!(cache.sequence(Ansi::new()), "");
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StyleCache {
+    sequences: HashMap<Ansi, Box<str>>,
+    order: CodeOrder,
+}
+
+impl StyleCache {
+    /// Creates a new, empty, [`StyleCache`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the escape sequence for `style`, formatting it the first time `style` is seen.
+    /// The default style has an empty sequence and is never stored.
+    pub fn sequence(&mut self, style: Ansi) -> &str {
+        if style.is_default() {
+            return "";
+        }
+        let order = crate::code_order();
+        if order != self.order {
+            self.sequences.clear();
+            self.order = order;
+        }
+        self.sequences
+            .entry(style)
+            .or_insert_with(|| style.to_string().into_boxed_str())
+    }
+
+    /// Gets the number of styles in the cache.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.sequences.len()
+    }
+
+    /// Returns `true` if the cache holds no styles.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sequences.is_empty()
+    }
+
+    /// Removes every style from the cache.
+    pub fn clear(&mut self) {
+        self.sequences.clear();
+    }
+}
+
+/// Calls `f` with the escape sequence of `style`, taken from the per-thread cache.
+pub(crate) fn with_sequence<R>(style: Ansi, f: impl FnOnce(&str) -> R) -> R {
+    THREAD_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= THREAD_CACHE_LIMIT {
+            cache.clear();
+        }
+        f(cache.sequence(style))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn caches_each_style_once() {
+        let mut cache = StyleCache::new();
+        assert!(cache.is_empty());
+        let styles = [Ansi::new().bold(), Ansi::red(), Ansi::new().bold()];
+        for style in styles {
+            assert_eq!(cache.sequence(style), style.to_string());
+        }
+        assert_eq!(cache.len(), 2);
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn thread_cache() {
+        let style = Ansi::from_fg((1, 2, 3)).italic();
+        assert_eq!(with_sequence(style, str::to_string), style.to_string());
+        assert_eq!(with_sequence(Ansi::new(), str::len), 0);
+
+        for blue in 0..=255 {
+            for green in 0..5 {
+                with_sequence(Ansi::from_fg((0, green, blue)), |_| ());
+            }
+        }
+        THREAD_CACHE.with(|cache| assert!(cache.borrow().len() <= THREAD_CACHE_LIMIT));
+    }
+
+    #[test]
+    fn follows_code_order() {
+        let mut cache = StyleCache::new();
+        let style = Ansi::red().bold();
+        assert_eq!(cache.sequence(style), "\x1b[1;38;2;255;0;0m");
+        let reordered =
+            crate::with_code_order(CodeOrder::ColorsFirst, || cache.sequence(style).to_string());
+        assert_eq!(reordered, "\x1b[38;2;255;0;0;1m");
+        assert_eq!(cache.sequence(style), "\x1b[1;38;2;255;0;0m");
+        assert_eq!(cache.len(), 1);
+    }
+}
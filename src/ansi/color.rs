@@ -0,0 +1,223 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{Ansi, Color};
+
+/// A color as it is written in an escape sequence, either as a 24-bit RGB value or as an
+/// index into the terminal's 256-color palette.
+///
+/// Keeping the index around (instead of converting it to RGB right away) means a style that
+/// was parsed from another tool's output is written back out exactly the way it came in, and
+/// the terminal's own palette keeps being used for it.
+///
+/// A plain serialized [`Color`], the way styles were serialized before indexed colors were
+/// kept, deserializes as [`AnsiColor::Rgb`], so saved [`Ansi`] values and themes keep loading.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{AnsiColor, Color};
+/// let indexed = AnsiColor::Indexed(196);
+/// assert_eq!(indexed.to_color(), Color::from_rgb(255, 0, 0));
+/// assert_eq!(AnsiColor::from(Color::from_rgb(1, 2, 3)), AnsiColor::Rgb(Color::from_rgb(1, 2, 3)));
+/// ```
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "AnsiColorRepr"))]
+pub enum AnsiColor {
+    /// One of the 256 indexed colors. The basic (30-37) and bright (90-97) colors are
+    /// stored as indices `0..=15`.
+    Indexed(u8),
+    /// A 24-bit RGB color.
+    Rgb(Color),
+}
+
+/// The serialized forms an [`AnsiColor`] is read from.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum AnsiColorRepr {
+    Tagged(TaggedAnsiColor),
+    Plain(Color),
+}
+
+/// The serialized form of an [`AnsiColor`] itself.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "AnsiColor")]
+enum TaggedAnsiColor {
+    Indexed(u8),
+    Rgb(Color),
+}
+
+#[cfg(feature = "serde")]
+impl From<AnsiColorRepr> for AnsiColor {
+    fn from(repr: AnsiColorRepr) -> Self {
+        match repr {
+            AnsiColorRepr::Tagged(TaggedAnsiColor::Indexed(idx)) => AnsiColor::Indexed(idx),
+            AnsiColorRepr::Tagged(TaggedAnsiColor::Rgb(color)) | AnsiColorRepr::Plain(color) => {
+                AnsiColor::Rgb(color)
+            }
+        }
+    }
+}
+
+impl AnsiColor {
+    /// Resolves this color to an RGB [`Color`], using the standard xterm values for indexed
+    /// colors.
+    #[must_use]
+    pub const fn to_color(self) -> Color {
+        match self {
+            AnsiColor::Indexed(idx) => Color::ansi_256_to_color(idx),
+            AnsiColor::Rgb(color) => color,
+        }
+    }
+
+    /// Converts this color to the nearest of the 256 indexed colors, for terminals without
+    /// 24-bit color. Indexed colors are returned unchanged.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{AnsiColor, Color};
+    /// let orange = AnsiColor::Rgb(Color::from_rgb(250, 130, 10));
+    /// assert_eq!(orange.to_ansi256(), AnsiColor::Indexed(208));
+    /// ```
+    #[must_use]
+    pub fn to_ansi256(self) -> AnsiColor {
+        /// The channel values of the 6x6x6 color cube (indices `16..=231`).
+        const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+        let AnsiColor::Rgb(color) = self else {
+            return self;
+        };
+        let (r, g, b) = color.rgb();
+        let distance = |(cr, cg, cb): (u8, u8, u8)| {
+            [(r, cr), (g, cg), (b, cb)]
+                .into_iter()
+                .map(|(a, b)| u32::from(a.abs_diff(b)).pow(2))
+                .sum::<u32>()
+        };
+        let level = |channel: u8| {
+            (0..LEVELS.len())
+                .min_by_key(|&idx| LEVELS[idx].abs_diff(channel))
+                .unwrap_or(0)
+        };
+
+        let (lr, lg, lb) = (level(r), level(g), level(b));
+        let cube = (LEVELS[lr], LEVELS[lg], LEVELS[lb]);
+        // The gray ramp (indices `232..=255`) goes from 8 to 238 in steps of 10.
+        let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+        let step = u8::try_from(average.saturating_sub(3) / 10).map_or(23, |step| step.min(23));
+        let gray = 8 + step * 10;
+
+        #[allow(clippy::cast_possible_truncation)]
+        if distance((gray, gray, gray)) < distance(cube) {
+            AnsiColor::Indexed(232 + step)
+        } else {
+            AnsiColor::Indexed((16 + 36 * lr + 6 * lg + lb) as u8)
+        }
+    }
+
+    /// Writes the SGR parameters selecting this color to `out`, where `base` is `38` for the
+    /// foreground, `48` for the background or `58` for the underline.
+    pub(crate) fn write_codes<W: std::fmt::Write + ?Sized>(
+        self,
+        base: u8,
+        out: &mut W,
+    ) -> std::fmt::Result {
+        match self {
+            AnsiColor::Indexed(idx) => write!(out, "{base};5;{idx}"),
+            AnsiColor::Rgb(color) => {
+                let (r, g, b) = color.rgb();
+                write!(out, "{base};2;{r};{g};{b}")
+            }
+        }
+    }
+}
+
+impl From<Color> for AnsiColor {
+    fn from(color: Color) -> Self {
+        AnsiColor::Rgb(color)
+    }
+}
+
+impl Ansi {
+    /// Converts this style to one that only uses the 256 indexed colors (see
+    /// [`AnsiColor::to_ansi256`]).
+    #[must_use]
+    pub fn to_ansi256(&self) -> Ansi {
+        let mut style = self.clear_fg().clear_bg().clear_ul();
+        if let Some(AnsiColor::Indexed(idx)) = self.foreground().map(AnsiColor::to_ansi256) {
+            style = style.fg_indexed(idx);
+        }
+        if let Some(AnsiColor::Indexed(idx)) = self.background().map(AnsiColor::to_ansi256) {
+            style = style.bg_indexed(idx);
+        }
+        if let Some(AnsiColor::Indexed(idx)) = self.underline_color().map(AnsiColor::to_ansi256) {
+            style = style.ul_indexed(idx);
+        }
+        style
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn codes() {
+        let mut out = String::new();
+        AnsiColor::Indexed(200).write_codes(38, &mut out).unwrap();
+        out.push(';');
+        AnsiColor::Rgb(Color::from_rgb(1, 2, 3))
+            .write_codes(58, &mut out)
+            .unwrap();
+        assert_eq!(out, "38;5;200;58;2;1;2;3");
+    }
+
+    #[test]
+    fn downgrade() {
+        let rgb = |r, g, b| AnsiColor::Rgb(Color::from_rgb(r, g, b));
+        assert_eq!(rgb(0, 0, 0).to_ansi256(), AnsiColor::Indexed(16));
+        assert_eq!(rgb(255, 255, 255).to_ansi256(), AnsiColor::Indexed(231));
+        assert_eq!(rgb(128, 128, 128).to_ansi256(), AnsiColor::Indexed(244));
+        assert_eq!(rgb(95, 135, 175).to_ansi256(), AnsiColor::Indexed(67));
+        assert_eq!(AnsiColor::Indexed(3).to_ansi256(), AnsiColor::Indexed(3));
+        assert_eq!(
+            Ansi::from_fg((255, 0, 0)).ul((8, 8, 8)).bold().to_ansi256(),
+            Ansi::new().fg_indexed(196).ul_indexed(232).bold()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_plain_colors() {
+        let style = Ansi::new().fg_indexed(3).bg((1, 2, 3)).bold();
+        let json = serde_json::to_value(style).unwrap();
+        assert_eq!(serde_json::from_value::<Ansi>(json.clone()).unwrap(), style);
+
+        // Styles saved before indexed colors were kept have plain colors and no underline.
+        let mut old = json;
+        old["fg"] = serde_json::json!([255, 0, 0]);
+        old["bg"] = serde_json::Value::Null;
+        old.as_object_mut().unwrap().remove("ul");
+        old.as_object_mut().unwrap().remove("font");
+        assert_eq!(
+            serde_json::from_value::<Ansi>(old).unwrap(),
+            Ansi::from_fg((255, 0, 0)).bold()
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        assert_eq!(AnsiColor::Indexed(0).to_color(), Color::from_rgb(0, 0, 0));
+        assert_eq!(
+            AnsiColor::Rgb(Color::from_rgb(9, 8, 7)).to_color(),
+            Color::from_rgb(9, 8, 7)
+        );
+    }
+}
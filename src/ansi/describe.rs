@@ -0,0 +1,198 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::sgr::{parse_sgr, Sgr};
+use crate::{Ansi, AnsiColor, AnsiFlags, AnsiTokenizer, Token};
+
+/// Names of the flags, in the order they are described.
+pub(crate) const FLAG_NAMES: [(AnsiFlags, &str); 8] = [
+    (AnsiFlags::BOLD, "bold"),
+    (AnsiFlags::DIM, "dim"),
+    (AnsiFlags::ITALIC, "italic"),
+    (AnsiFlags::UNDERLINE, "underline"),
+    (AnsiFlags::BLINK, "blink"),
+    (AnsiFlags::REVERSE, "reverse"),
+    (AnsiFlags::CONCEAL, "conceal"),
+    (AnsiFlags::STRIKE, "strike"),
+];
+
+/// Names of the 16 basic terminal colors.
+pub(crate) const BASIC_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "bright-black",
+    "bright-red",
+    "bright-green",
+    "bright-yellow",
+    "bright-blue",
+    "bright-magenta",
+    "bright-cyan",
+    "bright-white",
+];
+
+/// Replaces every escape sequence in `text` with a human readable description, e.g.
+/// `\x1b[1;31m` becomes `⟨bold red⟩`. This is meant for logging and snapshot tests that need
+/// to show which styling was applied.
+///
+/// - 24-bit colors are written as hex (`#ff8000`), 256-colors as `color(n)`.
+/// - Background colors are prefixed with `on`, e.g. `⟨on blue⟩`.
+/// - Attributes being turned off are prefixed with `not-`, e.g. `⟨not-bold not-dim⟩`.
+/// - Escape sequences other than SGR are shown verbatim with `ESC` written as `\e`.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{debug_escapes, Ansi};
+/// assert_eq!(debug_escapes("\x1b[1;31mError\x1b[0m"), "⟨bold red⟩Error⟨reset⟩");
+///
+/// let style = Ansi::from_fg((255, 128, 0)).italic();
+/// assert_eq!(debug_escapes(&style.paint_text("hi")), "⟨italic #ff8000⟩hi⟨reset⟩");
+/// ```
+#[must_use]
+pub fn debug_escapes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for token in AnsiTokenizer::new(text) {
+        match (token, token.sgr_params()) {
+            (Token::Text(text), _) => out.push_str(text),
+            (_, Some(params)) => {
+                out.push('⟨');
+                out.push_str(&describe_sgr(params));
+                out.push('⟩');
+            }
+            (sequence, None) => out.push_str(&sequence.as_str().replace('\x1b', "\\e")),
+        }
+    }
+    out
+}
+
+/// Describes the parameters of a single SGR sequence.
+pub(crate) fn describe_sgr(params: &str) -> String {
+    parse_sgr(params)
+        .into_iter()
+        .map(|op| match op {
+            Sgr::Reset => "reset".to_string(),
+            Sgr::Set(flags) => flag_words(flags, ""),
+            Sgr::Unset(flags) => flag_words(flags, "not-"),
+            Sgr::Fg(color) => color_name(color),
+            Sgr::Bg(color) => format!("on {}", color_name(color)),
+            Sgr::UnderlineColor(color) => format!("underline-color {}", color_name(color)),
+            Sgr::DefaultFg => "default".to_string(),
+            Sgr::DefaultBg => "on default".to_string(),
+            Sgr::DefaultUnderlineColor => "underline-color default".to_string(),
+            Sgr::Font(font) => font_name(font),
+            Sgr::Unknown(code) if code == u16::MAX => "?".to_string(),
+            Sgr::Unknown(code) => format!("?{code}"),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Describes a whole [`Ansi`], e.g. `bold #ff0000 on #000000`.
+pub(crate) fn describe_ansi(ansi: &Ansi) -> String {
+    let mut words = Vec::new();
+    if !ansi.flags().is_empty() {
+        words.push(flag_words(ansi.flags(), ""));
+    }
+    if ansi.font_number() != 0 {
+        words.push(font_name(ansi.font_number()));
+    }
+    if let Some(fg) = ansi.foreground() {
+        words.push(color_name(fg));
+    }
+    if let Some(bg) = ansi.background() {
+        words.push(format!("on {}", color_name(bg)));
+    }
+    if let Some(ul) = ansi.underline_color() {
+        words.push(format!("underline-color {}", color_name(ul)));
+    }
+
+    if words.is_empty() {
+        "default".to_string()
+    } else {
+        words.join(" ")
+    }
+}
+
+fn font_name(font: u8) -> String {
+    match font {
+        0 => "default-font".to_string(),
+        10 => "fraktur".to_string(),
+        font => format!("font-{font}"),
+    }
+}
+
+fn flag_words(flags: AnsiFlags, prefix: &str) -> String {
+    FLAG_NAMES
+        .iter()
+        .filter(|(flag, _)| flags.contains(*flag))
+        .map(|(_, name)| format!("{prefix}{name}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn color_name(color: AnsiColor) -> String {
+    match color {
+        AnsiColor::Indexed(idx) if idx < 16 => BASIC_NAMES[usize::from(idx)].to_string(),
+        AnsiColor::Indexed(idx) => format!("color({idx})"),
+        AnsiColor::Rgb(color) => color.as_hex_lower(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn basic_sequences() {
+        assert_eq!(debug_escapes("plain"), "plain");
+        assert_eq!(debug_escapes("\u{1b}[m"), "⟨reset⟩");
+        assert_eq!(
+            debug_escapes("\u{1b}[2;3;4;5;7;9;92;104mx"),
+            "⟨dim italic underline blink reverse strike bright-green on bright-blue⟩x"
+        );
+        assert_eq!(
+            debug_escapes("\u{1b}[8ms\u{1b}[28m"),
+            "⟨conceal⟩s⟨not-conceal⟩"
+        );
+        assert_eq!(
+            debug_escapes("\u{1b}[22;39;49;38;5;200m"),
+            "⟨not-bold not-dim default on default color(200)⟩"
+        );
+        assert_eq!(debug_escapes("\u{1b}[6m"), "⟨?6⟩");
+        assert_eq!(
+            debug_escapes("\u{1b}[58;2;1;2;3;59m"),
+            "⟨underline-color #010203 underline-color default⟩"
+        );
+        assert_eq!(
+            debug_escapes("\u{1b}[11;20;10m"),
+            "⟨font-1 fraktur default-font⟩"
+        );
+    }
+
+    #[test]
+    fn other_sequences() {
+        assert_eq!(debug_escapes("a\u{1b}[2Kb"), "a\\e[2Kb");
+        assert_eq!(debug_escapes("\u{1b}]0;title\u{7}"), "\\e]0;title\u{7}");
+        assert_eq!(debug_escapes("\u{1b}[12"), "\\e[12");
+    }
+
+    #[test]
+    fn ansi_description() {
+        let ansi = Ansi::from_fg((255, 0, 0)).bg((0, 0, 0)).bold();
+        assert_eq!(describe_ansi(&ansi), "bold #ff0000 on #000000");
+        assert_eq!(describe_ansi(&Ansi::new()), "default");
+        assert_eq!(
+            describe_ansi(&Ansi::new().fg_indexed(1).ul_indexed(100)),
+            "red underline-color color(100)"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// Error type used when parsing an [`Ansi`](crate::Ansi) from escape sequences.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnsiParseError {
+    /// Text that is not part of an escape sequence was found.
+    UnexpectedText(String),
+    /// An escape sequence was started but never finished.
+    Incomplete,
+    /// An escape sequence other than SGR (`ESC[...m`) was found.
+    UnsupportedSequence(String),
+    /// An SGR parameter that could not be parsed as a number.
+    InvalidParameter,
+    /// An SGR code that cannot be represented by an `Ansi`.
+    UnsupportedCode(u16),
+}
+
+impl std::fmt::Display for AnsiParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnsiParseError::UnexpectedText(text) => {
+                write!(f, "Unexpected text in escape sequences: {text:?}")
+            }
+            AnsiParseError::Incomplete => write!(f, "Escape sequence was not terminated"),
+            AnsiParseError::UnsupportedSequence(seq) => {
+                write!(f, "Unsupported escape sequence: {seq:?}")
+            }
+            AnsiParseError::InvalidParameter => write!(f, "SGR parameter is not a number"),
+            AnsiParseError::UnsupportedCode(code) => write!(f, "Unsupported SGR code: {code}"),
+        }
+    }
+}
+
+impl std::error::Error for AnsiParseError {}
+
+/// Error type used when parsing an [`Ansi`](crate::Ansi) from a style spec, see
+/// [`Ansi::parse_spec`](crate::Ansi::parse_spec).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StyleSpecError {
+    /// A word that is not an attribute, font or color.
+    UnknownWord(String),
+    /// `on` or `underline-color` was not followed by a color.
+    MissingColor(String),
+}
+
+impl std::fmt::Display for StyleSpecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StyleSpecError::UnknownWord(word) => write!(f, "Unknown word in style: {word:?}"),
+            StyleSpecError::MissingColor(word) => write!(f, "Expected a color after {word:?}"),
+        }
+    }
+}
+
+impl std::error::Error for StyleSpecError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ansi_parse_error() {
+        assert_eq!(
+            AnsiParseError::UnexpectedText("abc".to_string()).to_string(),
+            "Unexpected text in escape sequences: \"abc\""
+        );
+        assert_eq!(
+            AnsiParseError::UnsupportedCode(6).to_string(),
+            "Unsupported SGR code: 6"
+        );
+        assert_eq!(
+            StyleSpecError::MissingColor("on".to_string()).to_string(),
+            "Expected a color after \"on\""
+        );
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{Ansi, AnsiFlags};
+
+/// Number of attributes in [`AnsiFlags`], the position of its highest flag bit plus one.
+const FLAG_COUNT: usize = (u8::BITS - AnsiFlags::all().bits().leading_zeros()) as usize;
+
+/// Replacements for text attributes a terminal can not show, so a style can be designed once
+/// and still degrade sensibly, e.g. concealed text shown dimmed where concealing is not
+/// supported. Use it directly with [`AttributeFallback::apply`], or let an
+/// [`AnsiWriter`](crate::AnsiWriter) apply it to everything it writes.
+///
+/// Every attribute is supported until a fallback is set for it. Fallbacks are not chained: a
+/// replacement is used as is, even if it has a fallback itself.
+///
+/// Only the attributes of [`AnsiFlags`] (bold, dim, italic, underline, blink, reverse, conceal
+/// and strike) can have a fallback. Attributes this crate does not model, such as undercurl or
+/// overline, can not be described. The fallbacks are not detected from the terminal either,
+/// they have to be set up by the caller.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi, AnsiFlags, AttributeFallback};
+/// let fallback = AttributeFallback::new()
+///     .fallback(AnsiFlags::CONCEAL, AnsiFlags::DIM)
+///     .unsupported(AnsiFlags::BLINK);
+///
+/// let style = Ansi::red().conceal().blink().bold();
+/// assert_eq!(fallback.apply(style), Ansi::red().dim().bold());
+/// assert!(!fallback.is_supported(AnsiFlags::BLINK));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AttributeFallback {
+    /// The replacement of each attribute, by bit position, or `None` if it is supported.
+    fallbacks: [Option<AnsiFlags>; FLAG_COUNT],
+}
+
+impl AttributeFallback {
+    /// Creates a new [`AttributeFallback`] where every attribute is supported.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            fallbacks: [None; FLAG_COUNT],
+        }
+    }
+
+    /// Builder function to mark the attributes in `unsupported` as unsupported, showing
+    /// `replacement` instead of them.
+    #[must_use]
+    pub const fn fallback(mut self, unsupported: AnsiFlags, replacement: AnsiFlags) -> Self {
+        let mut bit = 0;
+        while bit < FLAG_COUNT {
+            if unsupported.bits() & (1 << bit) != 0 {
+                self.fallbacks[bit] = Some(replacement);
+            }
+            bit += 1;
+        }
+        self
+    }
+
+    /// Builder function to mark the attributes in `unsupported` as unsupported, dropping them
+    /// without a replacement.
+    #[must_use]
+    pub const fn unsupported(self, unsupported: AnsiFlags) -> Self {
+        self.fallback(unsupported, AnsiFlags::empty())
+    }
+
+    /// Returns `true` if no fallback is set for any of the attributes in `flags`.
+    #[must_use]
+    pub fn is_supported(&self, flags: AnsiFlags) -> bool {
+        self.fallbacks
+            .iter()
+            .enumerate()
+            .all(|(bit, fallback)| fallback.is_none() || flags.bits() & (1 << bit) == 0)
+    }
+
+    /// Replaces the unsupported attributes of `style` with their fallbacks. Colors are kept.
+    #[must_use]
+    pub fn apply(&self, style: Ansi) -> Ansi {
+        let mut flags = style.flags();
+        let mut replacements = AnsiFlags::empty();
+        for (bit, fallback) in self.fallbacks.iter().enumerate() {
+            let flag = AnsiFlags::from_bits_truncate(1 << bit);
+            if let (Some(replacement), true) = (fallback, flags.contains(flag)) {
+                flags.remove(flag);
+                replacements.insert(*replacement);
+            }
+        }
+        style.with_flags(flags | replacements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn fallbacks() {
+        let style = Ansi::from_fg((1, 2, 3)).italic().strike().reverse();
+        assert_eq!(AttributeFallback::new().apply(style), style);
+        assert!(AttributeFallback::default().is_supported(AnsiFlags::all()));
+
+        let fallback = AttributeFallback::new()
+            .fallback(AnsiFlags::ITALIC | AnsiFlags::STRIKE, AnsiFlags::UNDERLINE)
+            .fallback(AnsiFlags::UNDERLINE, AnsiFlags::BOLD);
+        assert_eq!(
+            fallback.apply(style),
+            Ansi::from_fg((1, 2, 3)).underline().reverse()
+        );
+        assert_eq!(fallback.apply(Ansi::new().underline()), Ansi::new().bold());
+        assert!(fallback.is_supported(AnsiFlags::REVERSE | AnsiFlags::BOLD));
+        assert!(!fallback.is_supported(AnsiFlags::REVERSE | AnsiFlags::STRIKE));
+
+        let all = AttributeFallback::new().unsupported(AnsiFlags::all());
+        assert_eq!(all.apply(Ansi::new().bold().dim().conceal()), Ansi::new());
+        assert!(all.fallbacks.iter().all(Option::is_some));
+
+        let fallback = fallback.unsupported(AnsiFlags::ITALIC);
+        assert_eq!(
+            fallback.apply(Ansi::new().italic().strike()),
+            Ansi::new().underline()
+        );
+    }
+}
@@ -50,6 +50,10 @@ impl AnsiFlags {
     pub const REVERSE: Self = Self { bits: (1 << 4) };
     /// Striken text.
     pub const STRIKE: Self = Self { bits: (1 << 5) };
+    /// Dimmed / faint text.
+    pub const DIM: Self = Self { bits: (1 << 6) };
+    /// Concealed / hidden text.
+    pub const CONCEAL: Self = Self { bits: (1 << 7) };
 
     /// Returns an empty set of flags.
     #[inline]
@@ -68,7 +72,9 @@ impl AnsiFlags {
                 | <Self as BitFlags>::ITALIC
                 | <Self as BitFlags>::BLINK
                 | <Self as BitFlags>::REVERSE
-                | <Self as BitFlags>::STRIKE,
+                | <Self as BitFlags>::STRIKE
+                | <Self as BitFlags>::DIM
+                | <Self as BitFlags>::CONCEAL,
         }
     }
 
@@ -438,6 +444,20 @@ impl std::fmt::Debug for AnsiFlags {
             first = false;
             f.write_str("STRIKE")?;
         }
+        if <Self as BoolFlags>::DIM(self) {
+            if !first {
+                f.write_str(" | ")?;
+            }
+            first = false;
+            f.write_str("DIM")?;
+        }
+        if <Self as BoolFlags>::CONCEAL(self) {
+            if !first {
+                f.write_str(" | ")?;
+            }
+            first = false;
+            f.write_str("CONCEAL")?;
+        }
         let extra_bits = self.bits & !Self::all().bits();
         if extra_bits != 0 {
             if !first {
@@ -481,6 +501,8 @@ trait BitFlags {
     const BLINK: u8 = 0;
     const REVERSE: u8 = 0;
     const STRIKE: u8 = 0;
+    const DIM: u8 = 0;
+    const CONCEAL: u8 = 0;
 }
 impl BitFlags for AnsiFlags {
     const BOLD: u8 = Self::BOLD.bits;
@@ -489,6 +511,8 @@ impl BitFlags for AnsiFlags {
     const BLINK: u8 = Self::BLINK.bits;
     const REVERSE: u8 = Self::REVERSE.bits;
     const STRIKE: u8 = Self::STRIKE.bits;
+    const DIM: u8 = Self::DIM.bits;
+    const CONCEAL: u8 = Self::CONCEAL.bits;
 }
 
 #[allow(non_snake_case)]
@@ -517,6 +541,14 @@ trait BoolFlags {
     fn STRIKE(&self) -> bool {
         false
     }
+    #[inline]
+    fn DIM(&self) -> bool {
+        false
+    }
+    #[inline]
+    fn CONCEAL(&self) -> bool {
+        false
+    }
 }
 #[allow(non_snake_case)]
 impl BoolFlags for AnsiFlags {
@@ -574,6 +606,24 @@ impl BoolFlags for AnsiFlags {
             self.bits & Self::STRIKE.bits == Self::STRIKE.bits
         }
     }
+    #[allow(deprecated)]
+    #[inline]
+    fn DIM(&self) -> bool {
+        if Self::DIM.bits == 0 && self.bits != 0 {
+            false
+        } else {
+            self.bits & Self::DIM.bits == Self::DIM.bits
+        }
+    }
+    #[allow(deprecated)]
+    #[inline]
+    fn CONCEAL(&self) -> bool {
+        if Self::CONCEAL.bits == 0 && self.bits != 0 {
+            false
+        } else {
+            self.bits & Self::CONCEAL.bits == Self::CONCEAL.bits
+        }
+    }
 }
 
 #[cfg(test)]
@@ -591,18 +641,26 @@ mod tests {
                 | AnsiFlags::BLINK
                 | AnsiFlags::REVERSE
                 | AnsiFlags::STRIKE
+                | AnsiFlags::DIM
+                | AnsiFlags::CONCEAL
         );
         assert!(AnsiFlags::is_all(&AnsiFlags::all()));
         assert_eq!(
             format!("{:?}", AnsiFlags::all()),
-            "BOLD | UNDERLINE | ITALIC | BLINK | REVERSE | STRIKE"
+            "BOLD | UNDERLINE | ITALIC | BLINK | REVERSE | STRIKE | DIM | CONCEAL"
         );
         assert!(!AnsiFlags::is_empty(&AnsiFlags::all()));
         assert!(AnsiFlags::all().contains(AnsiFlags::UNDERLINE));
         assert_eq!(AnsiFlags::from_bits(0x0), Some(AnsiFlags::empty()));
         assert_eq!(AnsiFlags::from_bits(0x1), Some(AnsiFlags::BOLD));
-        assert_eq!(AnsiFlags::from_bits(0x64), None);
-        assert_eq!(AnsiFlags::from_bits_truncate(200), AnsiFlags::BLINK);
+        assert_eq!(
+            AnsiFlags::from_bits(0x84),
+            Some(AnsiFlags::CONCEAL | AnsiFlags::ITALIC)
+        );
+        assert_eq!(
+            AnsiFlags::from_bits_truncate(136),
+            AnsiFlags::CONCEAL | AnsiFlags::BLINK
+        );
         unsafe {
             assert_eq!(AnsiFlags::from_bits_unchecked(1), AnsiFlags::BOLD);
         }
@@ -702,14 +760,16 @@ mod tests {
         assert!(!AnsiFlags::ITALIC.STRIKE());
         assert!(!AnsiFlags::ITALIC.UNDERLINE());
         assert!(!AnsiFlags::ITALIC.REVERSE());
+        assert!(!AnsiFlags::ITALIC.DIM());
+        assert!(!AnsiFlags::ITALIC.CONCEAL());
         assert!(AnsiFlags::ITALIC.ITALIC());
     }
 
     #[test]
     fn format() {
-        assert_eq!(format!("{:02x}", AnsiFlags::all()), "3f");
-        assert_eq!(format!("{:02X}", AnsiFlags::all()), "3F");
-        assert_eq!(format!("{:02o}", AnsiFlags::all()), "77");
-        assert_eq!(format!("{:02b}", AnsiFlags::all()), "111111");
+        assert_eq!(format!("{:02x}", AnsiFlags::all()), "ff");
+        assert_eq!(format!("{:02X}", AnsiFlags::all()), "FF");
+        assert_eq!(format!("{:02o}", AnsiFlags::all()), "377");
+        assert_eq!(format!("{:02b}", AnsiFlags::all()), "11111111");
     }
 }
@@ -0,0 +1,126 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{Ansi, AnsiFlags};
+
+/// Contrast ratio below which [`Ansi::lint`] reports [`StyleLint::LowContrast`]. This is the
+/// WCAG 2 minimum for large text, which is the most lenient of its thresholds.
+pub const MIN_CONTRAST: f32 = 3.0;
+
+/// A problem with a style, found by [`Ansi::lint`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StyleLint {
+    /// The foreground and background colors are too close to read text, see
+    /// [`MIN_CONTRAST`].
+    LowContrast {
+        /// The WCAG 2 contrast ratio between the two colors.
+        ratio: f32,
+    },
+    /// The text is concealed, so its foreground and underline colors are never seen.
+    ConcealedWithColor,
+    /// Bold and dim are both set. Terminals disagree on how to show this, and the two share a
+    /// reset code, so one can not be turned off without the other.
+    BoldAndDim,
+    /// An underline color is set without underlining the text.
+    UnderlineColorWithoutUnderline,
+}
+
+impl std::fmt::Display for StyleLint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StyleLint::LowContrast { ratio } => {
+                write!(
+                    f,
+                    "fg and bg contrast ratio {ratio:.1} is below {MIN_CONTRAST}"
+                )
+            }
+            StyleLint::ConcealedWithColor => write!(f, "concealed text has a color set"),
+            StyleLint::BoldAndDim => write!(f, "bold and dim are both set"),
+            StyleLint::UnderlineColorWithoutUnderline => {
+                write!(f, "underline color is set without underline")
+            }
+        }
+    }
+}
+
+impl Ansi {
+    /// Checks this style for combinations that are unreadable or redundant, e.g. so CI can
+    /// reject a theme file before users see it. Returns nothing for a good style.
+    ///
+    /// Indexed colors are checked using their standard xterm values, which the user's terminal
+    /// may change.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Ansi, StyleLint};
+    /// let style = Ansi::from_fg((90, 90, 90)).bg((60, 60, 60)).bold().dim();
+    /// let lints = style.lint();
+    /// assert_eq!(lints[0].to_string(), "fg and bg contrast ratio 1.6 is below 3");
+    /// assert_eq!(lints[1], StyleLint::BoldAndDim);
+    /// assert!(Ansi::from_fg((255, 255, 255)).bg((0, 0, 0)).lint().is_empty());
+    /// ```
+    #[must_use]
+    pub fn lint(&self) -> Vec<StyleLint> {
+        let mut lints = Vec::new();
+        let flags = self.flags();
+        if let (Some(fg), Some(bg)) = (self.foreground(), self.background()) {
+            let ratio = fg.to_color().contrast_ratio(bg.to_color());
+            if ratio < MIN_CONTRAST {
+                lints.push(StyleLint::LowContrast { ratio });
+            }
+        }
+        if flags.contains(AnsiFlags::CONCEAL)
+            && (self.foreground().is_some() || self.underline_color().is_some())
+        {
+            lints.push(StyleLint::ConcealedWithColor);
+        }
+        if flags.contains(AnsiFlags::BOLD | AnsiFlags::DIM) {
+            lints.push(StyleLint::BoldAndDim);
+        }
+        if self.underline_color().is_some() && !flags.contains(AnsiFlags::UNDERLINE) {
+            lints.push(StyleLint::UnderlineColorWithoutUnderline);
+        }
+        lints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn lints() {
+        assert!(Ansi::new().lint().is_empty());
+        assert!(Ansi::from_fg((0, 0, 0)).lint().is_empty());
+        assert!(Ansi::new().ul_indexed(1).underline().lint().is_empty());
+
+        let lints = Ansi::new().fg_indexed(4).bg_indexed(0).lint();
+        assert!(matches!(lints[..], [StyleLint::LowContrast { ratio }] if ratio < 2.0));
+
+        assert_eq!(
+            Ansi::new().conceal().ul((1, 2, 3)).bold().dim().lint(),
+            vec![
+                StyleLint::ConcealedWithColor,
+                StyleLint::BoldAndDim,
+                StyleLint::UnderlineColorWithoutUnderline
+            ]
+        );
+        assert!(Ansi::new().conceal().bg_indexed(0).lint().is_empty());
+    }
+
+    #[test]
+    fn messages() {
+        assert_eq!(
+            StyleLint::LowContrast { ratio: 1.26 }.to_string(),
+            "fg and bg contrast ratio 1.3 is below 3"
+        );
+        assert_eq!(
+            StyleLint::ConcealedWithColor.to_string(),
+            "concealed text has a color set"
+        );
+    }
+}
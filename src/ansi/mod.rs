@@ -7,9 +7,36 @@
 // Private module so who cares
 #[allow(clippy::module_inception)]
 mod ansi;
+mod ansi16;
+mod cache;
+mod color;
+mod describe;
+mod error;
+mod fallback;
 mod flags;
+mod lint;
+mod order;
+mod reset;
+mod sgr;
+mod spec;
+mod tags;
+mod tokenizer;
 mod traits;
 
 pub use ansi::Ansi;
+pub use ansi16::{Ansi16, BrightPolicy};
+pub(crate) use cache::with_sequence;
+pub use cache::StyleCache;
+pub use color::AnsiColor;
+pub use describe::debug_escapes;
+#[cfg(feature = "serde")]
+pub(crate) use describe::{describe_ansi, BASIC_NAMES, FLAG_NAMES};
+pub use error::{AnsiParseError, StyleSpecError};
+pub use fallback::AttributeFallback;
 pub use flags::AnsiFlags;
+pub use lint::{StyleLint, MIN_CONTRAST};
+pub use order::CodeOrder;
+pub use reset::ResetPolicy;
+pub use tags::{from_tags, to_tags};
+pub use tokenizer::{AnsiTokenizer, Token};
 pub use traits::*;
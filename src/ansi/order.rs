@@ -0,0 +1,70 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// The order in which the SGR parameters of a style are written, see
+/// [`with_code_order`](crate::with_code_order).
+///
+/// The order never changes what a terminal shows, only the exact bytes written, which matters
+/// to snapshot tests and terminal recorders that compare output. [`Ansi::parse`](crate::Ansi::parse)
+/// and the other parsers in this crate accept the parameters in any order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CodeOrder {
+    /// Write the parameters in ascending order of their code: the attributes (`1`-`9`), then
+    /// the font (`11`-`20`), then the foreground (`38`), background (`48`) and underline
+    /// (`58`) colors, e.g. `3;9;38;5;1;48;5;4`.
+    #[default]
+    Canonical,
+    /// Write the foreground, background and underline colors first, followed by the
+    /// attributes and the font, e.g. `38;5;1;48;5;4;3;9`.
+    ColorsFirst,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{with_code_order, Ansi};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn orders() {
+        let style = Ansi::new()
+            .fg_indexed(1)
+            .bg_indexed(4)
+            .ul((1, 2, 3))
+            .strike()
+            .italic()
+            .font(2);
+        assert_eq!(CodeOrder::default(), CodeOrder::Canonical);
+        assert_eq!(style.to_string(), "\x1b[3;9;12;38;5;1;48;5;4;58;2;1;2;3m");
+
+        let colors_first = with_code_order(CodeOrder::ColorsFirst, || style.to_string());
+        assert_eq!(colors_first, "\x1b[38;5;1;48;5;4;58;2;1;2;3;3;9;12m");
+        assert_eq!(Ansi::parse(&colors_first), Ok(style));
+        assert_eq!(style.to_string(), "\x1b[3;9;12;38;5;1;48;5;4;58;2;1;2;3m");
+    }
+
+    #[test]
+    fn applies_to_renderers() {
+        let style = Ansi::new().fg_indexed(9).bold();
+        let out = with_code_order(CodeOrder::ColorsFirst, || {
+            (
+                crate::style_text("a", style),
+                style.paint_text("b"),
+                style.ansi16_sequence(),
+            )
+        });
+        assert_eq!(
+            out,
+            (
+                "\x1b[38;5;9;1ma\x1b[0m".to_string(),
+                "\x1b[38;5;9;1mb\x1b[0m".to_string(),
+                "\x1b[91;1m".to_string(),
+            )
+        );
+        assert_eq!(crate::style_text("a", style), "\x1b[1;38;5;9ma\x1b[0m");
+    }
+}
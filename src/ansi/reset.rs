@@ -0,0 +1,156 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::{Ansi, AnsiFlags};
+
+/// How styled text is ended, see [`with_reset_policy`](crate::with_reset_policy).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ResetPolicy {
+    /// End styled text with a full reset (`ESC[0m`), which also clears any styling that was
+    /// active before it.
+    #[default]
+    Full,
+    /// End styled text by only turning off what its style turned on (e.g. `ESC[22;39m` after
+    /// bold red text), so attributes set up around it, e.g. by a prompt framework, that the
+    /// text does not use itself stay active.
+    ///
+    /// This can not bring back what the text's own style replaced: turning off a foreground,
+    /// background or underline color (`39`, `49`, `59`) goes back to the terminal's default
+    /// color, not to the color that was active before, and bold and dim are both turned off by
+    /// the same code (`22`), so a surrounding bold is lost after dim text and vice versa.
+    Targeted,
+}
+
+impl ResetPolicy {
+    /// Gets the escape sequence that ends text styled with `style` under this policy. Nothing
+    /// needs to be ended for the default style.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Ansi, ResetPolicy};
+    /// let style = Ansi::red().bold();
+    /// assert_eq!(ResetPolicy::Full.end(style), "\x1b[0m");
+    /// assert_eq!(ResetPolicy::Targeted.end(style), "\x1b[22;39m");
+    /// ```
+    #[must_use]
+    pub fn end(self, style: Ansi) -> Cow<'static, str> {
+        match self {
+            _ if style.is_default() => Cow::Borrowed(""),
+            ResetPolicy::Full => Cow::Borrowed(Ansi::reset()),
+            ResetPolicy::Targeted => Cow::Owned(style.undo_sequence()),
+        }
+    }
+
+    /// Writes the escape sequence that ends text styled with `style` to `out`.
+    pub(crate) fn write_end<W: fmt::Write + ?Sized>(self, style: Ansi, out: &mut W) -> fmt::Result {
+        match self {
+            _ if style.is_default() => Ok(()),
+            ResetPolicy::Full => out.write_str(Ansi::reset()),
+            ResetPolicy::Targeted => {
+                out.write_str("\x1b[")?;
+                style.write_undo_codes(out)?;
+                out.write_str("m")
+            }
+        }
+    }
+}
+
+impl Ansi {
+    /// Gets the escape sequence that turns off everything this style turns on, and nothing
+    /// else. This is empty for the default style.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Ansi;
+    /// let style = Ansi::from_bg((0, 0, 0)).italic().dim();
+    /// assert_eq!(style.undo_sequence(), "\x1b[22;23;49m");
+    /// assert_eq!(Ansi::new().undo_sequence(), "");
+    /// ```
+    #[must_use]
+    pub fn undo_sequence(&self) -> String {
+        if self.is_default() {
+            return String::new();
+        }
+        format!("\x1b[{}m", self.undo_codes().collect::<Vec<_>>().join(";"))
+    }
+
+    /// Writes the `;` separated SGR parameters that turn off everything this style turns on.
+    pub(crate) fn write_undo_codes<W: fmt::Write + ?Sized>(&self, out: &mut W) -> fmt::Result {
+        for (idx, code) in self.undo_codes().enumerate() {
+            if idx > 0 {
+                out.write_str(";")?;
+            }
+            out.write_str(code)?;
+        }
+        Ok(())
+    }
+
+    /// Gets the SGR parameters that turn off everything this style turns on.
+    fn undo_codes(&self) -> impl Iterator<Item = &'static str> {
+        const FLAG_UNDO: [(AnsiFlags, &str); 7] = [
+            (AnsiFlags::BOLD.union(AnsiFlags::DIM), "22"),
+            (AnsiFlags::ITALIC, "23"),
+            (AnsiFlags::UNDERLINE, "24"),
+            (AnsiFlags::BLINK, "25"),
+            (AnsiFlags::REVERSE, "27"),
+            (AnsiFlags::CONCEAL, "28"),
+            (AnsiFlags::STRIKE, "29"),
+        ];
+
+        let flags = self.flags();
+        FLAG_UNDO
+            .into_iter()
+            .filter(move |(flag, _)| flags.intersects(*flag))
+            .map(|(_, code)| code)
+            .chain((self.font_number() != 0).then_some("10"))
+            .chain(self.foreground().map(|_| "39"))
+            .chain(self.background().map(|_| "49"))
+            .chain(self.underline_color().map(|_| "59"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn undo() {
+        let everything = Ansi::from_fg((1, 1, 1))
+            .bg_indexed(2)
+            .ul_indexed(3)
+            .bold()
+            .dim()
+            .italic()
+            .underline()
+            .blink()
+            .reverse()
+            .conceal()
+            .strike()
+            .font(2);
+        assert_eq!(
+            everything.undo_sequence(),
+            "\x1b[22;23;24;25;27;28;29;10;39;49;59m"
+        );
+        assert_eq!(Ansi::new().bold().undo_sequence(), "\x1b[22m");
+        assert_eq!(Ansi::new().undo_sequence(), "");
+    }
+
+    #[test]
+    fn ends() {
+        assert_eq!(ResetPolicy::default(), ResetPolicy::Full);
+        assert_eq!(ResetPolicy::Full.end(Ansi::new()), "");
+        assert_eq!(ResetPolicy::Targeted.end(Ansi::new()), "");
+        assert_eq!(
+            ResetPolicy::Targeted.end(Ansi::new().underline()),
+            "\x1b[24m"
+        );
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{AnsiColor, AnsiFlags, Color};
+
+/// A single operation described by an SGR (Select Graphic Rendition) sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Sgr {
+    /// Reset all styling (`0`).
+    Reset,
+    /// Turn on the given flags.
+    Set(AnsiFlags),
+    /// Turn off the given flags.
+    Unset(AnsiFlags),
+    /// Set the foreground color.
+    Fg(AnsiColor),
+    /// Set the background color.
+    Bg(AnsiColor),
+    /// Reset the foreground color to the terminal default (`39`).
+    DefaultFg,
+    /// Reset the background color to the terminal default (`49`).
+    DefaultBg,
+    /// Set the underline color.
+    UnderlineColor(AnsiColor),
+    /// Reset the underline color to the terminal default (`59`).
+    DefaultUnderlineColor,
+    /// Select a font (`10` to `20`), where `0` is the primary font, `1..=9` the alternate
+    /// fonts and `10` Fraktur.
+    Font(u8),
+    /// A parameter that was not understood.
+    Unknown(u16),
+}
+
+/// Parses the parameters of an SGR sequence (the part between `ESC[` and `m`) into a list
+/// of operations. An empty parameter list is a reset. Both `;` and `:` separated extended
+/// colors (`38;2;r;g;b`, `38:2::r:g:b`, `38;5;n`) are understood, for the foreground (`38`),
+/// background (`48`) and underline (`58`).
+pub(crate) fn parse_sgr(params: &str) -> Vec<Sgr> {
+    if params.is_empty() {
+        return vec![Sgr::Reset];
+    }
+
+    let mut ops = Vec::new();
+    let mut params = params.split(';');
+    while let Some(param) = params.next() {
+        if param.contains(':') {
+            let mut sub = param.split(':').map(|p| p.parse::<u16>().ok());
+            let code = sub.next().flatten().unwrap_or_default();
+            ops.push(match code {
+                38 | 48 | 58 => extended_color(code, &sub.collect::<Vec<_>>(), true),
+                _ => Sgr::Unknown(code),
+            });
+            continue;
+        }
+
+        let Ok(code) = (if param.is_empty() {
+            Ok(0)
+        } else {
+            param.parse::<u16>()
+        }) else {
+            ops.push(Sgr::Unknown(u16::MAX));
+            continue;
+        };
+
+        ops.push(match code {
+            38 | 48 | 58 => {
+                let kind = params.next().and_then(|p| p.parse::<u16>().ok());
+                let rest = match kind {
+                    Some(2) => (0..3)
+                        .map(|_| params.next().and_then(|p| p.parse::<u16>().ok()))
+                        .collect::<Vec<_>>(),
+                    Some(5) => vec![params.next().and_then(|p| p.parse::<u16>().ok())],
+                    _ => Vec::new(),
+                };
+                let mut all = vec![kind];
+                all.extend(rest);
+                extended_color(code, &all, false)
+            }
+            code => simple(code),
+        });
+    }
+    ops
+}
+
+/// Maps a single, non-extended, SGR parameter to its operation.
+#[allow(clippy::cast_possible_truncation)]
+fn simple(code: u16) -> Sgr {
+    match code {
+        0 => Sgr::Reset,
+        1 => Sgr::Set(AnsiFlags::BOLD),
+        2 => Sgr::Set(AnsiFlags::DIM),
+        3 => Sgr::Set(AnsiFlags::ITALIC),
+        4 => Sgr::Set(AnsiFlags::UNDERLINE),
+        5 => Sgr::Set(AnsiFlags::BLINK),
+        7 => Sgr::Set(AnsiFlags::REVERSE),
+        8 => Sgr::Set(AnsiFlags::CONCEAL),
+        9 => Sgr::Set(AnsiFlags::STRIKE),
+        10..=20 => Sgr::Font((code - 10) as u8),
+        22 => Sgr::Unset(AnsiFlags::BOLD.union(AnsiFlags::DIM)),
+        23 => Sgr::Unset(AnsiFlags::ITALIC),
+        24 => Sgr::Unset(AnsiFlags::UNDERLINE),
+        25 => Sgr::Unset(AnsiFlags::BLINK),
+        27 => Sgr::Unset(AnsiFlags::REVERSE),
+        28 => Sgr::Unset(AnsiFlags::CONCEAL),
+        29 => Sgr::Unset(AnsiFlags::STRIKE),
+        30..=37 => Sgr::Fg(AnsiColor::Indexed((code - 30) as u8)),
+        39 => Sgr::DefaultFg,
+        40..=47 => Sgr::Bg(AnsiColor::Indexed((code - 40) as u8)),
+        49 => Sgr::DefaultBg,
+        59 => Sgr::DefaultUnderlineColor,
+        90..=97 => Sgr::Fg(AnsiColor::Indexed((code - 90 + 8) as u8)),
+        100..=107 => Sgr::Bg(AnsiColor::Indexed((code - 100 + 8) as u8)),
+        code => Sgr::Unknown(code),
+    }
+}
+
+/// Builds an extended (`38` / `48` / `58`) color from the parameters following the code. When
+/// `colon_form` is true, the `2` form may contain an (ignored) color space id before the channels.
+fn extended_color(code: u16, rest: &[Option<u16>], colon_form: bool) -> Sgr {
+    fn byte(value: Option<&Option<u16>>) -> Option<u8> {
+        value.copied().flatten().and_then(|v| u8::try_from(v).ok())
+    }
+
+    let color = match rest.first().copied().flatten() {
+        Some(5) => byte(rest.get(1)).map(AnsiColor::Indexed),
+        Some(2) => {
+            let offset = usize::from(colon_form && rest.len() == 5);
+            match (
+                byte(rest.get(1 + offset)),
+                byte(rest.get(2 + offset)),
+                byte(rest.get(3 + offset)),
+            ) {
+                (Some(red), Some(green), Some(blue)) => {
+                    Some(AnsiColor::Rgb(Color::from_rgb(red, green, blue)))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+
+    match (code, color) {
+        (38, Some(color)) => Sgr::Fg(color),
+        (48, Some(color)) => Sgr::Bg(color),
+        (58, Some(color)) => Sgr::UnderlineColor(color),
+        (code, _) => Sgr::Unknown(code),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn basic() {
+        assert_eq!(parse_sgr(""), vec![Sgr::Reset]);
+        assert_eq!(
+            parse_sgr("1;31;42"),
+            vec![
+                Sgr::Set(AnsiFlags::BOLD),
+                Sgr::Fg(AnsiColor::Indexed(1)),
+                Sgr::Bg(AnsiColor::Indexed(2))
+            ]
+        );
+        assert_eq!(
+            parse_sgr("22;39;49;97"),
+            vec![
+                Sgr::Unset(AnsiFlags::BOLD | AnsiFlags::DIM),
+                Sgr::DefaultFg,
+                Sgr::DefaultBg,
+                Sgr::Fg(AnsiColor::Indexed(15))
+            ]
+        );
+        assert_eq!(
+            parse_sgr("8;28"),
+            vec![Sgr::Set(AnsiFlags::CONCEAL), Sgr::Unset(AnsiFlags::CONCEAL)]
+        );
+        assert_eq!(
+            parse_sgr("10;13;20"),
+            vec![Sgr::Font(0), Sgr::Font(3), Sgr::Font(10)]
+        );
+        assert_eq!(
+            parse_sgr("6;x"),
+            vec![Sgr::Unknown(6), Sgr::Unknown(u16::MAX)]
+        );
+    }
+
+    #[test]
+    fn extended() {
+        assert_eq!(
+            parse_sgr("38;2;1;2;3;48;5;200"),
+            vec![
+                Sgr::Fg(AnsiColor::Rgb(Color::from_rgb(1, 2, 3))),
+                Sgr::Bg(AnsiColor::Indexed(200))
+            ]
+        );
+        assert_eq!(
+            parse_sgr("38:2::1:2:3;48:2:4:5:6;38:5:9"),
+            vec![
+                Sgr::Fg(AnsiColor::Rgb(Color::from_rgb(1, 2, 3))),
+                Sgr::Bg(AnsiColor::Rgb(Color::from_rgb(4, 5, 6))),
+                Sgr::Fg(AnsiColor::Indexed(9))
+            ]
+        );
+        assert_eq!(parse_sgr("38;2;1;2"), vec![Sgr::Unknown(38)]);
+        assert_eq!(parse_sgr("48;7"), vec![Sgr::Unknown(48)]);
+        assert_eq!(
+            parse_sgr("58;5;3;58:2::9:8:7;59"),
+            vec![
+                Sgr::UnderlineColor(AnsiColor::Indexed(3)),
+                Sgr::UnderlineColor(AnsiColor::Rgb(Color::from_rgb(9, 8, 7))),
+                Sgr::DefaultUnderlineColor
+            ]
+        );
+    }
+}
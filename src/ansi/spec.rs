@@ -0,0 +1,496 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::describe::{BASIC_NAMES, FLAG_NAMES};
+use super::sgr::Sgr;
+use crate::{Ansi, AnsiColor, Color, StyleSpecError};
+
+impl Ansi {
+    /// Parses a human readable style description, like `bold red on black` or
+    /// `italic #ff8000 underline-color color(200)`, for config files and environment variables.
+    /// This is the same format the alternate [`Debug`](std::fmt::Debug) output (`{:#?}`) uses
+    /// to describe a style, e.g. `Ansi⟨bold red on black⟩`.
+    ///
+    /// The spec is a list of whitespace separated, case insensitive, words:
+    /// - Attributes: `bold`, `dim`, `italic`, `underline`, `blink`, `reverse`, `conceal` and
+    ///   `strike`.
+    /// - Fonts: `font-1` to `font-9`, `fraktur` and `default-font`.
+    /// - A color sets the foreground, `on <color>` the background and
+    ///   `underline-color <color>` the underline color.
+    /// - Colors are one of the 16 basic names (`red`, `bright-blue`), a 256-color index as
+    ///   `color(n)`, where `n` is written without a sign or leading zeros, a hex color
+    ///   (`#ff8000` or `#f80`), `default`, or, with the `named-colors` feature, a named color
+    ///   like `orange`.
+    ///
+    /// ## Errors
+    /// - [`StyleSpecError::UnknownWord`] if a word is not understood.
+    /// - [`StyleSpecError::MissingColor`] if `on` or `underline-color` is not followed by a
+    ///   color.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Ansi;
+    /// let style = Ansi::parse_spec("bold #ff5555 on black").unwrap();
+    /// assert_eq!(style, Ansi::from_fg((255, 85, 85)).bg_indexed(0).bold());
+    /// assert!(Ansi::parse_spec("bold sparkly").is_err());
+    /// ```
+    pub fn parse_spec(spec: &str) -> Result<Ansi, StyleSpecError> {
+        let mut ansi = Ansi::new();
+        let mut words = spec.split_whitespace().map(str::to_lowercase);
+        while let Some(word) = words.next() {
+            let op = match word.as_str() {
+                "on" | "underline-color" => {
+                    let color = words
+                        .next()
+                        .ok_or_else(|| StyleSpecError::MissingColor(word.clone()))?;
+                    match (word.as_str(), parse_color(&color)) {
+                        (_, Err(())) => return Err(StyleSpecError::MissingColor(word)),
+                        ("on", Ok(Some(color))) => Sgr::Bg(color),
+                        ("on", Ok(None)) => Sgr::DefaultBg,
+                        (_, Ok(Some(color))) => Sgr::UnderlineColor(color),
+                        (_, Ok(None)) => Sgr::DefaultUnderlineColor,
+                    }
+                }
+                "default-font" => Sgr::Font(0),
+                "fraktur" => Sgr::Font(10),
+                word => {
+                    if let Some((flag, _)) = FLAG_NAMES.iter().find(|(_, name)| *name == word) {
+                        Sgr::Set(*flag)
+                    } else if let Some(&[font @ b'1'..=b'9']) =
+                        word.strip_prefix("font-").map(str::as_bytes)
+                    {
+                        Sgr::Font(font - b'0')
+                    } else {
+                        match parse_color(word) {
+                            Ok(Some(color)) => Sgr::Fg(color),
+                            Ok(None) => Sgr::DefaultFg,
+                            Err(()) => return Err(StyleSpecError::UnknownWord(word.to_string())),
+                        }
+                    }
+                }
+            };
+            ansi.apply_sgr(op)
+                .map_err(|_| StyleSpecError::UnknownWord(word))?;
+        }
+        Ok(ansi)
+    }
+}
+
+impl Ansi {
+    /// Parses a style spec like [`Ansi::parse_spec`] does, panicking if it is invalid.
+    ///
+    /// Unlike [`Ansi::parse_spec`] this is a `const fn`, so it can be used to declare styles
+    /// as constants, where an invalid spec is a compile time error. Words are only split on
+    /// ASCII whitespace. With the `macros` feature, see also the `styles!` macro.
+    ///
+    /// ```
+    /// # use ansirs::Ansi;
+    /// const ERROR: Ansi = Ansi::parse_spec_unwrap("bold red on #202020");
+    /// assert_eq!(Ansi::parse_spec("bold red on #202020"), Ok(ERROR));
+    /// ```
+    ///
+    /// ## Panics
+    /// - If `spec` is not a valid style spec.
+    #[must_use]
+    pub const fn parse_spec_unwrap(spec: &str) -> Ansi {
+        let spec = spec.as_bytes();
+        let mut ansi = Ansi::new();
+        let mut pos = 0;
+        while let Some((start, end)) = next_word(spec, pos) {
+            pos = end;
+            let word = sub_slice(spec, start, end);
+            let op = if eq_ignore_case(word, b"on") || eq_ignore_case(word, b"underline-color") {
+                let Some((start, end)) = next_word(spec, pos) else {
+                    panic!("Missing color after `on` or `underline-color` in style spec");
+                };
+                pos = end;
+                match (
+                    eq_ignore_case(word, b"on"),
+                    const_color(sub_slice(spec, start, end)),
+                ) {
+                    (_, Err(())) => panic!("Invalid color after `on` or `underline-color`"),
+                    (true, Ok(Some(color))) => Sgr::Bg(color),
+                    (true, Ok(None)) => Sgr::DefaultBg,
+                    (false, Ok(Some(color))) => Sgr::UnderlineColor(color),
+                    (false, Ok(None)) => Sgr::DefaultUnderlineColor,
+                }
+            } else if eq_ignore_case(word, b"default-font") {
+                Sgr::Font(0)
+            } else if eq_ignore_case(word, b"fraktur") {
+                Sgr::Font(10)
+            } else if let Some(flag) = const_flag(word) {
+                Sgr::Set(flag)
+            } else if word.len() == 6
+                && eq_ignore_case(sub_slice(word, 0, 5), b"font-")
+                && matches!(word[5], b'1'..=b'9')
+            {
+                Sgr::Font(word[5] - b'0')
+            } else {
+                match const_color(word) {
+                    Ok(Some(color)) => Sgr::Fg(color),
+                    Ok(None) => Sgr::DefaultFg,
+                    Err(()) => panic!("Unknown word in style spec"),
+                }
+            };
+            ansi = ansi.with_sgr(op);
+        }
+        ansi
+    }
+}
+
+/// Finds the next whitespace separated word in `bytes`, starting at `pos`, as a start and end
+/// index.
+const fn next_word(bytes: &[u8], mut pos: usize) -> Option<(usize, usize)> {
+    while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    if pos == bytes.len() {
+        return None;
+    }
+    let start = pos;
+    while pos < bytes.len() && !bytes[pos].is_ascii_whitespace() {
+        pos += 1;
+    }
+    Some((start, pos))
+}
+
+/// Gets `bytes[start..end]`, which can not be written as such in a `const fn`.
+const fn sub_slice(bytes: &[u8], start: usize, end: usize) -> &[u8] {
+    bytes.split_at(end).0.split_at(start).1
+}
+
+/// Compares two words, ignoring ASCII case.
+const fn eq_ignore_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut idx = 0;
+    while idx < a.len() {
+        if !a[idx].eq_ignore_ascii_case(&b[idx]) {
+            return false;
+        }
+        idx += 1;
+    }
+    true
+}
+
+/// Gets the flag named by `word`, in a `const fn`.
+const fn const_flag(word: &[u8]) -> Option<crate::AnsiFlags> {
+    let mut idx = 0;
+    while idx < FLAG_NAMES.len() {
+        let (flag, name) = FLAG_NAMES[idx];
+        if eq_ignore_case(word, name.as_bytes()) {
+            return Some(flag);
+        }
+        idx += 1;
+    }
+    None
+}
+
+/// Like [`parse_color`], but in a `const fn` and for words in any case.
+const fn const_color(word: &[u8]) -> Result<Option<AnsiColor>, ()> {
+    if eq_ignore_case(word, b"default") {
+        return Ok(None);
+    }
+    let mut idx = 0;
+    while idx < BASIC_NAMES.len() {
+        if eq_ignore_case(word, BASIC_NAMES[idx].as_bytes()) {
+            // There are only 16 basic colors.
+            #[allow(clippy::cast_possible_truncation)]
+            return Ok(Some(AnsiColor::Indexed(idx as u8)));
+        }
+        idx += 1;
+    }
+    if word.len() > 7
+        && eq_ignore_case(sub_slice(word, 0, 6), b"color(")
+        && word[word.len() - 1] == b')'
+    {
+        let digits = sub_slice(word, 6, word.len() - 1);
+        if digits.len() > 1 && digits[0] == b'0' {
+            return Err(());
+        }
+        let mut value: u16 = 0;
+        let mut idx = 0;
+        while idx < digits.len() {
+            // Stops at 4 digits, before the value can overflow.
+            if !digits[idx].is_ascii_digit() || value > 25 {
+                return Err(());
+            }
+            value = value * 10 + (digits[idx] - b'0') as u16;
+            idx += 1;
+        }
+        return match value {
+            256.. => Err(()),
+            // Checked to be at most 255 above.
+            #[allow(clippy::cast_possible_truncation)]
+            value => Ok(Some(AnsiColor::Indexed(value as u8))),
+        };
+    }
+    if let [b'#', ..] = word {
+        let Ok(hex) = std::str::from_utf8(word) else {
+            return Err(());
+        };
+        return Ok(Some(AnsiColor::Rgb(Color::from_hex_unwrap(hex))));
+    }
+    const_named_color(word)
+}
+
+/// Gets the named color called `word`, in a `const fn`.
+#[cfg(feature = "named-colors")]
+const fn const_named_color(word: &[u8]) -> Result<Option<AnsiColor>, ()> {
+    use crate::Colors;
+
+    let mut named = Colors::AliceBlue;
+    loop {
+        if eq_ignore_case(word, named.name().as_bytes()) {
+            return Ok(Some(AnsiColor::Rgb(named.into_color())));
+        }
+        named = named.next();
+        if matches!(named, Colors::AliceBlue) {
+            return Err(());
+        }
+    }
+}
+
+/// Named colors need the `named-colors` feature.
+#[cfg(not(feature = "named-colors"))]
+const fn const_named_color(_word: &[u8]) -> Result<Option<AnsiColor>, ()> {
+    Err(())
+}
+
+/// Defines constant [`Ansi`] styles from [style specs](Ansi::parse_spec), which are parsed at
+/// compile time, so an invalid spec is a compile error. Each entry is `NAME: "spec";`,
+/// optionally with attributes (e.g. doc comments) and a visibility. Requires the `macros`
+/// feature.
+///
+/// ## Example
+/// ```
+/// # #[cfg(feature = "macros")]
+/// # {
+/// use ansirs::{styles, Ansi};
+///
+/// styles! {
+///     /// Style for errors.
+///     pub ERROR: "bold red";
+///     WARN: "yellow";
+///     OK: "green";
+/// }
+///
+/// assert_eq!(ERROR, Ansi::new().bold().fg_indexed(1));
+/// assert_eq!(OK, Ansi::new().fg_indexed(2));
+/// # }
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! styles {
+    ($($(#[$meta:meta])* $vis:vis $name:ident : $spec:expr);* $(;)?) => {
+        $(
+            $(#[$meta])*
+            $vis const $name: $crate::Ansi = $crate::Ansi::parse_spec_unwrap($spec);
+        )*
+    };
+}
+
+/// Parses a lowercase color word, where `default` is `None`.
+fn parse_color(word: &str) -> Result<Option<AnsiColor>, ()> {
+    if word == "default" {
+        return Ok(None);
+    }
+    if let Some(idx) = BASIC_NAMES.iter().position(|name| *name == word) {
+        return u8::try_from(idx)
+            .map(|idx| Some(AnsiColor::Indexed(idx)))
+            .map_err(|_| ());
+    }
+    if let Some(idx) = word
+        .strip_prefix("color(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        // Only plain decimal numbers, like `parse_spec_unwrap` takes them.
+        let plain = !idx.is_empty()
+            && idx.bytes().all(|byte| byte.is_ascii_digit())
+            && (idx == "0" || !idx.starts_with('0'));
+        if !plain {
+            return Err(());
+        }
+        return idx
+            .parse()
+            .map(|idx| Some(AnsiColor::Indexed(idx)))
+            .map_err(|_| ());
+    }
+    if word.starts_with('#') {
+        return Color::from_hex(word)
+            .map(|color| Some(AnsiColor::Rgb(color)))
+            .map_err(|_| ());
+    }
+    #[cfg(feature = "named-colors")]
+    if let Some(color) = crate::Colors::from_name_ignore_case(word) {
+        use crate::ToColor;
+
+        return Ok(Some(AnsiColor::Rgb(color.to_color())));
+    }
+    Err(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::describe::describe_ansi;
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn words() {
+        assert_eq!(Ansi::parse_spec(""), Ok(Ansi::new()));
+        assert_eq!(Ansi::parse_spec("default"), Ok(Ansi::new()));
+        assert_eq!(
+            Ansi::parse_spec("  BOLD\tItalic strike conceal "),
+            Ok(Ansi::new().bold().italic().strike().conceal())
+        );
+        assert_eq!(
+            Ansi::parse_spec("bright-green on color(236) underline-color #f80"),
+            Ok(Ansi::new().fg_indexed(10).bg_indexed(236).ul((255, 136, 0)))
+        );
+        assert_eq!(
+            Ansi::parse_spec("red blue on red on default"),
+            Ok(Ansi::new().fg_indexed(4))
+        );
+        assert_eq!(Ansi::parse_spec("font-3"), Ok(Ansi::new().font(3)));
+        assert_eq!(Ansi::parse_spec("fraktur default-font"), Ok(Ansi::new()));
+    }
+
+    #[cfg(feature = "named-colors")]
+    #[test]
+    fn named_colors() {
+        assert_eq!(
+            Ansi::parse_spec("orange on navy"),
+            Ok(Ansi::from_fg(crate::Colors::Orange).bg(crate::Colors::Navy))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Ansi::parse_spec("bold sparkly"),
+            Err(StyleSpecError::UnknownWord("sparkly".to_string()))
+        );
+        assert_eq!(
+            Ansi::parse_spec("font-10"),
+            Err(StyleSpecError::UnknownWord("font-10".to_string()))
+        );
+        assert_eq!(
+            Ansi::parse_spec("red on"),
+            Err(StyleSpecError::MissingColor("on".to_string()))
+        );
+        assert_eq!(
+            Ansi::parse_spec("underline-color bold"),
+            Err(StyleSpecError::MissingColor("underline-color".to_string()))
+        );
+        assert_eq!(
+            Ansi::parse_spec("color(256)"),
+            Err(StyleSpecError::UnknownWord("color(256)".to_string()))
+        );
+    }
+
+    #[test]
+    fn const_specs() {
+        const STYLE: Ansi =
+            Ansi::parse_spec_unwrap(" Bold\tred ON color(17) underline-color #F80 ");
+        assert_eq!(
+            STYLE,
+            Ansi::new()
+                .bold()
+                .fg_indexed(1)
+                .bg_indexed(17)
+                .ul((255, 136, 0))
+        );
+
+        let specs = [
+            "",
+            "default",
+            "italic strike conceal dim blink reverse underline",
+            "bright-green on color(236) underline-color #ff8000",
+            "red blue on red on default underline-color default",
+            "font-3",
+            "fraktur default-font",
+            "color(0) on color(255)",
+        ];
+        for spec in specs {
+            assert_eq!(
+                Ansi::parse_spec(spec),
+                Ok(Ansi::parse_spec_unwrap(spec)),
+                "{spec}"
+            );
+        }
+        #[cfg(feature = "named-colors")]
+        for (_, _, name) in crate::Colors::all_pairs() {
+            assert_eq!(Ansi::parse_spec(name), Ok(Ansi::parse_spec_unwrap(name)));
+        }
+    }
+
+    #[test]
+    fn const_spec_errors() {
+        for spec in [
+            "color(256)",
+            "color()",
+            "font-10",
+            "bold sparkly",
+            "red on",
+            "on bold",
+        ] {
+            assert!(Ansi::parse_spec(spec).is_err());
+            assert!(std::panic::catch_unwind(|| Ansi::parse_spec_unwrap(spec)).is_err());
+        }
+    }
+
+    #[test]
+    fn same_grammar() {
+        let specs = [
+            "color(5)",
+            "color(+5)",
+            "color(-5)",
+            "color(05)",
+            "color(00)",
+            "color(0)",
+            "color(255)",
+            "color(0255)",
+            "color( 5)",
+            "on color(+1)",
+            "font-1",
+            "font-9",
+            "font-0",
+            "font-03",
+            "font-+3",
+            "font-",
+            "FONT-4",
+            "#f80",
+            "#ff8000",
+            "#ff800",
+            "#+f8",
+            "bright-red",
+            "bright_red",
+        ];
+        for spec in specs {
+            let parsed = Ansi::parse_spec(spec).ok();
+            let unwrapped = std::panic::catch_unwind(|| Ansi::parse_spec_unwrap(spec)).ok();
+            assert_eq!(parsed, unwrapped, "{spec}");
+        }
+    }
+
+    #[test]
+    fn describe_round_trip() {
+        let styles = [
+            Ansi::new(),
+            Ansi::from_fg((1, 2, 3))
+                .bg_indexed(100)
+                .ul_indexed(9)
+                .blink(),
+            Ansi::new().fraktur().dim().reverse(),
+            Ansi::new().fg_indexed(200).font(7),
+        ];
+        for style in styles {
+            assert_eq!(Ansi::parse_spec(&describe_ansi(&style)), Ok(style));
+        }
+    }
+}
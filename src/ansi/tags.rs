@@ -0,0 +1,409 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::describe::BASIC_NAMES;
+use super::sgr::{parse_sgr, Sgr};
+use crate::{AnsiColor, AnsiFlags, AnsiTokenizer, Color, Token};
+
+/// Tag names of the flags, in the order they are opened.
+const FLAG_TAGS: [(AnsiFlags, &str); 8] = [
+    (AnsiFlags::BOLD, "b"),
+    (AnsiFlags::DIM, "dim"),
+    (AnsiFlags::ITALIC, "i"),
+    (AnsiFlags::UNDERLINE, "u"),
+    (AnsiFlags::BLINK, "blink"),
+    (AnsiFlags::REVERSE, "reverse"),
+    (AnsiFlags::CONCEAL, "conceal"),
+    (AnsiFlags::STRIKE, "s"),
+];
+
+/// The styling in effect at some point in a string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct TagState {
+    fg: Option<AnsiColor>,
+    bg: Option<AnsiColor>,
+    ul: Option<AnsiColor>,
+    flags: AnsiFlags,
+    font: u8,
+}
+
+impl TagState {
+    fn apply(&mut self, op: Sgr) {
+        match op {
+            Sgr::Reset => *self = Self::default(),
+            Sgr::Set(flags) => self.flags.insert(flags),
+            Sgr::Unset(flags) => self.flags.remove(flags),
+            Sgr::Fg(color) => self.fg = Some(color),
+            Sgr::Bg(color) => self.bg = Some(color),
+            Sgr::DefaultFg => self.fg = None,
+            Sgr::DefaultBg => self.bg = None,
+            Sgr::UnderlineColor(color) => self.ul = Some(color),
+            Sgr::DefaultUnderlineColor => self.ul = None,
+            Sgr::Font(font) => self.font = font,
+            Sgr::Unknown(_) => {}
+        }
+    }
+
+    /// The opening tags (without brackets) that produce this state.
+    fn tags(&self) -> Vec<String> {
+        let mut tags = Vec::new();
+        match self.fg {
+            Some(AnsiColor::Indexed(idx)) if idx < 16 => {
+                tags.push(BASIC_NAMES[usize::from(idx)].to_string());
+            }
+            Some(color) => tags.push(format!("fg={}", color_value(color))),
+            None => {}
+        }
+        if let Some(color) = self.bg {
+            tags.push(format!("bg={}", color_value(color)));
+        }
+        if let Some(color) = self.ul {
+            tags.push(format!("ul={}", color_value(color)));
+        }
+        tags.extend(
+            FLAG_TAGS
+                .iter()
+                .filter(|(flag, _)| self.flags.contains(*flag))
+                .map(|(_, name)| (*name).to_string()),
+        );
+        if self.font != 0 {
+            tags.push(format!("font={}", self.font));
+        }
+        tags
+    }
+
+    /// The SGR parameters that produce this state from a reset terminal.
+    fn codes(&self) -> String {
+        let mut codes = [
+            (AnsiFlags::BOLD, "1"),
+            (AnsiFlags::DIM, "2"),
+            (AnsiFlags::ITALIC, "3"),
+            (AnsiFlags::UNDERLINE, "4"),
+            (AnsiFlags::BLINK, "5"),
+            (AnsiFlags::REVERSE, "7"),
+            (AnsiFlags::CONCEAL, "8"),
+            (AnsiFlags::STRIKE, "9"),
+        ]
+        .iter()
+        .filter(|(flag, _)| self.flags.contains(*flag))
+        .map(|(_, code)| (*code).to_string())
+        .collect::<Vec<_>>();
+        if self.font != 0 {
+            codes.push((10 + self.font).to_string());
+        }
+
+        for (color, base, bright, extended) in [(self.fg, 30, 90, 38), (self.bg, 40, 100, 48)] {
+            match color {
+                Some(AnsiColor::Indexed(idx)) if idx < 8 => codes.push(format!("{}", base + idx)),
+                Some(AnsiColor::Indexed(idx)) if idx < 16 => {
+                    codes.push(format!("{}", bright + idx - 8));
+                }
+                Some(AnsiColor::Indexed(idx)) => codes.push(format!("{extended};5;{idx}")),
+                Some(AnsiColor::Rgb(color)) => {
+                    let (r, g, b) = color.rgb();
+                    codes.push(format!("{extended};2;{r};{g};{b}"));
+                }
+                None => {}
+            }
+        }
+        if let Some(color) = self.ul {
+            let mut code = String::new();
+            color
+                .write_codes(58, &mut code)
+                .expect("Failed to write! to string");
+            codes.push(code);
+        }
+        codes.join(";")
+    }
+}
+
+fn color_value(color: AnsiColor) -> String {
+    match color {
+        AnsiColor::Indexed(idx) if idx < 16 => BASIC_NAMES[usize::from(idx)].to_string(),
+        AnsiColor::Indexed(idx) => idx.to_string(),
+        AnsiColor::Rgb(color) => color.as_hex_lower(),
+    }
+}
+
+fn parse_color_value(value: &str) -> Option<AnsiColor> {
+    if let Some(idx) = BASIC_NAMES.iter().position(|name| *name == value) {
+        return u8::try_from(idx).ok().map(AnsiColor::Indexed);
+    }
+    if value.starts_with('#') {
+        return Color::from_hex(value).ok().map(AnsiColor::Rgb);
+    }
+    value.parse::<u8>().ok().map(AnsiColor::Indexed)
+}
+
+/// Parses the inside of an opening tag into the operation it applies.
+fn parse_tag(tag: &str) -> Option<Sgr> {
+    if let Some(font) = tag.strip_prefix("font=") {
+        return font
+            .parse::<u8>()
+            .ok()
+            .filter(|font| *font <= 10)
+            .map(Sgr::Font);
+    }
+    if let Some((name, value)) = tag.split_once('=') {
+        let color = parse_color_value(value)?;
+        return match name {
+            "fg" => Some(Sgr::Fg(color)),
+            "bg" => Some(Sgr::Bg(color)),
+            "ul" => Some(Sgr::UnderlineColor(color)),
+            _ => None,
+        };
+    }
+    if let Some((flag, _)) = FLAG_TAGS.iter().find(|(_, name)| *name == tag) {
+        return Some(Sgr::Set(*flag));
+    }
+    parse_color_value(tag)
+        .filter(|color| matches!(color, AnsiColor::Indexed(idx) if *idx < 16))
+        .map(Sgr::Fg)
+}
+
+/// Writes `text` with every `<` doubled, so it cannot be mistaken for a tag.
+fn push_escaped(out: &mut String, text: &str) {
+    for (idx, part) in text.split('<').enumerate() {
+        if idx > 0 {
+            out.push_str("<<");
+        }
+        out.push_str(part);
+    }
+}
+
+/// Renders styled text with every SGR escape sequence replaced by stable, nested, textual tags,
+/// e.g. `\x1b[1;31mtext\x1b[0m` becomes `<red><b>text</b></red>`. This makes snapshots
+/// (golden files, `insta`, etc.) of styled output readable, diffable and independent of how
+/// the escape sequences happened to be written. Use [`from_tags`] to turn the tags back into
+/// escape sequences.
+///
+/// - The 16 basic foreground colors use their name as the tag (`<red>`, `<bright-blue>`).
+/// - Other foreground colors are written as `<fg=#ff8000>` or `<fg=200>` (256-colors),
+///   background colors as `<bg=...>` and underline colors as `<ul=...>`.
+/// - The flags are `<b>`, `<dim>`, `<i>`, `<u>`, `<blink>`, `<reverse>`, `<conceal>` and
+///   `<s>`.
+/// - Fonts are written as `<font=n>`, with `n` as in [`Ansi::font`](crate::Ansi::font).
+/// - A literal `<` in the text is written as `<<`.
+///
+/// Escape sequences that do not change the styling are left as they are.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{to_tags, Ansi};
+/// assert_eq!(to_tags("\x1b[1;31mtext\x1b[0m"), "<red><b>text</b></red>");
+///
+/// let styled = Ansi::from_fg((255, 128, 0)).underline().paint_text("a < b");
+/// assert_eq!(to_tags(&styled), "<fg=#ff8000><u>a << b</u></fg>");
+/// ```
+#[must_use]
+pub fn to_tags(text: &str) -> String {
+    fn closing(tag: &str) -> &str {
+        tag.split_once('=').map_or(tag, |(name, _)| name)
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut open: Vec<String> = Vec::new();
+    let mut state = TagState::default();
+
+    for token in AnsiTokenizer::new(text) {
+        match (token, token.sgr_params()) {
+            (_, Some(params)) => {
+                for op in parse_sgr(params) {
+                    state.apply(op);
+                }
+            }
+            (Token::Text(text), _) => {
+                let wanted = state.tags();
+                let keep = open
+                    .iter()
+                    .zip(&wanted)
+                    .take_while(|(open, wanted)| open == wanted)
+                    .count();
+                for tag in open.drain(keep..).rev() {
+                    out.push_str("</");
+                    out.push_str(closing(&tag));
+                    out.push('>');
+                }
+                for tag in &wanted[keep..] {
+                    out.push('<');
+                    out.push_str(tag);
+                    out.push('>');
+                }
+                open = wanted;
+                push_escaped(&mut out, text);
+            }
+            (sequence, None) => out.push_str(sequence.as_str()),
+        }
+    }
+
+    for tag in open.iter().rev() {
+        out.push_str("</");
+        out.push_str(closing(tag));
+        out.push('>');
+    }
+    out
+}
+
+/// Converts text produced by [`to_tags`] back into text styled with escape sequences, so
+/// tagged snapshots can be displayed in a terminal.
+///
+/// Tags do not have to be closed in order, a closing tag ends the most recently opened tag with
+/// the same name. Anything that looks like a tag but is not understood is kept as plain text.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{from_tags, to_tags, Ansi};
+/// assert_eq!(from_tags("<red><b>text</b></red>"), "\x1b[1;31mtext\x1b[0m");
+///
+/// let styled = Ansi::from_fg((255, 128, 0)).bold().paint_text("hi");
+/// assert_eq!(from_tags(&to_tags(&styled)), styled);
+/// ```
+#[must_use]
+pub fn from_tags(text: &str) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::with_capacity(text.len());
+    let mut open: Vec<(String, Sgr)> = Vec::new();
+    let mut written = TagState::default();
+    let mut rest = text;
+
+    let mut push_text = |out: &mut String, open: &[(String, Sgr)], text: &str| {
+        let mut state = TagState::default();
+        for (_, op) in open {
+            state.apply(*op);
+        }
+        if state != written {
+            if state == TagState::default() {
+                out.push_str("\x1b[0m");
+            } else if written == TagState::default() {
+                write!(out, "\x1b[{}m", state.codes()).expect("Failed to write! to string");
+            } else {
+                write!(out, "\x1b[0;{}m", state.codes()).expect("Failed to write! to string");
+            }
+            written = state;
+        }
+        out.push_str(text);
+    };
+
+    while let Some(start) = rest.find('<') {
+        let (before, tail) = rest.split_at(start);
+        if !before.is_empty() {
+            push_text(&mut out, &open, before);
+        }
+
+        if let Some(tail) = tail.strip_prefix("<<") {
+            push_text(&mut out, &open, "<");
+            rest = tail;
+            continue;
+        }
+
+        let tag = tail[1..].find('>').map(|end| &tail[1..=end]);
+        let handled = tag.is_some_and(|tag| {
+            if let Some(name) = tag.strip_prefix('/') {
+                let Some(idx) = open.iter().rposition(|(open, _)| open == name) else {
+                    return false;
+                };
+                open.remove(idx);
+                true
+            } else if let Some(op) = parse_tag(tag) {
+                let name = tag.split_once('=').map_or(tag, |(name, _)| name);
+                open.push((name.to_string(), op));
+                true
+            } else {
+                false
+            }
+        });
+
+        if let (true, Some(tag)) = (handled, tag) {
+            rest = &tail[tag.len() + 2..];
+        } else {
+            push_text(&mut out, &open, "<");
+            rest = &tail[1..];
+        }
+    }
+    if !rest.is_empty() {
+        push_text(&mut out, &open, rest);
+    }
+    if written != TagState::default() {
+        out.push_str("\x1b[0m");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Ansi;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn nested_tags() {
+        assert_eq!(to_tags("plain"), "plain");
+        assert_eq!(
+            to_tags("\u{1b}[1mbold \u{1b}[3mboth\u{1b}[22m italic\u{1b}[0m done"),
+            "<b>bold <i>both</i></b><i> italic</i> done"
+        );
+        assert_eq!(
+            to_tags("\u{1b}[92;44;38;5;200mx\u{1b}[39my\u{1b}[m"),
+            "<fg=200><bg=blue>x</bg></fg><bg=blue>y</bg>"
+        );
+    }
+
+    #[test]
+    fn equivalent_escapes_give_equal_tags() {
+        let a = "\u{1b}[1m\u{1b}[31mx\u{1b}[0m";
+        let b = "\u{1b}[31;1mx\u{1b}[m";
+        assert_eq!(to_tags(a), to_tags(b));
+        assert_eq!(to_tags(a), "<red><b>x</b></red>");
+    }
+
+    #[test]
+    fn other_sequences_are_kept() {
+        assert_eq!(to_tags("\u{1b}[2Ka"), "\u{1b}[2Ka");
+    }
+
+    #[test]
+    fn round_trip() {
+        let styled = format!(
+            "{}{}{}",
+            Ansi::red().bold().paint_text("a<b"),
+            "plain",
+            Ansi::from_bg((1, 2, 3)).dim().strike().paint_text("c")
+        );
+        let tags = to_tags(&styled);
+        assert_eq!(
+            tags,
+            "<fg=#ff0000><b>a<<b</b></fg>plain<bg=#010203><dim><s>c</s></dim></bg>"
+        );
+        assert_eq!(from_tags(&tags), styled);
+    }
+
+    #[test]
+    fn fonts() {
+        let styled = Ansi::new().italic().font(3).paint_text("x");
+        assert_eq!(to_tags(&styled), "<i><font=3>x</font></i>");
+        assert_eq!(from_tags("<i><font=3>x</font></i>"), styled);
+        assert_eq!(from_tags("<font=11>x</font>"), "<font=11>x</font>");
+    }
+
+    #[test]
+    fn parse_tags() {
+        assert_eq!(
+            from_tags("<bright-red>a<bg=7><u>b</bg>c</u></bright-red>"),
+            "\u{1b}[91ma\u{1b}[0;4;91;47mb\u{1b}[0;4;91mc\u{1b}[0m"
+        );
+        assert_eq!(from_tags("a <unknown> </b> <b"), "a <unknown> </b> <b");
+        assert_eq!(from_tags("<fg=#zzz>"), "<fg=#zzz>");
+        assert_eq!(
+            from_tags("<u><ul=200>x</ul></u>"),
+            "\u{1b}[4;58;5;200mx\u{1b}[0m"
+        );
+        assert_eq!(
+            to_tags("\u{1b}[4;58;5;200mx\u{1b}[0m"),
+            "<ul=200><u>x</u></ul>"
+        );
+    }
+}
@@ -0,0 +1,294 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+/// A piece of (possibly) styled text, as produced by [`AnsiTokenizer`]. Every token borrows
+/// from the input, and concatenating the tokens in order gives back the input exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Token<'a> {
+    /// Plain text, without any escape characters.
+    Text(&'a str),
+    /// A complete CSI (Control Sequence Introducer) sequence, such as `\x1b[1;31m` or `\x1b[2K`,
+    /// including the leading `ESC[` and the final byte.
+    Csi(&'a str),
+    /// A complete OSC (Operating System Command) sequence, such as a hyperlink or window title,
+    /// including the leading `ESC]` and the terminator (`BEL` or `ESC\`).
+    Osc(&'a str),
+    /// Any other escape sequence (e.g. `ESC 7`, `ESC ( B`, or a DCS / APC string such as
+    /// `ESC P ... ESC\`), a lone `ESC`, or a CSI / OSC sequence that is cut off by the end of
+    /// the input.
+    Esc(&'a str),
+}
+
+impl<'a> Token<'a> {
+    /// Gets the text of this token, exactly as it appeared in the input.
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        match self {
+            Token::Text(s) | Token::Csi(s) | Token::Osc(s) | Token::Esc(s) => s,
+        }
+    }
+
+    /// Returns `true` if this token is plain text.
+    #[must_use]
+    pub const fn is_text(&self) -> bool {
+        matches!(self, Token::Text(_))
+    }
+
+    /// If this token is an SGR (styling) sequence, gets its parameters, i.e. the part between
+    /// `ESC[` and `m`.
+    ///
+    /// ```
+    /// # use ansirs::Token;
+    /// assert_eq!(Token::Csi("\x1b[1;31m").sgr_params(), Some("1;31"));
+    /// assert_eq!(Token::Csi("\x1b[2K").sgr_params(), None);
+    /// ```
+    #[must_use]
+    pub fn sgr_params(&self) -> Option<&'a str> {
+        let Token::Csi(seq) = self else {
+            return None;
+        };
+        let params = seq.strip_prefix("\x1b[")?.strip_suffix('m')?;
+        // Private sequences (`ESC[?...m`, `ESC[>...m`) are not SGR.
+        params
+            .bytes()
+            .all(|b| b.is_ascii_digit() || b == b';' || b == b':')
+            .then_some(params)
+    }
+}
+
+/// Iterator splitting text into plain text and escape sequences without allocating.
+///
+/// This is the building block for everything in this crate that needs to look inside styled
+/// text (stripping, measuring, converting), and can be used for custom processing of mixed
+/// styled input as well.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{AnsiTokenizer, Token};
+/// let input = "\x1b[1mbold\x1b[0m \x1b]8;;https://example.com\x07link\x1b]8;;\x07";
+/// let tokens = AnsiTokenizer::new(input).collect::<Vec<_>>();
+/// assert_eq!(
+///     tokens,
+///     vec![
+///         Token::Csi("\x1b[1m"),
+///         Token::Text("bold"),
+///         Token::Csi("\x1b[0m"),
+///         Token::Text(" "),
+///         Token::Osc("\x1b]8;;https://example.com\x07"),
+///         Token::Text("link"),
+///         Token::Osc("\x1b]8;;\x07"),
+///     ]
+/// );
+///
+/// let plain = AnsiTokenizer::new(input)
+///     .filter(Token::is_text)
+///     .map(|token| token.as_str())
+///     .collect::<String>();
+/// assert_eq!(plain, "bold link");
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnsiTokenizer<'a> {
+    rest: &'a str,
+}
+
+impl<'a> AnsiTokenizer<'a> {
+    /// Creates a new tokenizer over `text`.
+    #[must_use]
+    pub const fn new(text: &'a str) -> Self {
+        Self { rest: text }
+    }
+
+    /// Gets the part of the input that has not been tokenized yet.
+    #[must_use]
+    pub const fn remainder(&self) -> &'a str {
+        self.rest
+    }
+
+    /// Finds the length of the escape sequence at the start of `self.rest`, which must start
+    /// with `ESC`, and whether it is complete.
+    fn escape_len(&self) -> (usize, bool) {
+        let bytes = self.rest.as_bytes();
+        match bytes.get(1) {
+            // CSI: parameter and intermediate bytes, then a final byte in 0x40..=0x7E.
+            Some(b'[') => bytes[2..]
+                .iter()
+                .position(|b| (0x40..=0x7e).contains(b))
+                .map_or((bytes.len(), false), |end| (end + 3, true)),
+            // OSC: terminated by BEL or ST (`ESC\`). The other control strings (DCS, SOS, PM
+            // and APC, used e.g. for terminal graphics) are only terminated by ST.
+            Some(&kind @ (b']' | b'P' | b'X' | b'^' | b'_')) => {
+                let body = &bytes[2..];
+                let bel = body
+                    .iter()
+                    .position(|b| *b == 0x07)
+                    .filter(|_| kind == b']')
+                    .map(|end| end + 3);
+                let st = body
+                    .windows(2)
+                    .position(|w| w == b"\x1b\\")
+                    .map(|end| end + 4);
+                match (bel, st) {
+                    (Some(bel), Some(st)) => (bel.min(st), true),
+                    (Some(end), None) | (None, Some(end)) => (end, true),
+                    (None, None) => (bytes.len(), false),
+                }
+            }
+            // Any other escape: intermediate bytes in 0x20..=0x2F (e.g. the `(` of the charset
+            // selection `ESC ( B`), then a single final character. An `ESC` starts the next
+            // sequence instead of ending this one.
+            Some(_) => {
+                let start = 1 + bytes[1..]
+                    .iter()
+                    .take_while(|b| (0x20..=0x2f).contains(*b))
+                    .count();
+                match self.rest[start..].chars().next() {
+                    Some(c) if c != '\x1b' => (start + c.len_utf8(), true),
+                    _ => (start, false),
+                }
+            }
+            None => (1, false),
+        }
+    }
+}
+
+impl<'a> Iterator for AnsiTokenizer<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let text_len = self.rest.find('\x1b').unwrap_or(self.rest.len());
+        if text_len > 0 {
+            let (text, rest) = self.rest.split_at(text_len);
+            self.rest = rest;
+            return Some(Token::Text(text));
+        }
+
+        let (len, complete) = self.escape_len();
+        let (seq, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Some(match seq.as_bytes().get(1) {
+            Some(b'[') if complete => Token::Csi(seq),
+            Some(b']') if complete => Token::Osc(seq),
+            _ => Token::Esc(seq),
+        })
+    }
+}
+
+impl std::iter::FusedIterator for AnsiTokenizer<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn tokens(text: &str) -> Vec<Token<'_>> {
+        AnsiTokenizer::new(text).collect()
+    }
+
+    #[test]
+    fn text_and_csi() {
+        assert!(tokens("").is_empty());
+        assert_eq!(tokens("plain"), vec![Token::Text("plain")]);
+        assert_eq!(
+            tokens("a\u{1b}[1;31mb\u{1b}[2K\u{1b}[?25l"),
+            vec![
+                Token::Text("a"),
+                Token::Csi("\u{1b}[1;31m"),
+                Token::Text("b"),
+                Token::Csi("\u{1b}[2K"),
+                Token::Csi("\u{1b}[?25l"),
+            ]
+        );
+    }
+
+    #[test]
+    fn osc() {
+        assert_eq!(
+            tokens("\u{1b}]0;title\u{1b}\\x\u{1b}]2;t\u{7}"),
+            vec![
+                Token::Osc("\u{1b}]0;title\u{1b}\\"),
+                Token::Text("x"),
+                Token::Osc("\u{1b}]2;t\u{7}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn other_and_incomplete() {
+        assert_eq!(
+            tokens("\u{1b}7é\u{1b}é"),
+            vec![
+                Token::Esc("\u{1b}7"),
+                Token::Text("é"),
+                Token::Esc("\u{1b}é")
+            ]
+        );
+        assert_eq!(
+            tokens("x\u{1b}"),
+            vec![Token::Text("x"), Token::Esc("\u{1b}")]
+        );
+        assert_eq!(tokens("\u{1b}[12"), vec![Token::Esc("\u{1b}[12")]);
+        assert_eq!(tokens("\u{1b}]8;;x"), vec![Token::Esc("\u{1b}]8;;x")]);
+    }
+
+    #[test]
+    fn control_strings() {
+        assert_eq!(
+            tokens("a\u{1b}_Gf=100;AAAA\u{1b}\\b\u{1b}Pq\u{7}#0\u{1b}\\"),
+            vec![
+                Token::Text("a"),
+                Token::Esc("\u{1b}_Gf=100;AAAA\u{1b}\\"),
+                Token::Text("b"),
+                Token::Esc("\u{1b}Pq\u{7}#0\u{1b}\\"),
+            ]
+        );
+        assert_eq!(tokens("\u{1b}^note"), vec![Token::Esc("\u{1b}^note")]);
+    }
+
+    #[test]
+    fn intermediate_bytes() {
+        assert_eq!(
+            tokens("\u{1b}[1mhi\u{1b}(B\u{1b}[m"),
+            vec![
+                Token::Csi("\u{1b}[1m"),
+                Token::Text("hi"),
+                Token::Esc("\u{1b}(B"),
+                Token::Csi("\u{1b}[m"),
+            ]
+        );
+        assert_eq!(
+            tokens("\u{1b}#8x\u{1b} %G\u{1b}(\u{1b}[m\u{1b}("),
+            vec![
+                Token::Esc("\u{1b}#8"),
+                Token::Text("x"),
+                Token::Esc("\u{1b} %G"),
+                Token::Esc("\u{1b}("),
+                Token::Csi("\u{1b}[m"),
+                Token::Esc("\u{1b}("),
+            ]
+        );
+        assert_eq!(crate::strip_ansi("\u{1b}[1mhi\u{1b}(B\u{1b}[m"), "hi");
+        assert_eq!(crate::visible_width("\u{1b}[1mhi\u{1b}(B\u{1b}[m"), 2);
+    }
+
+    #[test]
+    fn lossless() {
+        let input = "\u{1b}[1mé\u{1b}]8;;u\u{7}ü\u{1b}[0m\u{1b}(B\u{1b}[";
+        let joined = tokens(input).iter().map(Token::as_str).collect::<String>();
+        assert_eq!(joined, input);
+    }
+
+    #[test]
+    fn sgr_params() {
+        assert_eq!(Token::Csi("\u{1b}[m").sgr_params(), Some(""));
+        assert_eq!(Token::Csi("\u{1b}[38:5:1m").sgr_params(), Some("38:5:1"));
+        assert_eq!(Token::Csi("\u{1b}[>4;2m").sgr_params(), None);
+        assert_eq!(Token::Text("m").sgr_params(), None);
+    }
+}
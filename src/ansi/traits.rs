@@ -4,7 +4,9 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::{Ansi, Color, Colors};
+#[cfg(feature = "named-colors")]
+use crate::Colors;
+use crate::{Ansi, Color, ToColor};
 
 /// Trait used to enable style functions to accept value or closure.
 #[allow(clippy::module_name_repetitions)]
@@ -34,6 +36,26 @@ impl IntoAnsi for &Ansi {
     }
 }
 
+/// A `(foreground, background)` pair of colors.
+///
+/// ```
+/// # use ansirs::{Color, Styled};
+/// let white = Color::from_rgb(255, 255, 255);
+/// let badge = " FAIL ".style((white, Color::from_rgb(139, 0, 0)));
+/// assert_eq!(badge, "\x1b[38;2;255;255;255;48;2;139;0;0m FAIL \x1b[0m");
+/// ```
+impl<F: ToColor, B: ToColor> IntoAnsi for (F, B) {
+    fn into_ansi(self) -> Ansi {
+        Ansi::from_fg(self.0).bg(self.1)
+    }
+}
+
+impl<F: ToColor, B: ToColor> From<(F, B)> for Ansi {
+    fn from(colors: (F, B)) -> Self {
+        colors.into_ansi()
+    }
+}
+
 impl From<Color> for Ansi {
     fn from(c: Color) -> Self {
         c.into_ansi()
@@ -46,12 +68,14 @@ impl From<&Color> for Ansi {
     }
 }
 
+#[cfg(feature = "named-colors")]
 impl From<Colors> for Ansi {
     fn from(c: Colors) -> Self {
         c.into_ansi()
     }
 }
 
+#[cfg(feature = "named-colors")]
 impl From<&Colors> for Ansi {
     fn from(c: &Colors) -> Self {
         c.into_ansi()
@@ -76,6 +100,7 @@ mod tests {
         assert_eq!(ansi, Ansi::from(&c));
     }
 
+    #[cfg(feature = "named-colors")]
     #[test]
     fn ansi_from_colors() {
         let colors = Colors::Maroon;
@@ -83,6 +108,7 @@ mod tests {
         assert_eq!(ansi, Ansi::from(colors));
     }
 
+    #[cfg(feature = "named-colors")]
     #[test]
     fn ansi_from_ref_colors() {
         let colors = Colors::Maroon;
@@ -90,6 +116,19 @@ mod tests {
         assert_eq!(ansi, Ansi::from(&colors));
     }
 
+    #[cfg(feature = "named-colors")]
+    #[test]
+    fn fg_bg_tuples() {
+        let expected = Ansi::from_fg((255, 255, 255)).bg((139, 0, 0));
+        assert_eq!((Colors::White, Colors::DarkRed).into_ansi(), expected);
+        assert_eq!(
+            (Color::from_rgb(255, 255, 255), Color::from_rgb(139, 0, 0)).into_ansi(),
+            expected
+        );
+        assert_eq!(((255, 255, 255), &Colors::DarkRed).into_ansi(), expected);
+        assert_eq!(Ansi::from((Colors::White, 0x008b_0000)), expected);
+    }
+
     #[test]
     fn ansi_ref_intoansi() {
         let ansi: Ansi = Ansi::from_fg((100, 250, 100));
@@ -0,0 +1,237 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{Ansi, Color, Palette, ToColor};
+
+/// A single step of a [`ColorAdjust`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Adjustment {
+    RotateHue(f32),
+    Saturate(f32),
+    Lighten(f32),
+    Mix(Color, f32),
+    Warm(f32),
+}
+
+/// A reusable list of color adjustments, applied in order, that can be run over a single
+/// [`Color`], a whole [`Palette`] or all the colors of an [`Ansi`] style. This makes global
+/// tweaks like "slightly dim everything" a single expression.
+///
+/// Amounts for [`saturate`](ColorAdjust::saturate) and [`lighten`](ColorAdjust::lighten) (and
+/// their opposites) are absolute changes to the HSL saturation and lightness, which range from
+/// `0.0` to `1.0`.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi, Color, ColorAdjust};
+/// let dim = ColorAdjust::new().desaturate(0.2).darken(0.1);
+///
+/// assert_eq!(dim.apply((255, 0, 0)), Color::from_rgb(184, 20, 20));
+/// assert_eq!(
+///     dim.apply_ansi(Ansi::from_fg((255, 0, 0)).bold()),
+///     Ansi::from_fg((184, 20, 20)).bold()
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColorAdjust {
+    steps: Vec<Adjustment>,
+}
+
+impl ColorAdjust {
+    /// Creates a new [`ColorAdjust`] that leaves colors unchanged.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Builder function to rotate the hue by `degrees`.
+    #[must_use]
+    pub fn rotate_hue(mut self, degrees: f32) -> Self {
+        self.steps.push(Adjustment::RotateHue(degrees));
+        self
+    }
+
+    /// Builder function to increase the saturation by `amount`.
+    #[must_use]
+    pub fn saturate(mut self, amount: f32) -> Self {
+        self.steps.push(Adjustment::Saturate(amount));
+        self
+    }
+
+    /// Builder function to decrease the saturation by `amount`.
+    #[must_use]
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Builder function to increase the lightness by `amount`.
+    #[must_use]
+    pub fn lighten(mut self, amount: f32) -> Self {
+        self.steps.push(Adjustment::Lighten(amount));
+        self
+    }
+
+    /// Builder function to decrease the lightness by `amount`.
+    #[must_use]
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Builder function to blend towards `color`, where `t` works like in [`Color::lerp`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn mix<C: ToColor>(mut self, color: C, t: f32) -> Self {
+        self.steps.push(Adjustment::Mix(color.to_color(), t));
+        self
+    }
+
+    /// Builder function to make colors warmer by `amount`, see [`Color::warm`].
+    #[must_use]
+    pub fn warm(mut self, amount: f32) -> Self {
+        self.steps.push(Adjustment::Warm(amount));
+        self
+    }
+
+    /// Builder function to make colors cooler by `amount`, see [`Color::cool`].
+    #[must_use]
+    pub fn cool(self, amount: f32) -> Self {
+        self.warm(-amount)
+    }
+
+    /// Returns `true` if this adjustment has no steps and leaves every color unchanged.
+    #[must_use]
+    pub fn is_identity(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Applies all the steps of this adjustment to `color`.
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn apply<C: ToColor>(&self, color: C) -> Color {
+        self.steps
+            .iter()
+            .fold(color.to_color(), |color, step| match *step {
+                Adjustment::RotateHue(degrees) => color.rotate_hue(degrees),
+                Adjustment::Saturate(amount) => {
+                    let (h, s, l) = color.to_hsl();
+                    Color::from_hsl(h, s + amount, l)
+                }
+                Adjustment::Lighten(amount) => {
+                    let (h, s, l) = color.to_hsl();
+                    Color::from_hsl(h, s, l + amount)
+                }
+                Adjustment::Mix(other, t) => color.lerp(other, t),
+                Adjustment::Warm(amount) => color.warm(amount),
+            })
+    }
+
+    /// Applies this adjustment to every color of `palette`, keeping names and order.
+    #[must_use]
+    pub fn apply_palette(&self, palette: &Palette) -> Palette {
+        let mut adjusted = Palette::new(palette.name());
+        adjusted.extend(
+            palette
+                .iter()
+                .map(|(name, color)| (name, self.apply(color))),
+        );
+        adjusted
+    }
+
+    /// Applies this adjustment to the foreground, background and underline colors of `style`.
+    /// Indexed colors are resolved to RGB before being adjusted, unless this adjustment
+    /// [is the identity](ColorAdjust::is_identity).
+    #[must_use]
+    pub fn apply_ansi(&self, style: Ansi) -> Ansi {
+        if self.is_identity() {
+            return style;
+        }
+
+        let mut adjusted = style;
+        if let Some(fg) = style.foreground() {
+            adjusted = adjusted.fg(self.apply(fg.to_color()));
+        }
+        if let Some(bg) = style.background() {
+            adjusted = adjusted.bg(self.apply(bg.to_color()));
+        }
+        if let Some(ul) = style.underline_color() {
+            adjusted = adjusted.ul(self.apply(ul.to_color()));
+        }
+        adjusted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn steps_apply_in_order() {
+        let red = Color::from_rgb(255, 0, 0);
+        assert_eq!(ColorAdjust::new().apply(red), red);
+        assert!(ColorAdjust::new().is_identity());
+        assert_eq!(
+            ColorAdjust::new().rotate_hue(120.0).apply(red),
+            Color::from_rgb(0, 255, 0)
+        );
+        assert_eq!(
+            ColorAdjust::new().desaturate(1.0).apply(red),
+            Color::from_rgb(128, 128, 128)
+        );
+        assert_eq!(
+            ColorAdjust::new().lighten(1.0).apply(red),
+            Color::from_rgb(255, 255, 255)
+        );
+        assert_eq!(
+            ColorAdjust::new().mix((0, 0, 0), 0.5).apply(red),
+            Color::from_rgb(128, 0, 0)
+        );
+        let gray = Color::from_rgb(128, 128, 128);
+        assert_eq!(ColorAdjust::new().warm(0.5).apply(gray), gray.warm(0.5));
+        assert_eq!(ColorAdjust::new().cool(0.5).apply(gray), gray.cool(0.5));
+
+        // Lightening a fully desaturated color stays gray, the other way around it does not.
+        let gray_first = ColorAdjust::new().desaturate(1.0).lighten(0.2);
+        let light_first = ColorAdjust::new().lighten(0.2).desaturate(1.0);
+        assert_eq!(gray_first.apply(red), Color::from_rgb(179, 179, 179));
+        assert_eq!(light_first.apply(red), gray_first.apply(red));
+        assert_ne!(
+            ColorAdjust::new()
+                .mix((0, 0, 255), 0.5)
+                .rotate_hue(90.0)
+                .apply(red),
+            ColorAdjust::new()
+                .rotate_hue(90.0)
+                .mix((0, 0, 255), 0.5)
+                .apply(red)
+        );
+    }
+
+    #[test]
+    fn palettes_and_styles() {
+        let invert = ColorAdjust::new().rotate_hue(180.0);
+        let palette = Palette::new("p")
+            .with("a", (255, 0, 0))
+            .with("b", (0, 0, 255));
+        let adjusted = invert.apply_palette(&palette);
+        assert_eq!(adjusted.name(), "p");
+        assert_eq!(
+            adjusted.iter().collect::<Vec<_>>(),
+            vec![
+                ("a", Color::from_rgb(0, 255, 255)),
+                ("b", Color::from_rgb(255, 255, 0))
+            ]
+        );
+
+        let style = Ansi::new().fg_indexed(9).bg((0, 0, 255)).underline();
+        assert_eq!(ColorAdjust::new().apply_ansi(style), style);
+        assert_eq!(
+            invert.apply_ansi(style),
+            Ansi::new().fg((0, 255, 255)).bg((255, 255, 0)).underline()
+        );
+        assert_eq!(invert.apply_ansi(Ansi::new().bold()), Ansi::new().bold());
+    }
+}
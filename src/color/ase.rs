@@ -0,0 +1,274 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{Color, Palette, PaletteParseError};
+
+const SIGNATURE: &[u8; 4] = b"ASEF";
+const BLOCK_COLOR: u16 = 0x0001;
+const BLOCK_GROUP_START: u16 = 0xc001;
+
+/// Big-endian reader over the bytes of an ASE file.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], PaletteParseError> {
+        if self.bytes.len() < len {
+            return Err(PaletteParseError::UnexpectedEnd);
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn u16(&mut self) -> Result<u16, PaletteParseError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, PaletteParseError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn f32(&mut self) -> Result<f32, PaletteParseError> {
+        self.u32().map(f32::from_bits)
+    }
+
+    /// Reads a length prefixed, null terminated, UTF-16 name.
+    fn name(&mut self) -> Result<String, PaletteParseError> {
+        let len = usize::from(self.u16()?);
+        let units = (0..len)
+            .map(|_| self.u16())
+            .collect::<Result<Vec<_>, _>>()?;
+        let units = units.strip_suffix(&[0]).unwrap_or(&units);
+        String::from_utf16(units).map_err(|_| PaletteParseError::InvalidName)
+    }
+}
+
+impl Palette {
+    /// Reads a palette in the Adobe Swatch Exchange (`.ase`) format, as exported by Photoshop,
+    /// Illustrator and most online palette tools. Requires the `palette-import` feature.
+    ///
+    /// RGB, CMYK, grayscale and LAB swatches are converted to RGB. Swatch groups are flattened
+    /// into a single list, in file order.
+    ///
+    /// ## Errors
+    /// - [`PaletteParseError::MissingHeader`] if the data does not start with `ASEF`.
+    /// - [`PaletteParseError::UnexpectedEnd`] if the data ends in the middle of a block.
+    /// - [`PaletteParseError::UnknownColorModel`] if a swatch uses an unknown color model.
+    /// - [`PaletteParseError::InvalidName`] if a name is not valid UTF-16.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Palette;
+    /// let ase = std::fs::read("brand.ase");
+    /// # let ase: std::io::Result<Vec<u8>> = Ok(b"ASEF\0\x01\0\0\0\0\0\0".to_vec());
+    /// let palette = Palette::from_ase(&ase.unwrap()).unwrap();
+    /// # assert!(palette.is_empty());
+    /// ```
+    pub fn from_ase(bytes: &[u8]) -> Result<Self, PaletteParseError> {
+        let mut reader = Reader { bytes };
+        if reader
+            .take(4)
+            .map_err(|_| PaletteParseError::MissingHeader)?
+            != SIGNATURE
+        {
+            return Err(PaletteParseError::MissingHeader);
+        }
+        // Version, always 1.0.
+        reader.take(4)?;
+        let blocks = reader.u32()?;
+
+        let mut palette = Palette::default();
+        for _ in 0..blocks {
+            let kind = reader.u16()?;
+            let len = reader.u32()?;
+            let mut block = Reader {
+                bytes: reader.take(usize::try_from(len).unwrap_or(usize::MAX))?,
+            };
+
+            match kind {
+                BLOCK_COLOR => {
+                    let name = block.name()?;
+                    let color = read_color(&mut block)?;
+                    palette.push(name, color);
+                }
+                BLOCK_GROUP_START if palette.name().is_empty() && palette.is_empty() => {
+                    palette.set_name(block.name()?);
+                }
+                _ => {}
+            }
+        }
+        Ok(palette)
+    }
+}
+
+/// Reads the color model and values of a color block and converts them to RGB.
+#[allow(clippy::many_single_char_names)]
+fn read_color(block: &mut Reader<'_>) -> Result<Color, PaletteParseError> {
+    let model = block.take(4)?;
+    let rgb = match model {
+        b"RGB " => (block.f32()?, block.f32()?, block.f32()?),
+        b"CMYK" => {
+            let (c, m, y, k) = (block.f32()?, block.f32()?, block.f32()?, block.f32()?);
+            (
+                (1.0 - c) * (1.0 - k),
+                (1.0 - m) * (1.0 - k),
+                (1.0 - y) * (1.0 - k),
+            )
+        }
+        b"Gray" => {
+            let v = block.f32()?;
+            (v, v, v)
+        }
+        b"LAB " => lab_to_rgb(block.f32()? * 100.0, block.f32()?, block.f32()?),
+        model => {
+            return Err(PaletteParseError::UnknownColorModel(
+                String::from_utf8_lossy(model).into_owned(),
+            ))
+        }
+    };
+    Ok(Color::from_rgb(
+        channel(rgb.0),
+        channel(rgb.1),
+        channel(rgb.2),
+    ))
+}
+
+/// Converts a `0.0..=1.0` channel to a byte.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn channel(value: f32) -> u8 {
+    let value = if value.is_nan() { 0.0 } else { value };
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Converts a CIE L*a*b* color (D50, as used by Adobe) to sRGB channels in `0.0..=1.0`.
+#[allow(
+    clippy::many_single_char_names,
+    clippy::unreadable_literal,
+    clippy::excessive_precision
+)]
+fn lab_to_rgb(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+    const EPSILON: f32 = 216.0 / 24389.0;
+    const KAPPA: f32 = 24389.0 / 27.0;
+
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    let inverse = |f: f32| {
+        let cubed = f * f * f;
+        if cubed > EPSILON {
+            cubed
+        } else {
+            (116.0 * f - 16.0) / KAPPA
+        }
+    };
+    let x = inverse(fx) * 0.96422;
+    let y = if l > KAPPA * EPSILON {
+        fy * fy * fy
+    } else {
+        l / KAPPA
+    };
+    let z = inverse(fz) * 0.82521;
+
+    // XYZ (D50) to linear sRGB, with Bradford adaptation to D65.
+    let r = 3.1338561 * x - 1.6168667 * y - 0.4906146 * z;
+    let g = -0.9787684 * x + 1.9161415 * y + 0.0334540 * z;
+    let b = 0.0719453 * x - 0.2289914 * y + 1.4052427 * z;
+
+    let gamma = |c: f32| {
+        if c <= 0.0031308 {
+            12.92 * c
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        }
+    };
+    (gamma(r), gamma(g), gamma(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn name(name: &str) -> Vec<u8> {
+        let units = name.encode_utf16().chain([0]).collect::<Vec<_>>();
+        let mut out = u16::try_from(units.len()).unwrap().to_be_bytes().to_vec();
+        out.extend(units.iter().flat_map(|u| u.to_be_bytes()));
+        out
+    }
+
+    fn block(kind: u16, body: &[u8]) -> Vec<u8> {
+        let mut out = kind.to_be_bytes().to_vec();
+        out.extend(u32::try_from(body.len()).unwrap().to_be_bytes());
+        out.extend(body);
+        out
+    }
+
+    fn color(label: &str, model: [u8; 4], values: &[f32]) -> Vec<u8> {
+        let mut body = name(label);
+        body.extend(model);
+        body.extend(values.iter().flat_map(|v| v.to_be_bytes()));
+        body.extend(2u16.to_be_bytes());
+        block(BLOCK_COLOR, &body)
+    }
+
+    fn file(blocks: &[Vec<u8>]) -> Vec<u8> {
+        let mut out = b"ASEF\0\x01\0\0".to_vec();
+        out.extend(u32::try_from(blocks.len()).unwrap().to_be_bytes());
+        out.extend(blocks.concat());
+        out
+    }
+
+    #[test]
+    fn color_models() {
+        let data = file(&[
+            block(BLOCK_GROUP_START, &name("Brand")),
+            color("rgb", *b"RGB ", &[1.0, 0.5, 0.0]),
+            color("cmyk", *b"CMYK", &[0.0, 1.0, 1.0, 0.5]),
+            color("gray", *b"Gray", &[0.2]),
+            color("lab", *b"LAB ", &[0.5, 0.0, 0.0]),
+            block(0xc002, &[]),
+        ]);
+        let palette = Palette::from_ase(&data).unwrap();
+        assert_eq!(palette.name(), "Brand");
+        assert_eq!(
+            palette.iter().collect::<Vec<_>>(),
+            vec![
+                ("rgb", Color::from_rgb(255, 128, 0)),
+                ("cmyk", Color::from_rgb(128, 0, 0)),
+                ("gray", Color::from_rgb(51, 51, 51)),
+                ("lab", Color::from_rgb(119, 119, 119)),
+            ]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Palette::from_ase(b"GIMP"),
+            Err(PaletteParseError::MissingHeader)
+        );
+        assert_eq!(
+            Palette::from_ase(b"AS"),
+            Err(PaletteParseError::MissingHeader)
+        );
+
+        let mut truncated = file(&[color("x", *b"RGB ", &[1.0, 1.0, 1.0])]);
+        truncated.truncate(truncated.len() - 3);
+        assert_eq!(
+            Palette::from_ase(&truncated),
+            Err(PaletteParseError::UnexpectedEnd)
+        );
+
+        assert_eq!(
+            Palette::from_ase(&file(&[color("x", *b"HSV ", &[1.0, 1.0, 1.0])])),
+            Err(PaletteParseError::UnknownColorModel("HSV ".to_string()))
+        );
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::Color;
+
+/// The color space colors are blended in.
+///
+/// Blending the (gamma encoded) sRGB values directly is cheap and is what most tools do, but
+/// makes the middle of a blend noticeably darker than either end. Blending in linear light
+/// matches how light actually mixes, at the cost of a few more calculations.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{BlendSpace, Color};
+/// let red = Color::from_rgb(255, 0, 0);
+/// let green = Color::from_rgb(0, 255, 0);
+/// assert_eq!(red.lerp_in(green, 0.5, BlendSpace::Srgb), Color::from_rgb(128, 128, 0));
+/// assert_eq!(red.lerp_in(green, 0.5, BlendSpace::Linear), Color::from_rgb(188, 188, 0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlendSpace {
+    /// Blend the sRGB channel values directly.
+    #[default]
+    Srgb,
+    /// Blend in linear light, converting from and back to sRGB.
+    Linear,
+}
+
+impl Color {
+    /// Converts this color to linear light RGB channels in `0.0..=1.0`, undoing the sRGB
+    /// gamma encoding.
+    #[must_use]
+    pub fn to_linear(self) -> (f32, f32, f32) {
+        fn linear(channel: u8) -> f32 {
+            let c = f32::from(channel) / 255.0;
+            if c <= 0.040_45 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        let (r, g, b) = self.rgb();
+        (linear(r), linear(g), linear(b))
+    }
+
+    /// Creates a color from linear light RGB channels, which are clamped to `0.0..=1.0`,
+    /// applying the sRGB gamma encoding.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn from_linear(r: f32, g: f32, b: f32) -> Self {
+        fn encode(channel: f32) -> u8 {
+            let c = if channel.is_nan() {
+                0.0
+            } else {
+                channel.clamp(0.0, 1.0)
+            };
+            let c = if c <= 0.003_130_8 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            };
+            (c * 255.0).round().clamp(0.0, 255.0) as u8
+        }
+
+        Self::from_rgb(encode(r), encode(g), encode(b))
+    }
+
+    /// Like [`Color::lerp`], but blending in the given color `space`.
+    #[must_use]
+    pub fn lerp_in(self, other: Color, t: f32, space: BlendSpace) -> Self {
+        match space {
+            BlendSpace::Srgb => self.lerp(other, t),
+            BlendSpace::Linear => {
+                let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+                let (r1, g1, b1) = self.to_linear();
+                let (r2, g2, b2) = other.to_linear();
+                Self::from_linear(r1 + (r2 - r1) * t, g1 + (g2 - g1) * t, b1 + (b2 - b1) * t)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn linear_round_trip() {
+        for value in u8::MIN..=u8::MAX {
+            let color = Color::from_rgb(value, value / 2, 255 - value);
+            let (r, g, b) = color.to_linear();
+            assert_eq!(Color::from_linear(r, g, b), color);
+        }
+        assert_eq!(
+            Color::from_linear(2.0, -1.0, f32::NAN),
+            Color::from_rgb(255, 0, 0)
+        );
+    }
+
+    #[test]
+    fn linear_blend() {
+        let black = Color::from_rgb(0, 0, 0);
+        let white = Color::from_rgb(255, 255, 255);
+        assert_eq!(
+            black.lerp_in(white, 0.5, BlendSpace::Srgb),
+            Color::from_rgb(128, 128, 128)
+        );
+        assert_eq!(
+            black.lerp_in(white, 0.5, BlendSpace::Linear),
+            Color::from_rgb(188, 188, 188)
+        );
+        assert_eq!(black.lerp_in(white, 0.0, BlendSpace::Linear), black);
+        assert_eq!(black.lerp_in(white, 7.0, BlendSpace::Linear), white);
+    }
+}
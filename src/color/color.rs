@@ -18,55 +18,115 @@ impl Color {
         Self(r, g, b)
     }
 
-    /// Attempt to create a new color from the given hexadecimal string.
+    /// Create a new color from a packed `0xRRGGBB` integer. The top byte is ignored.
+    ///
+    /// ```
+    /// # use ansirs::Color;
+    /// assert_eq!(Color::from_u32(0x1964fa), Color::from_rgb(25, 100, 250));
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn from_u32(rgb: u32) -> Self {
+        Self((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+    }
+
+    /// Create a new color from a packed `0xRRGGBBAA` integer, ignoring the alpha channel.
+    #[must_use]
+    pub const fn from_rgba_u32(rgba: u32) -> Self {
+        Self::from_u32(rgba >> 8)
+    }
+
+    /// Get this color as a packed `0xRRGGBB` integer.
+    #[must_use]
+    pub const fn to_u32(&self) -> u32 {
+        (self.0 as u32) << 16 | (self.1 as u32) << 8 | self.2 as u32
+    }
+
+    /// Attempt to create a new color from the given hexadecimal string (`#RRGGBB` or `#RGB`,
+    /// the `#` being optional).
     ///
     /// ## Errors
-    /// - `ColorParseError` if the given input string cannot be converted to a color.
+    /// - [`ColorParseError::BadChar`] if the string contains anything but hex digits.
+    /// - [`ColorParseError::WrongLength`] if the string does not have 3 or 6 digits.
     #[cfg_attr(feature = "trace", tracing::instrument)]
     pub fn from_hex<S: AsRef<str> + std::fmt::Debug>(input: S) -> Result<Self, ColorParseError> {
-        fn convert(input: &str) -> Result<u8, ColorParseError> {
-            u8::from_str_radix(input, 16).map_err(ColorParseError::ParseIntError)
+        let input = input.as_ref();
+        let start = usize::from(input.starts_with('#'));
+        let digits = &input[start..];
+
+        if let Some((idx, found)) = digits.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(ColorParseError::BadChar {
+                input: input.to_string(),
+                position: start + idx,
+                found,
+            });
         }
 
-        let mut string = input.as_ref();
-
-        if string.starts_with('#') {
-            string = &string[1..];
-        }
-
-        if string.len() != 3 && string.len() != 6 {
-            return Err(ColorParseError::WrongLength);
+        // Every digit is valid, and ASCII, at this point.
+        let nibble = |idx: usize| {
+            let digit = digits.as_bytes()[idx];
+            match digit {
+                b'0'..=b'9' => digit - b'0',
+                b'a'..=b'f' => digit - b'a' + 10,
+                _ => digit - b'A' + 10,
+            }
+        };
+        match digits.len() {
+            3 => Ok(Self(nibble(0) * 17, nibble(1) * 17, nibble(2) * 17)),
+            6 => Ok(Self(
+                nibble(0) << 4 | nibble(1),
+                nibble(2) << 4 | nibble(3),
+                nibble(4) << 4 | nibble(5),
+            )),
+            len => Err(ColorParseError::WrongLength {
+                input: input.to_string(),
+                digits: len,
+            }),
         }
+    }
 
-        if !string.is_ascii() {
-            return Err(ColorParseError::BadChars);
+    /// Create a new color from the given hexadecimal string (`#RRGGBB`, `#RGB`, the `#` being
+    /// optional), panicking if it is invalid.
+    ///
+    /// Unlike [`Color::from_hex`] this is a `const fn`, so it can be used to declare color
+    /// tables as constants, where an invalid color is a compile time error.
+    ///
+    /// ```
+    /// # use ansirs::Color;
+    /// const BRAND: [Color; 3] = [
+    ///     Color::from_hex_unwrap("#1d4ed8"),
+    ///     Color::from_hex_unwrap("f97316"),
+    ///     Color::from_hex_unwrap("#fff"),
+    /// ];
+    /// assert_eq!(BRAND[2], Color::from_rgb(255, 255, 255));
+    /// ```
+    ///
+    /// ## Panics
+    /// - If `input` is not a valid hex color.
+    #[must_use]
+    pub const fn from_hex_unwrap(input: &str) -> Self {
+        const fn digit(byte: u8) -> u8 {
+            match byte {
+                b'0'..=b'9' => byte - b'0',
+                b'a'..=b'f' => byte - b'a' + 10,
+                b'A'..=b'F' => byte - b'A' + 10,
+                _ => panic!("Invalid character in hex color"),
+            }
         }
 
-        let is_double = string.len() == 6;
-
-        let mut chars = string.chars();
-
-        let mut rgb = [0u8, 0u8, 0u8];
-        for idx in &mut rgb {
-            *idx = if is_double {
-                let f = chars.next().ok_or_else(|| {
-                    ColorParseError::Unknown("Unexpected end of string!".to_string())
-                })?;
-                let s = chars.next().ok_or_else(|| {
-                    ColorParseError::Unknown("Unexpected end of string!".to_string())
-                })?;
-
-                convert(&format!("{f}{s}"))?
-            } else {
-                let c = chars.next().ok_or_else(|| {
-                    ColorParseError::Unknown("Unexpected end of string!".to_string())
-                })?;
-
-                convert(&format!("{c}{c}"))?
-            };
+        let bytes = match input.as_bytes() {
+            [b'#', rest @ ..] => rest,
+            bytes => bytes,
+        };
+        match bytes {
+            [r1, r2, g1, g2, b1, b2] => Self(
+                digit(*r1) * 16 + digit(*r2),
+                digit(*g1) * 16 + digit(*g2),
+                digit(*b1) * 16 + digit(*b2),
+            ),
+            [r, g, b] => Self(digit(*r) * 17, digit(*g) * 17, digit(*b) * 17),
+            _ => panic!("Hex color must have 3 or 6 digits"),
         }
-
-        Ok(Self(rgb[0], rgb[1], rgb[2]))
     }
 
     /// Create a hex string from this color.
@@ -107,6 +167,105 @@ impl Color {
         self.2
     }
 
+    /// Linearly interpolates between this color and `other`, where `t` is clamped to
+    /// `0.0..=1.0` and `0.0` returns this color. This blends the sRGB values directly, see
+    /// [`Color::lerp_in`] for blending in linear light.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn lerp(self, other: Color, t: f32) -> Self {
+        fn channel(a: u8, b: u8, t: f32) -> u8 {
+            (f32::from(a) + (f32::from(b) - f32::from(a)) * t)
+                .round()
+                .clamp(0.0, 255.0) as u8
+        }
+
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        Self(
+            channel(self.0, other.0, t),
+            channel(self.1, other.1, t),
+            channel(self.2, other.2, t),
+        )
+    }
+
+    /// Creates a color from HSL values, where `hue` is in degrees (wrapped to `0.0..360.0`) and
+    /// `saturation` and `lightness` are clamped to `0.0..=1.0`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        fn unit(value: f32) -> f32 {
+            if value.is_nan() {
+                0.0
+            } else {
+                value.clamp(0.0, 1.0)
+            }
+        }
+
+        let hue = if hue.is_finite() {
+            hue.rem_euclid(360.0)
+        } else {
+            0.0
+        };
+        let (saturation, lightness) = (unit(saturation), unit(lightness));
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue {
+            h if h < 60.0 => (chroma, x, 0.0),
+            h if h < 120.0 => (x, chroma, 0.0),
+            h if h < 180.0 => (0.0, chroma, x),
+            h if h < 240.0 => (0.0, x, chroma),
+            h if h < 300.0 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let offset = lightness - chroma / 2.0;
+        let channel = |value: f32| ((value + offset) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self(channel(r), channel(g), channel(b))
+    }
+
+    /// Gets the HSL values of this color, as `(hue, saturation, lightness)` where `hue` is in
+    /// degrees (`0.0..360.0`) and the others are in `0.0..=1.0`.
+    #[must_use]
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (r, g, b) = (
+            f32::from(self.0) / 255.0,
+            f32::from(self.1) / 255.0,
+            f32::from(self.2) / 255.0,
+        );
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = f32::midpoint(max, min);
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        #[allow(clippy::float_cmp)]
+        let hue = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        (hue, saturation, lightness)
+    }
+
+    /// Gets the relative luminance of this color as defined by WCAG 2, from `0.0` for black to
+    /// `1.0` for white.
+    #[must_use]
+    pub fn luminance(self) -> f32 {
+        let (r, g, b) = self.to_linear();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Gets the WCAG 2 contrast ratio between this color and `other`, from `1.0` (no contrast)
+    /// to `21.0` (black on white).
+    #[must_use]
+    pub fn contrast_ratio(self, other: Color) -> f32 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
     /// Converts an ANSI-256 color number to an rgb [`Color`].
     #[allow(clippy::match_same_arms, clippy::too_many_lines)]
     #[must_use]
@@ -400,6 +559,7 @@ impl std::fmt::Display for Color {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ToColor;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -409,11 +569,47 @@ mod tests {
         assert_eq!(color.as_hex_lower(), "#1964fa");
     }
 
+    #[test]
+    fn hex_unwrap() {
+        const COLORS: [Color; 3] = [
+            Color::from_hex_unwrap("#1964FA"),
+            Color::from_hex_unwrap("1964fa"),
+            Color::from_hex_unwrap("#f0a"),
+        ];
+        assert_eq!(COLORS[0], Color::from_rgb(25, 100, 250));
+        assert_eq!(COLORS[1], COLORS[0]);
+        assert_eq!(COLORS[2], Color::from_rgb(255, 0, 170));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid character")]
+    fn hex_unwrap_bad_char() {
+        let _ = Color::from_hex_unwrap("#12345g");
+    }
+
+    #[test]
+    #[should_panic(expected = "3 or 6 digits")]
+    fn hex_unwrap_wrong_length() {
+        let _ = Color::from_hex_unwrap("#1234");
+    }
+
     #[test]
     fn color_from_non_ascii() {
         assert!(Color::from_hex("üßü").is_err());
     }
 
+    #[test]
+    fn packed() {
+        let color = Color::from_rgb(25, 100, 250);
+        assert_eq!(color.to_u32(), 0x0019_64fa);
+        assert_eq!(Color::from_u32(0x0019_64fa), color);
+        assert_eq!(Color::from_u32(0xff19_64fa), color);
+        assert_eq!(Color::from_rgba_u32(0x1964_fa80), color);
+        assert_eq!(Color::from(0x0019_64fa), color);
+        assert_eq!(u32::from(color), 0x0019_64fa);
+        assert_eq!(0x0019_64fa.to_color(), color);
+    }
+
     #[test]
     fn components() {
         let color = Color::from_rgb(25, 100, 250);
@@ -430,6 +626,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn lerp() {
+        let black = Color::from_rgb(0, 0, 0);
+        let white = Color::from_rgb(255, 255, 255);
+        assert_eq!(black.lerp(white, 0.0), black);
+        assert_eq!(black.lerp(white, 1.0), white);
+        assert_eq!(black.lerp(white, 0.5), Color::from_rgb(128, 128, 128));
+        assert_eq!(white.lerp(black, 5.0), black);
+    }
+
+    #[test]
+    fn hsl() {
+        assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::from_rgb(255, 0, 0));
+        assert_eq!(
+            Color::from_hsl(480.0, 1.0, 0.25),
+            Color::from_rgb(0, 128, 0)
+        );
+        assert_eq!(
+            Color::from_hsl(-120.0, 2.0, 0.5),
+            Color::from_rgb(0, 0, 255)
+        );
+        assert_eq!(
+            Color::from_hsl(90.0, 0.0, 1.0),
+            Color::from_rgb(255, 255, 255)
+        );
+
+        assert_eq!(Color::from_rgb(255, 0, 0).to_hsl(), (0.0, 1.0, 0.5));
+        assert_eq!(Color::from_rgb(0, 0, 0).to_hsl(), (0.0, 0.0, 0.0));
+        let color = Color::from_rgb(25, 100, 250);
+        let (h, s, l) = color.to_hsl();
+        assert_eq!(Color::from_hsl(h, s, l), color);
+    }
+
+    #[test]
+    fn contrast() {
+        let black = Color::from_rgb(0, 0, 0);
+        let white = Color::from_rgb(255, 255, 255);
+        assert!((white.luminance() - 1.0).abs() < 1e-6);
+        assert!((black.contrast_ratio(white) - 21.0).abs() < 1e-4);
+        assert!((white.contrast_ratio(white) - 1.0).abs() < 1e-6);
+        // #767676 is the lightest gray passing WCAG AA (4.5) on white.
+        assert!(Color::from_rgb(0x76, 0x76, 0x76).contrast_ratio(white) > 4.5);
+    }
+
     #[test]
     fn display() {
         let color = Color::from_rgb(25, 100, 250);
@@ -1083,6 +1083,21 @@ impl Colors {
         Self::AliceBlue.into_iter()
     }
 
+    /// Creates an iterator over all named colors together with their [`Color`] and name, in the
+    /// same order as [`Colors::all`]. Useful for building pickers, completion lists and docs.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Color, Colors};
+    /// let (variant, color, name) = Colors::all_pairs().next().unwrap();
+    /// assert_eq!(variant, Colors::AliceBlue);
+    /// assert_eq!(color, Color::from_rgb(240, 248, 255));
+    /// assert_eq!(name, "AliceBlue");
+    /// ```
+    pub fn all_pairs() -> impl Iterator<Item = (Self, Color, &'static str)> {
+        Self::all().map(|c| (c, c.into_color(), c.name()))
+    }
+
     /// Gets the closest named color to the rgb values given using simple absolute difference calculations.
     ///
     /// # **This is a very expensive operation.**
@@ -1226,6 +1241,23 @@ mod tests {
         assert_eq!(first.next(), Colors::AntiqueWhite);
     }
 
+    #[test]
+    fn all_pairs() {
+        assert_eq!(Colors::all_pairs().count(), Colors::all().count());
+        for (variant, color, name) in Colors::all_pairs() {
+            assert_eq!(color, variant.into_color());
+            assert_eq!(Colors::from_name(name), Some(variant));
+        }
+        assert_eq!(
+            Colors::all_pairs().last(),
+            Some((
+                Colors::YellowGreen,
+                Color::from_rgb(154, 205, 50),
+                "YellowGreen"
+            ))
+        );
+    }
+
     #[test]
     fn dumb_test() {
         assert_eq!(Colors::AliceBlue.rgb(), (240, 248, 255));
@@ -5,53 +5,184 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 /// Error type used when parsing a color.
+///
+/// Both variants keep the whole input, and [`position`](ColorParseError::position) and
+/// [`fragment`](ColorParseError::fragment) point at the part of it that could not be parsed.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Color, ColorParseError};
+/// let error = Color::from_hex("#12x456").unwrap_err();
+/// assert_eq!(error.position(), 3);
+/// assert_eq!(error.fragment(), "x");
+/// assert_eq!(error.to_string(), "Invalid character 'x' at position 3 in color \"#12x456\"");
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ColorParseError {
-    /// Bad characters were found in the color string.
-    BadChars,
-    /// The color string had too few or too many characters.
-    WrongLength,
-    /// The color string segment could not be parsed into a valid decimal number.
-    ParseIntError(std::num::ParseIntError),
-    /// Other errors (with message).
-    Unknown(String),
+    /// A character that is not a hexadecimal digit was found.
+    BadChar {
+        /// The string that was being parsed.
+        input: String,
+        /// The byte offset of the character in `input`.
+        position: usize,
+        /// The character that was found.
+        found: char,
+    },
+    /// The color did not have 3 or 6 digits.
+    WrongLength {
+        /// The string that was being parsed.
+        input: String,
+        /// The number of digits that were found.
+        digits: usize,
+    },
+}
+
+impl ColorParseError {
+    /// Gets the string that was being parsed.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        match self {
+            ColorParseError::BadChar { input, .. } | ColorParseError::WrongLength { input, .. } => {
+                input
+            }
+        }
+    }
+
+    /// Gets the byte offset in the [`input`](ColorParseError::input) where the
+    /// [`fragment`](ColorParseError::fragment) that could not be parsed starts.
+    #[must_use]
+    pub fn position(&self) -> usize {
+        match self {
+            ColorParseError::BadChar { position, .. } => *position,
+            ColorParseError::WrongLength { input, digits } => input.len() - digits,
+        }
+    }
+
+    /// Gets the part of the [`input`](ColorParseError::input) that could not be parsed: the bad
+    /// character, or all of the digits when there are too few or too many of them.
+    #[must_use]
+    pub fn fragment(&self) -> &str {
+        match self {
+            ColorParseError::BadChar {
+                input,
+                position,
+                found,
+            } => &input[*position..*position + found.len_utf8()],
+            ColorParseError::WrongLength { input, .. } => &input[self.position()..],
+        }
+    }
 }
 
 impl std::fmt::Display for ColorParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ColorParseError::BadChars => write!(f, "Bad characters found in color string"),
-            ColorParseError::WrongLength => {
-                write!(f, "Color string had too few or too many characters")
+            ColorParseError::BadChar {
+                input,
+                position,
+                found,
+            } => write!(
+                f,
+                "Invalid character {found:?} at position {position} in color {input:?}"
+            ),
+            ColorParseError::WrongLength { input, digits } => write!(
+                f,
+                "Color {input:?} has {digits} hex digits, expected 3 or 6"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+/// Error type used when reading a [`Palette`](crate::Palette) from a palette file.
+#[cfg(feature = "palette-import")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteParseError {
+    /// The file does not start with the signature of the expected format.
+    MissingHeader,
+    /// A line (1-based) of a text based palette could not be parsed.
+    InvalidLine(usize),
+    /// The file ended in the middle of an entry.
+    UnexpectedEnd,
+    /// A color entry uses a color model that cannot be converted to RGB.
+    UnknownColorModel(String),
+    /// A name in the file is not valid UTF-16.
+    InvalidName,
+}
+
+#[cfg(feature = "palette-import")]
+impl std::fmt::Display for PaletteParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaletteParseError::MissingHeader => write!(f, "Palette file header is missing"),
+            PaletteParseError::InvalidLine(line) => {
+                write!(f, "Invalid palette entry on line {line}")
             }
-            ColorParseError::ParseIntError(inner) => {
-                write!(f, "Could not parse color string into a number: {inner}")
+            PaletteParseError::UnexpectedEnd => write!(f, "Palette file ended unexpectedly"),
+            PaletteParseError::UnknownColorModel(model) => {
+                write!(f, "Unknown color model: {model:?}")
             }
-            ColorParseError::Unknown(msg) => write!(f, "Unknown error: {msg}"),
+            PaletteParseError::InvalidName => write!(f, "Color name is not valid UTF-16"),
         }
     }
 }
 
-impl std::error::Error for ColorParseError {}
+#[cfg(feature = "palette-import")]
+impl std::error::Error for PaletteParseError {}
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-
     fn color_parse_error() {
-        let output_badchars = "Bad characters found in color string";
-        let output_wronglength = "Color string had too few or too many characters";
-        let output_unknown = "Unknown error: some_error";
-        assert_eq!(output_badchars, format!("{}", ColorParseError::BadChars));
+        let bad_char = ColorParseError::BadChar {
+            input: "#a💜".to_string(),
+            position: 2,
+            found: '💜',
+        };
+        assert_eq!(
+            bad_char.to_string(),
+            "Invalid character '💜' at position 2 in color \"#a💜\""
+        );
+        assert_eq!(bad_char.input(), "#a💜");
+        assert_eq!(bad_char.position(), 2);
+        assert_eq!(bad_char.fragment(), "💜");
+
+        let wrong_length = ColorParseError::WrongLength {
+            input: "#ff00".to_string(),
+            digits: 4,
+        };
+        assert_eq!(
+            wrong_length.to_string(),
+            "Color \"#ff00\" has 4 hex digits, expected 3 or 6"
+        );
+        assert_eq!(wrong_length.position(), 1);
+        assert_eq!(wrong_length.fragment(), "ff00");
+    }
+
+    #[test]
+    fn boxed_error() {
+        fn parse(input: &str) -> Result<crate::Color, Box<dyn std::error::Error>> {
+            Ok(crate::Color::from_hex(input)?)
+        }
+        assert!(parse("#fff").is_ok());
+        assert_eq!(
+            parse("#ffff").unwrap_err().to_string(),
+            "Color \"#ffff\" has 4 hex digits, expected 3 or 6"
+        );
+    }
+
+    #[cfg(feature = "palette-import")]
+    #[test]
+    fn palette_parse_error() {
         assert_eq!(
-            output_wronglength,
-            format!("{}", ColorParseError::WrongLength)
+            PaletteParseError::InvalidLine(4).to_string(),
+            "Invalid palette entry on line 4"
         );
         assert_eq!(
-            output_unknown,
-            format!("{}", ColorParseError::Unknown("some_error".to_string()))
+            PaletteParseError::UnknownColorModel("XYZ ".to_string()).to_string(),
+            "Unknown color model: \"XYZ \""
         );
     }
 }
@@ -0,0 +1,171 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::io::Write;
+
+use crate::Palette;
+#[cfg(feature = "palette-import")]
+use crate::{Color, PaletteParseError};
+
+impl Palette {
+    /// Writes this palette to `writer` in the GIMP palette (`.gpl`) format, which is understood
+    /// by GIMP, Inkscape, Krita, Aseprite and most other image editors.
+    ///
+    /// Line breaks in the palette or color names are replaced by spaces.
+    ///
+    /// ## Errors
+    /// - Any error returned while writing to `writer`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Palette;
+    /// let palette = Palette::new("Example").with("red", (255, 0, 0)).with("teal", (0, 128, 128));
+    ///
+    /// let mut file = Vec::new();
+    /// palette.to_gpl(&mut file).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(file).unwrap(),
+    ///     "GIMP Palette\nName: Example\n#\n255   0   0\tred\n  0 128 128\tteal\n"
+    /// );
+    /// ```
+    pub fn to_gpl<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(self.to_gpl_string().as_bytes())
+    }
+
+    /// Creates a string containing this palette in the GIMP palette (`.gpl`) format. See
+    /// [`Palette::to_gpl`].
+    #[must_use]
+    pub fn to_gpl_string(&self) -> String {
+        let header = format!("GIMP Palette\nName: {}\n#\n", single_line(self.name()));
+        let entries = self.iter().map(|(name, color)| {
+            let (r, g, b) = color.rgb();
+            format!("{r:3} {g:3} {b:3}\t{}\n", single_line(name))
+        });
+        std::iter::once(header).chain(entries).collect()
+    }
+}
+
+#[cfg(feature = "palette-import")]
+impl Palette {
+    /// Reads a palette in the GIMP palette (`.gpl`) format. Entries without a name are named
+    /// `"Untitled"`, the same way GIMP does it. Requires the `palette-import` feature.
+    ///
+    /// ## Errors
+    /// - [`PaletteParseError::MissingHeader`] if the text does not start with `GIMP Palette`.
+    /// - [`PaletteParseError::InvalidLine`] if a color entry cannot be parsed.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Color, Palette};
+    /// let gpl = "GIMP Palette\nName: Example\nColumns: 2\n#\n255   0   0\tred\n  0 128 128 teal\n";
+    /// let palette = Palette::from_gpl(gpl).unwrap();
+    /// assert_eq!(palette.name(), "Example");
+    /// assert_eq!(palette.get("teal"), Some(Color::from_rgb(0, 128, 128)));
+    /// ```
+    pub fn from_gpl(text: &str) -> Result<Self, PaletteParseError> {
+        let mut lines = text.trim_start_matches('\u{feff}').lines().enumerate();
+        match lines.next() {
+            Some((_, header)) if header.trim() == "GIMP Palette" => {}
+            _ => return Err(PaletteParseError::MissingHeader),
+        }
+
+        let mut palette = Palette::default();
+        for (idx, line) in lines {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix("Name:") {
+                palette.set_name(name.trim());
+                continue;
+            }
+            if line.starts_with("Columns:") {
+                continue;
+            }
+
+            let mut parts = line.split_whitespace();
+            let mut channel = || {
+                parts
+                    .next()
+                    .and_then(|p| p.parse::<u8>().ok())
+                    .ok_or(PaletteParseError::InvalidLine(idx + 1))
+            };
+            let color = Color::from_rgb(channel()?, channel()?, channel()?);
+            let name = parts.collect::<Vec<_>>().join(" ");
+            let name = if name.is_empty() {
+                "Untitled".to_string()
+            } else {
+                name
+            };
+            palette.push(name, color);
+        }
+        Ok(palette)
+    }
+}
+
+/// Replaces line breaks so a name cannot break the line based format.
+fn single_line(name: &str) -> String {
+    name.replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{palettes::tailwind, Palette};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn gpl_string() {
+        let palette = Palette::new("multi\nline").with("a\r\nb", (1, 22, 255));
+        assert_eq!(
+            palette.to_gpl_string(),
+            "GIMP Palette\nName: multi line\n#\n  1  22 255\ta  b\n"
+        );
+        assert_eq!(
+            Palette::default().to_gpl_string(),
+            "GIMP Palette\nName: \n#\n"
+        );
+    }
+
+    #[cfg(feature = "palette-import")]
+    #[test]
+    fn gpl_round_trip() {
+        let palette = tailwind::palette();
+        assert_eq!(Palette::from_gpl(&palette.to_gpl_string()), Ok(palette));
+    }
+
+    #[cfg(feature = "palette-import")]
+    #[test]
+    fn gpl_errors() {
+        use crate::PaletteParseError;
+
+        assert_eq!(
+            Palette::from_gpl("Not a palette\n"),
+            Err(PaletteParseError::MissingHeader)
+        );
+        assert_eq!(Palette::from_gpl(""), Err(PaletteParseError::MissingHeader));
+        assert_eq!(
+            Palette::from_gpl("GIMP Palette\n# comment\n\n1 2 300 x\n"),
+            Err(PaletteParseError::InvalidLine(4))
+        );
+        assert_eq!(
+            Palette::from_gpl("GIMP Palette\n1 2\n"),
+            Err(PaletteParseError::InvalidLine(2))
+        );
+
+        let palette = Palette::from_gpl("\u{feff}GIMP Palette\r\n10\t20\t30\r\n").unwrap();
+        assert_eq!(
+            palette.get("Untitled"),
+            Some(crate::Color::from_rgb(10, 20, 30))
+        );
+    }
+
+    #[test]
+    fn tailwind_gpl() {
+        let gpl = tailwind::palette().to_gpl_string();
+        assert_eq!(gpl.lines().count(), 3 + 242);
+        assert!(gpl.contains("\n 59 130 246\tblue-500\n"));
+    }
+}
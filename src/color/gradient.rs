@@ -0,0 +1,215 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{Ansi, BlendSpace, Color, ToColor};
+
+/// A linear gradient between two or more evenly spaced color stops.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{BlendSpace, Color, Gradient};
+/// let gradient = Gradient::new((255, 0, 0), (0, 0, 255));
+/// assert_eq!(gradient.at(0.0), Color::from_rgb(255, 0, 0));
+/// assert_eq!(gradient.at(0.5), Color::from_rgb(128, 0, 128));
+/// assert_eq!(gradient.at(1.0), Color::from_rgb(0, 0, 255));
+///
+/// // Blending in linear light keeps the middle from getting muddy.
+/// let gradient = gradient.space(BlendSpace::Linear);
+/// assert_eq!(gradient.at(0.5), Color::from_rgb(188, 0, 188));
+/// ```
+///
+/// Deserializing a gradient with fewer than two stops fails.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawGradient"))]
+pub struct Gradient {
+    stops: Vec<Color>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    space: BlendSpace,
+}
+
+/// A deserialized [`Gradient`] whose stops have not been checked yet.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawGradient {
+    stops: Vec<Color>,
+    #[serde(default)]
+    space: BlendSpace,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawGradient> for Gradient {
+    type Error = &'static str;
+
+    fn try_from(raw: RawGradient) -> Result<Self, Self::Error> {
+        if raw.stops.len() < 2 {
+            return Err("a gradient needs at least two stops");
+        }
+        Ok(Self {
+            stops: raw.stops,
+            space: raw.space,
+        })
+    }
+}
+
+impl Gradient {
+    /// Creates a new [`Gradient`] going from `start` to `end`.
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn new<S: ToColor, E: ToColor>(start: S, end: E) -> Self {
+        Self {
+            stops: vec![start.to_color(), end.to_color()],
+            space: BlendSpace::Srgb,
+        }
+    }
+
+    /// Builder function to add another stop to the end of this gradient. Stops are always
+    /// evenly spaced.
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn then<C: ToColor>(mut self, color: C) -> Self {
+        self.stops.push(color.to_color());
+        self
+    }
+
+    /// Builder function to set the color space the stops are blended in. The default is
+    /// [`BlendSpace::Srgb`].
+    #[must_use]
+    pub const fn space(mut self, space: BlendSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Gets the color stops of this gradient.
+    #[must_use]
+    pub fn stops(&self) -> &[Color] {
+        &self.stops
+    }
+
+    /// Gets the color at position `t`, which is clamped to `0.0..=1.0`.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    #[must_use]
+    pub fn at(&self, t: f32) -> Color {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let segments = self.stops.len() - 1;
+        let scaled = t * segments as f32;
+        let idx = (scaled.floor() as usize).min(segments - 1);
+        self.stops[idx].lerp_in(self.stops[idx + 1], scaled - idx as f32, self.space)
+    }
+
+    /// Gets `count` colors sampled evenly along this gradient, including both ends.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn steps(&self, count: usize) -> Vec<Color> {
+        match count {
+            0 => Vec::new(),
+            1 => vec![self.at(0.0)],
+            _ => (0..count)
+                .map(|idx| self.at(idx as f32 / (count - 1) as f32))
+                .collect(),
+        }
+    }
+}
+
+/// Trait for anything that can provide a style for a position along a range, such as a
+/// single [`Ansi`] (which is the same everywhere) or a [`Gradient`].
+pub trait Ramp {
+    /// Gets the style for position `t`, where `t` is in `0.0..=1.0`.
+    fn style_at(&self, t: f32) -> Ansi;
+}
+
+impl Ramp for Ansi {
+    fn style_at(&self, _t: f32) -> Ansi {
+        *self
+    }
+}
+
+impl Ramp for Color {
+    fn style_at(&self, _t: f32) -> Ansi {
+        Ansi::from_fg(*self)
+    }
+}
+
+impl Ramp for Gradient {
+    fn style_at(&self, t: f32) -> Ansi {
+        Ansi::from_fg(self.at(t))
+    }
+}
+
+impl<R: Ramp + ?Sized> Ramp for &R {
+    fn style_at(&self, t: f32) -> Ansi {
+        (**self).style_at(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn multiple_stops() {
+        let gradient = Gradient::new((0, 0, 0), (100, 100, 100)).then((100, 0, 0));
+        assert_eq!(gradient.stops().len(), 3);
+        assert_eq!(gradient.at(0.25), Color::from_rgb(50, 50, 50));
+        assert_eq!(gradient.at(0.5), Color::from_rgb(100, 100, 100));
+        assert_eq!(gradient.at(0.75), Color::from_rgb(100, 50, 50));
+        assert_eq!(gradient.at(1.0), Color::from_rgb(100, 0, 0));
+    }
+
+    #[test]
+    fn clamping() {
+        let gradient = Gradient::new((0, 0, 0), (255, 255, 255));
+        assert_eq!(gradient.at(-1.0), Color::from_rgb(0, 0, 0));
+        assert_eq!(gradient.at(2.0), Color::from_rgb(255, 255, 255));
+        assert_eq!(gradient.at(f32::NAN), Color::from_rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn steps() {
+        let gradient = Gradient::new((0, 0, 0), (200, 100, 0));
+        assert_eq!(gradient.steps(0), vec![]);
+        assert_eq!(gradient.steps(1), vec![Color::from_rgb(0, 0, 0)]);
+        assert_eq!(
+            gradient.steps(3),
+            vec![
+                Color::from_rgb(0, 0, 0),
+                Color::from_rgb(100, 50, 0),
+                Color::from_rgb(200, 100, 0)
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_needs_two_stops() {
+        let gradient = Gradient::new((0, 0, 0), (255, 255, 255)).space(BlendSpace::Linear);
+        let json = serde_json::to_string(&gradient).unwrap();
+        assert_eq!(serde_json::from_str::<Gradient>(&json).unwrap(), gradient);
+
+        let one_stop = serde_json::to_string(&serde_json::json!({
+            "stops": [gradient.stops()[0]],
+        }))
+        .unwrap();
+        assert!(serde_json::from_str::<Gradient>(&one_stop).is_err());
+        assert!(serde_json::from_str::<Gradient>(r#"{"stops":[]}"#).is_err());
+    }
+
+    #[test]
+    fn ramps() {
+        let gradient = Gradient::new((0, 0, 0), (255, 255, 255));
+        assert_eq!(gradient.style_at(1.0), Ansi::from_fg((255, 255, 255)));
+        assert_eq!(Ansi::red().style_at(0.3), Ansi::red());
+        assert_eq!(
+            Color::from_rgb(1, 2, 3).style_at(0.3),
+            Ansi::from_fg((1, 2, 3))
+        );
+    }
+}
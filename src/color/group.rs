@@ -0,0 +1,361 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::Colors;
+
+/// The groups the CSS named colors are traditionally sorted into, e.g. on MDN and in the
+/// "Web colors" tables, in their usual order. See [`Colors::group`] and
+/// [`Colors::web_categories`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorGroup {
+    /// Pink colors, e.g. [`Colors::HotPink`].
+    Pink,
+    /// Red colors, e.g. [`Colors::Crimson`].
+    Red,
+    /// Orange colors, e.g. [`Colors::Tomato`].
+    Orange,
+    /// Yellow colors, e.g. [`Colors::Gold`].
+    Yellow,
+    /// Brown colors, e.g. [`Colors::Chocolate`].
+    Brown,
+    /// Green colors, e.g. [`Colors::ForestGreen`].
+    Green,
+    /// Cyan colors, e.g. [`Colors::Teal`].
+    Cyan,
+    /// Blue colors, e.g. [`Colors::RoyalBlue`].
+    Blue,
+    /// Purple, violet and magenta colors, e.g. [`Colors::Orchid`].
+    Purple,
+    /// White colors, e.g. [`Colors::Ivory`].
+    White,
+    /// Gray and black colors, e.g. [`Colors::SlateGray`].
+    Gray,
+}
+
+/// The named colors of each group, in group order. Within a group, colors are in the order
+/// of the "Web colors" tables, with the `Grey` spellings right after their `Gray` ones.
+const CATEGORIES: [(ColorGroup, &[Colors]); 11] = [
+    (
+        ColorGroup::Pink,
+        &[
+            Colors::MediumVioletRed,
+            Colors::DeepPink,
+            Colors::PaleVioletRed,
+            Colors::HotPink,
+            Colors::LightPink,
+            Colors::Pink,
+        ],
+    ),
+    (
+        ColorGroup::Red,
+        &[
+            Colors::DarkRed,
+            Colors::Red,
+            Colors::Firebrick,
+            Colors::Crimson,
+            Colors::IndianRed,
+            Colors::LightCoral,
+            Colors::Salmon,
+            Colors::DarkSalmon,
+            Colors::LightSalmon,
+        ],
+    ),
+    (
+        ColorGroup::Orange,
+        &[
+            Colors::OrangeRed,
+            Colors::Tomato,
+            Colors::DarkOrange,
+            Colors::Coral,
+            Colors::Orange,
+        ],
+    ),
+    (
+        ColorGroup::Yellow,
+        &[
+            Colors::DarkKhaki,
+            Colors::Gold,
+            Colors::Khaki,
+            Colors::PeachPuff,
+            Colors::Yellow,
+            Colors::PaleGoldenRod,
+            Colors::Moccasin,
+            Colors::PapayaWhip,
+            Colors::LightGoldenRodYellow,
+            Colors::LemonChiffon,
+            Colors::LightYellow,
+        ],
+    ),
+    (
+        ColorGroup::Brown,
+        &[
+            Colors::Maroon,
+            Colors::Brown,
+            Colors::SaddleBrown,
+            Colors::Sienna,
+            Colors::Chocolate,
+            Colors::DarkGoldenRod,
+            Colors::Peru,
+            Colors::RosyBrown,
+            Colors::GoldenRod,
+            Colors::SandyBrown,
+            Colors::Tan,
+            Colors::BurlyWood,
+            Colors::Wheat,
+            Colors::NavajoWhite,
+            Colors::Bisque,
+            Colors::BlanchedAlmond,
+            Colors::CornSilk,
+        ],
+    ),
+    (
+        ColorGroup::Green,
+        &[
+            Colors::DarkGreen,
+            Colors::Green,
+            Colors::DarkOliveGreen,
+            Colors::ForestGreen,
+            Colors::SeaGreen,
+            Colors::Olive,
+            Colors::OliveDrab,
+            Colors::MediumSeaGreen,
+            Colors::LimeGreen,
+            Colors::Lime,
+            Colors::SpringGreen,
+            Colors::MediumSpringGreen,
+            Colors::DarkSeaGreen,
+            Colors::MediumAquaMarine,
+            Colors::YellowGreen,
+            Colors::LawnGreen,
+            Colors::Chartreuse,
+            Colors::LightGreen,
+            Colors::GreenYellow,
+            Colors::PaleGreen,
+        ],
+    ),
+    (
+        ColorGroup::Cyan,
+        &[
+            Colors::Teal,
+            Colors::DarkCyan,
+            Colors::LightSeaGreen,
+            Colors::CadetBlue,
+            Colors::DarkTurquoise,
+            Colors::MediumTurquoise,
+            Colors::Turquoise,
+            Colors::Aqua,
+            Colors::Cyan,
+            Colors::AquaMarine,
+            Colors::PaleTurquoise,
+            Colors::LightCyan,
+        ],
+    ),
+    (
+        ColorGroup::Blue,
+        &[
+            Colors::MidnightBlue,
+            Colors::Navy,
+            Colors::DarkBlue,
+            Colors::MediumBlue,
+            Colors::Blue,
+            Colors::RoyalBlue,
+            Colors::SteelBlue,
+            Colors::DodgerBlue,
+            Colors::DeepSkyBlue,
+            Colors::CornFlowerBlue,
+            Colors::SkyBlue,
+            Colors::LightSkyBlue,
+            Colors::LightSteelBlue,
+            Colors::LightBlue,
+            Colors::PowderBlue,
+        ],
+    ),
+    (
+        ColorGroup::Purple,
+        &[
+            Colors::Indigo,
+            Colors::Purple,
+            Colors::DarkMagenta,
+            Colors::DarkViolet,
+            Colors::DarkSlateBlue,
+            Colors::BlueViolet,
+            Colors::DarkOrchid,
+            Colors::Fuchsia,
+            Colors::Magenta,
+            Colors::SlateBlue,
+            Colors::MediumSlateBlue,
+            Colors::MediumOrchid,
+            Colors::MediumPurple,
+            Colors::Orchid,
+            Colors::Violet,
+            Colors::Plum,
+            Colors::Thistle,
+            Colors::Lavender,
+        ],
+    ),
+    (
+        ColorGroup::White,
+        &[
+            Colors::MistyRose,
+            Colors::AntiqueWhite,
+            Colors::Linen,
+            Colors::Beige,
+            Colors::WhiteSmoke,
+            Colors::LavenderBlush,
+            Colors::OldLace,
+            Colors::AliceBlue,
+            Colors::SeaShell,
+            Colors::GhostWhite,
+            Colors::Honeydew,
+            Colors::FloralWhite,
+            Colors::Azure,
+            Colors::MintCream,
+            Colors::Snow,
+            Colors::Ivory,
+            Colors::White,
+        ],
+    ),
+    (
+        ColorGroup::Gray,
+        &[
+            Colors::Black,
+            Colors::DarkSlateGray,
+            Colors::DimGray,
+            Colors::DimGrey,
+            Colors::SlateGray,
+            Colors::Gray,
+            Colors::Grey,
+            Colors::LightSlateGray,
+            Colors::DarkGray,
+            Colors::DarkGrey,
+            Colors::Silver,
+            Colors::LightGray,
+            Colors::LightGrey,
+            Colors::Gainsboro,
+        ],
+    ),
+];
+
+impl ColorGroup {
+    /// Every group, in their usual order from [`ColorGroup::Pink`] to [`ColorGroup::Gray`].
+    pub const ALL: [ColorGroup; 11] = [
+        ColorGroup::Pink,
+        ColorGroup::Red,
+        ColorGroup::Orange,
+        ColorGroup::Yellow,
+        ColorGroup::Brown,
+        ColorGroup::Green,
+        ColorGroup::Cyan,
+        ColorGroup::Blue,
+        ColorGroup::Purple,
+        ColorGroup::White,
+        ColorGroup::Gray,
+    ];
+
+    /// Gets the name of this group, e.g. `"Pink"`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ColorGroup::Pink => "Pink",
+            ColorGroup::Red => "Red",
+            ColorGroup::Orange => "Orange",
+            ColorGroup::Yellow => "Yellow",
+            ColorGroup::Brown => "Brown",
+            ColorGroup::Green => "Green",
+            ColorGroup::Cyan => "Cyan",
+            ColorGroup::Blue => "Blue",
+            ColorGroup::Purple => "Purple",
+            ColorGroup::White => "White",
+            ColorGroup::Gray => "Gray",
+        }
+    }
+
+    /// Gets the named colors in this group, in the order of the "Web colors" tables.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{ColorGroup, Colors};
+    /// assert_eq!(
+    ///     ColorGroup::Orange.colors(),
+    ///     [Colors::OrangeRed, Colors::Tomato, Colors::DarkOrange, Colors::Coral, Colors::Orange]
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn colors(&self) -> &'static [Colors] {
+        CATEGORIES[*self as usize].1
+    }
+}
+
+impl std::fmt::Display for ColorGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Colors {
+    /// Gets the [`ColorGroup`] this color belongs to.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{ColorGroup, Colors};
+    /// assert_eq!(Colors::Crimson.group(), ColorGroup::Red);
+    /// assert_eq!(Colors::DimGrey.group(), ColorGroup::Gray);
+    /// ```
+    #[must_use]
+    pub fn group(&self) -> ColorGroup {
+        CATEGORIES
+            .iter()
+            .find(|(_, colors)| colors.contains(self))
+            .map_or(ColorGroup::Gray, |(group, _)| *group)
+    }
+
+    /// Creates an iterator over the color groups with their named colors, in the order MDN and
+    /// the "Web colors" tables list them, so pickers and docs can use the same organization.
+    /// Every named color is in exactly one group.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{ColorGroup, Colors};
+    /// let (group, colors) = Colors::web_categories().next().unwrap();
+    /// assert_eq!(group, ColorGroup::Pink);
+    /// assert_eq!(colors[0], Colors::MediumVioletRed);
+    ///
+    /// let by_group: Vec<Colors> = Colors::web_categories()
+    ///     .flat_map(|(_, colors)| colors.iter().copied())
+    ///     .collect();
+    /// assert_eq!(by_group.len(), Colors::all().count());
+    /// ```
+    pub fn web_categories() -> impl Iterator<Item = (ColorGroup, &'static [Colors])> {
+        CATEGORIES.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn groups() {
+        for (idx, group) in ColorGroup::ALL.into_iter().enumerate() {
+            assert_eq!(CATEGORIES[idx].0, group);
+            assert!(group.colors().iter().all(|color| color.group() == group));
+        }
+        assert_eq!(ColorGroup::Purple.to_string(), "Purple");
+        assert_eq!(Colors::Lavender.group(), ColorGroup::Purple);
+        assert_eq!(Colors::AliceBlue.group(), ColorGroup::White);
+    }
+
+    #[test]
+    fn every_color_once() {
+        let mut grouped: Vec<Colors> = Colors::web_categories()
+            .flat_map(|(_, colors)| colors.iter().copied())
+            .collect();
+        grouped.sort();
+        assert_eq!(grouped, Colors::all().collect::<Vec<_>>());
+    }
+}
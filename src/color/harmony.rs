@@ -0,0 +1,139 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{Color, Palette};
+
+impl Color {
+    /// Creates a copy of this color with its hue rotated by `degrees`, keeping saturation and
+    /// lightness.
+    #[must_use]
+    pub fn rotate_hue(self, degrees: f32) -> Self {
+        let (hue, saturation, lightness) = self.to_hsl();
+        Self::from_hsl(hue + degrees, saturation, lightness)
+    }
+
+    /// Gets the complementary color scheme of this color: this color (`"base"`) and the color
+    /// opposite it on the color wheel (`"complement"`).
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Color;
+    /// let palette = Color::from_rgb(255, 0, 0).complementary();
+    /// assert_eq!(palette.get("complement"), Some(Color::from_rgb(0, 255, 255)));
+    /// ```
+    #[must_use]
+    pub fn complementary(self) -> Palette {
+        self.harmony("complementary", &[("complement", 180.0)])
+    }
+
+    /// Gets `count` analogous colors, neighbours of this color on the color wheel that are
+    /// `spread` degrees apart, centered on this color. The colors are named `"1"` to `"count"`
+    /// in order of hue.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Color;
+    /// let base = Color::from_rgb(255, 0, 0);
+    /// let palette = base.analogous(3, 30.0);
+    /// assert_eq!(palette.get("2"), Some(base));
+    /// assert_eq!(palette.get("3"), Some(Color::from_rgb(255, 128, 0)));
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn analogous(self, count: usize, spread: f32) -> Palette {
+        let center = count.saturating_sub(1) as f32 / 2.0;
+        let mut palette = Palette::new("analogous");
+        palette.extend((0..count).map(|idx| {
+            let color = self.rotate_hue((idx as f32 - center) * spread);
+            ((idx + 1).to_string(), color)
+        }));
+        palette
+    }
+
+    /// Gets the triadic color scheme of this color: this color (`"base"`) and the two colors
+    /// evenly spaced around the color wheel from it (`"triadic-1"` and `"triadic-2"`).
+    #[must_use]
+    pub fn triadic(self) -> Palette {
+        self.harmony("triadic", &[("triadic-1", 120.0), ("triadic-2", 240.0)])
+    }
+
+    /// Gets the tetradic (square) color scheme of this color: this color (`"base"`) and the
+    /// three colors at 90 degree steps around the color wheel from it (`"tetradic-1"` to
+    /// `"tetradic-3"`).
+    #[must_use]
+    pub fn tetradic(self) -> Palette {
+        self.harmony(
+            "tetradic",
+            &[
+                ("tetradic-1", 90.0),
+                ("tetradic-2", 180.0),
+                ("tetradic-3", 270.0),
+            ],
+        )
+    }
+
+    fn harmony(self, name: &str, rotations: &[(&str, f32)]) -> Palette {
+        let mut palette = Palette::new(name).with("base", self);
+        for (entry, degrees) in rotations {
+            palette.push(*entry, self.rotate_hue(*degrees));
+        }
+        palette
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn schemes() {
+        let red = Color::from_rgb(255, 0, 0);
+        assert_eq!(
+            red.complementary().iter().collect::<Vec<_>>(),
+            vec![("base", red), ("complement", Color::from_rgb(0, 255, 255))]
+        );
+        assert_eq!(
+            red.triadic().colors().collect::<Vec<_>>(),
+            vec![red, Color::from_rgb(0, 255, 0), Color::from_rgb(0, 0, 255)]
+        );
+        assert_eq!(
+            red.tetradic().colors().collect::<Vec<_>>(),
+            vec![
+                red,
+                Color::from_rgb(128, 255, 0),
+                Color::from_rgb(0, 255, 255),
+                Color::from_rgb(128, 0, 255)
+            ]
+        );
+        assert_eq!(red.triadic().name(), "triadic");
+    }
+
+    #[test]
+    fn analogous() {
+        let red = Color::from_rgb(255, 0, 0);
+        assert!(red.analogous(0, 30.0).is_empty());
+        assert_eq!(
+            red.analogous(1, 30.0).colors().collect::<Vec<_>>(),
+            vec![red]
+        );
+        assert_eq!(
+            red.analogous(4, 60.0).colors().collect::<Vec<_>>(),
+            vec![
+                Color::from_rgb(128, 0, 255),
+                Color::from_rgb(255, 0, 128),
+                Color::from_rgb(255, 128, 0),
+                Color::from_rgb(128, 255, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn grays_have_no_hue() {
+        let gray = Color::from_rgb(120, 120, 120);
+        assert!(gray.triadic().colors().all(|color| color == gray));
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::hash::{Hash, Hasher};
+
+use crate::{Colors, ToColor};
+
+/// Minimum contrast ratio a color needs against the background to be picked by
+/// [`Colors::for_key_on`].
+const MIN_CONTRAST: f32 = 3.0;
+
+/// 64-bit FNV-1a. Unlike [`DefaultHasher`](std::collections::hash_map::DefaultHasher), its
+/// output is specified, so keys keep their colors across runs and Rust versions.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+impl Colors {
+    /// Picks a named color for `key`, always the same color for the same key, so log viewers
+    /// and similar tools can consistently color the same module, host or thread across runs
+    /// without keeping track of the colors they assigned.
+    ///
+    /// Different keys can get the same color. Integer keys are hashed in native byte order,
+    /// so they only get the same color on platforms with the same endianness.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Colors;
+    /// assert_eq!(Colors::for_key("db::pool"), Colors::for_key("db::pool"));
+    /// ```
+    #[must_use]
+    pub fn for_key(key: impl Hash) -> Self {
+        Self::pick(key, Self::all())
+    }
+
+    /// Like [`Colors::for_key`], but only picks from the colors with a WCAG contrast ratio of
+    /// at least `3.0` against `background`, so the result stays readable on dark or light
+    /// backgrounds.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Color, Colors};
+    /// let black = Color::from_rgb(0, 0, 0);
+    /// let color = Colors::for_key_on("web-01", black);
+    /// assert!(color.into_color().contrast_ratio(black) >= 3.0);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn for_key_on<C: ToColor>(key: impl Hash, background: C) -> Self {
+        let background = background.to_color();
+        Self::pick(
+            key,
+            Self::all()
+                .filter(|color| color.into_color().contrast_ratio(background) >= MIN_CONTRAST),
+        )
+    }
+
+    /// Picks one of `candidates` based on the hash of `key`.
+    #[allow(clippy::cast_possible_truncation)]
+    fn pick(key: impl Hash, candidates: impl Iterator<Item = Self>) -> Self {
+        let mut hasher = Fnv1a::default();
+        key.hash(&mut hasher);
+        let candidates = candidates.collect::<Vec<_>>();
+        if candidates.is_empty() {
+            return Self::Black;
+        }
+        candidates[(hasher.finish() % candidates.len() as u64) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn fnv() {
+        let mut hasher = Fnv1a::default();
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn stable() {
+        assert_eq!(Colors::for_key("alpha"), Colors::for_key("alpha"));
+        assert_eq!(Colors::for_key(42_u8), Colors::for_key(42_u8));
+        let assigned = ["api", "db", "cache", "worker", "auth", "queue"]
+            .iter()
+            .map(Colors::for_key)
+            .collect::<std::collections::HashSet<_>>();
+        assert!(assigned.len() > 1);
+    }
+
+    #[test]
+    fn readable() {
+        let white = Color::from_rgb(255, 255, 255);
+        let black = Color::from_rgb(0, 0, 0);
+        for key in 0..200 {
+            let on_white = Colors::for_key_on(key, white).into_color();
+            let on_black = Colors::for_key_on(key, black).into_color();
+            assert!(on_white.contrast_ratio(white) >= MIN_CONTRAST);
+            assert!(on_black.contrast_ratio(black) >= MIN_CONTRAST);
+        }
+    }
+}
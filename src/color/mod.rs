@@ -4,20 +4,46 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+mod adjust;
+#[cfg(feature = "palette-import")]
+mod ase;
+mod blend;
 // Private module so who cares
 #[allow(clippy::module_inception)]
 mod color;
+#[cfg(feature = "named-colors")]
 mod colors;
 mod error;
+mod gpl;
+mod gradient;
+#[cfg(feature = "named-colors")]
+mod group;
+mod harmony;
+#[cfg(feature = "named-colors")]
+mod key_color;
+mod palette;
+pub mod palette256;
+pub mod palettes;
+mod temperature;
 mod traits;
 
+#[cfg(feature = "named-colors")]
 pub mod iter {
     pub use super::colors::iter::*;
 }
 
+pub use adjust::ColorAdjust;
+pub use blend::BlendSpace;
 pub use color::Color;
+#[cfg(feature = "named-colors")]
 pub use colors::Colors;
 pub use error::ColorParseError;
+#[cfg(feature = "palette-import")]
+pub use error::PaletteParseError;
+pub use gradient::{Gradient, Ramp};
+#[cfg(feature = "named-colors")]
+pub use group::ColorGroup;
+pub use palette::Palette;
 pub use traits::*;
 
 #[cfg(test)]
@@ -35,6 +61,27 @@ mod tests {
         assert_eq!(color3.rgb(), (255, 0, 0));
     }
 
+    #[test]
+    fn hex_errors() {
+        assert_eq!(
+            Color::from_hex("#FF000G"),
+            Err(ColorParseError::BadChar {
+                input: "#FF000G".to_string(),
+                position: 6,
+                found: 'G'
+            })
+        );
+        assert_eq!(Color::from_hex("+f+f+f").unwrap_err().fragment(), "+");
+        assert_eq!(
+            Color::from_hex("abcd"),
+            Err(ColorParseError::WrongLength {
+                input: "abcd".to_string(),
+                digits: 4
+            })
+        );
+        assert_eq!(Color::from_hex("#").unwrap_err().position(), 1);
+    }
+
     #[test]
     fn hex_convert_unicode() {
         let color1 = Color::from_hex("#💜💙💚💛💚💙💜");
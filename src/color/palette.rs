@@ -0,0 +1,255 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{Color, ToColor};
+
+/// A named, ordered, collection of named colors.
+///
+/// Palettes can be built up in code, collected from an iterator of `(name, color)` pairs, or
+/// taken from one of the built-in [`palettes`](crate::palettes).
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Color, Palette};
+/// let palette = Palette::new("brand")
+///     .with("primary", (0x1d, 0x4e, 0xd8))
+///     .with("accent", Color::from_hex("#f97316").unwrap());
+///
+/// assert_eq!(palette.len(), 2);
+/// assert_eq!(palette.get("accent"), Some(Color::from_rgb(0xf9, 0x73, 0x16)));
+/// assert_eq!(palette.iter().map(|(name, _)| name).collect::<Vec<_>>(), ["primary", "accent"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Palette {
+    name: String,
+    entries: Vec<(String, Color)>,
+}
+
+impl Palette {
+    /// Creates a new, empty, [`Palette`] with the given name.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Builder function to add a color to the end of this palette.
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn with<C: ToColor>(mut self, name: impl Into<String>, color: C) -> Self {
+        self.push(name, color);
+        self
+    }
+
+    /// Adds a color to the end of this palette.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn push<C: ToColor>(&mut self, name: impl Into<String>, color: C) {
+        self.entries.push((name.into(), color.to_color()));
+    }
+
+    /// Gets the name of this palette.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Sets the name of this palette.
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = name.into();
+    }
+
+    /// Gets the number of colors in this palette.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if this palette has no colors.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets the first color in this palette with the given name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == name)
+            .map(|(_, color)| *color)
+    }
+
+    /// Iterates over the `(name, color)` entries of this palette, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Color)> + '_ {
+        self.entries
+            .iter()
+            .map(|(name, color)| (name.as_str(), *color))
+    }
+
+    /// Iterates over the colors of this palette, in order.
+    pub fn colors(&self) -> impl Iterator<Item = Color> + '_ {
+        self.entries.iter().map(|(_, color)| *color)
+    }
+
+    /// Generates a palette of `n` colors that are as easy to tell apart as possible, for coloring
+    /// an arbitrary number of series, threads, hosts, etc.
+    ///
+    /// Hues are stepped by the golden angle, so every prefix of the palette is well spread out
+    /// and the first colors stay the same no matter how large `n` is. Lightness also alternates
+    /// between colors, to separate neighbours further. The colors are named `"1"` to `"n"`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Palette;
+    /// let palette = Palette::distinct(8);
+    /// assert_eq!(palette.len(), 8);
+    /// assert_eq!(Palette::distinct(3).colors().collect::<Vec<_>>(), palette.colors().take(3).collect::<Vec<_>>());
+    /// ```
+    #[must_use]
+    pub fn distinct(n: usize) -> Self {
+        Self::distinct_colors(n, None)
+    }
+
+    /// Like [`Palette::distinct`], but every color is adjusted (darkened or lightened) until it
+    /// has a WCAG contrast ratio of at least `3.0` against `background`, so it stays legible on
+    /// it.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Color, Palette};
+    /// let white = Color::from_rgb(255, 255, 255);
+    /// let palette = Palette::distinct_on(12, white);
+    /// assert!(palette.colors().all(|color| color.contrast_ratio(white) >= 3.0));
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[must_use]
+    pub fn distinct_on<C: ToColor>(n: usize, background: C) -> Self {
+        Self::distinct_colors(n, Some(background.to_color()))
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn distinct_colors(n: usize, background: Option<Color>) -> Self {
+        const GOLDEN_ANGLE: f32 = 137.507_77;
+        const SATURATION: f32 = 0.7;
+        const LIGHTNESS: [f32; 3] = [0.5, 0.65, 0.38];
+        const MIN_CONTRAST: f32 = 3.0;
+
+        let mut palette = Self::new("distinct");
+        for idx in 0..n {
+            let hue = (idx % 360) as f32 * GOLDEN_ANGLE + (idx / 360) as f32;
+            let mut lightness = LIGHTNESS[idx % LIGHTNESS.len()];
+            let mut color = Color::from_hsl(hue, SATURATION, lightness);
+
+            if let Some(background) = background {
+                let black = Color::from_rgb(0, 0, 0);
+                let white = Color::from_rgb(255, 255, 255);
+                let step = if background.contrast_ratio(black) >= background.contrast_ratio(white) {
+                    -0.02
+                } else {
+                    0.02
+                };
+                while color.contrast_ratio(background) < MIN_CONTRAST
+                    && (0.0..=1.0).contains(&(lightness + step))
+                {
+                    lightness += step;
+                    color = Color::from_hsl(hue, SATURATION, lightness);
+                }
+            }
+
+            palette.push((idx + 1).to_string(), color);
+        }
+        palette
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, Color)> for Palette {
+    fn from_iter<T: IntoIterator<Item = (S, Color)>>(iter: T) -> Self {
+        let mut palette = Self::default();
+        palette.extend(iter);
+        palette
+    }
+}
+
+impl<S: Into<String>> Extend<(S, Color)> for Palette {
+    fn extend<T: IntoIterator<Item = (S, Color)>>(&mut self, iter: T) {
+        self.entries
+            .extend(iter.into_iter().map(|(name, color)| (name.into(), color)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn build() {
+        let mut palette = [("a", Color::from_rgb(1, 1, 1))]
+            .into_iter()
+            .collect::<Palette>();
+        assert_eq!(palette.name(), "");
+        palette.set_name("test");
+        palette.push("b", (2, 2, 2));
+        palette.extend([("a".to_string(), Color::from_rgb(3, 3, 3))]);
+
+        assert_eq!(palette.name(), "test");
+        assert_eq!(palette.len(), 3);
+        assert!(!palette.is_empty());
+        assert_eq!(palette.get("a"), Some(Color::from_rgb(1, 1, 1)));
+        assert_eq!(palette.get("c"), None);
+        assert_eq!(
+            palette.colors().collect::<Vec<_>>(),
+            vec![
+                Color::from_rgb(1, 1, 1),
+                Color::from_rgb(2, 2, 2),
+                Color::from_rgb(3, 3, 3)
+            ]
+        );
+        assert!(Palette::new("empty").is_empty());
+    }
+
+    #[test]
+    fn distinct() {
+        assert!(Palette::distinct(0).is_empty());
+
+        let palette = Palette::distinct(40);
+        let mut colors = palette.colors().collect::<Vec<_>>();
+        assert_eq!(palette.iter().next().map(|(name, _)| name), Some("1"));
+        assert_eq!(palette.get("40"), colors.last().copied());
+        colors.sort();
+        colors.dedup();
+        assert_eq!(colors.len(), 40);
+
+        // Consecutive colors are never close in hue.
+        for pair in palette.colors().collect::<Vec<_>>().windows(2) {
+            let (a, b) = (pair[0].to_hsl().0, pair[1].to_hsl().0);
+            let distance = (a - b).abs().min(360.0 - (a - b).abs());
+            assert!(distance > 90.0, "{pair:?}");
+        }
+    }
+
+    #[test]
+    fn distinct_on_background() {
+        for background in [
+            Color::from_rgb(255, 255, 255),
+            Color::from_rgb(0, 0, 0),
+            Color::from_rgb(0x28, 0x2c, 0x34),
+            Color::from_rgb(0xfd, 0xf6, 0xe3),
+        ] {
+            let palette = Palette::distinct_on(30, background);
+            assert_eq!(palette.len(), 30);
+            for color in palette.colors() {
+                assert!(
+                    color.contrast_ratio(background) >= 3.0,
+                    "{color} on {background}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,845 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! The xterm 256-color palette, by index and by its conventional name.
+//!
+//! Every entry is available as a `u8` index constant, for use with
+//! [`Ansi::fg_indexed`](crate::Ansi::fg_indexed) and friends. A few names are used for more than
+//! one entry in the palette; the first entry gets the plain name and the others get their index
+//! appended, e.g. [`BLUE3`] (19) and [`BLUE3_20`].
+//!
+//! ## Example
+//! ```
+//! # use ansirs::{palette256, Ansi, Color};
+//! let style = Ansi::new().fg_indexed(palette256::ORANGE1);
+//! assert_eq!(style.to_string(), "\x1b[38;5;214m");
+//!
+//! assert_eq!(palette256::name(palette256::ORANGE1), "Orange1");
+//! assert_eq!(palette256::from_index(palette256::ORANGE1), Color::from_rgb(0xff, 0xaf, 0x00));
+//! assert_eq!(palette256::from_name("orange1"), Some(214));
+//! ```
+
+use crate::Color;
+
+/// The conventional names of the palette entries, by index.
+const NAMES: [&str; 256] = [
+    "Black",
+    "Maroon",
+    "Green",
+    "Olive",
+    "Navy",
+    "Purple",
+    "Teal",
+    "Silver",
+    "Grey",
+    "Red",
+    "Lime",
+    "Yellow",
+    "Blue",
+    "Fuchsia",
+    "Aqua",
+    "White",
+    "Grey0",
+    "NavyBlue",
+    "DarkBlue",
+    "Blue3",
+    "Blue3",
+    "Blue1",
+    "DarkGreen",
+    "DeepSkyBlue4",
+    "DeepSkyBlue4",
+    "DeepSkyBlue4",
+    "DodgerBlue3",
+    "DodgerBlue2",
+    "Green4",
+    "SpringGreen4",
+    "Turquoise4",
+    "DeepSkyBlue3",
+    "DeepSkyBlue3",
+    "DodgerBlue1",
+    "Green3",
+    "SpringGreen3",
+    "DarkCyan",
+    "LightSeaGreen",
+    "DeepSkyBlue2",
+    "DeepSkyBlue1",
+    "Green3",
+    "SpringGreen3",
+    "SpringGreen2",
+    "Cyan3",
+    "DarkTurquoise",
+    "Turquoise2",
+    "Green1",
+    "SpringGreen2",
+    "SpringGreen1",
+    "MediumSpringGreen",
+    "Cyan2",
+    "Cyan1",
+    "DarkRed",
+    "DeepPink4",
+    "Purple4",
+    "Purple4",
+    "Purple3",
+    "BlueViolet",
+    "Orange4",
+    "Grey37",
+    "MediumPurple4",
+    "SlateBlue3",
+    "SlateBlue3",
+    "RoyalBlue1",
+    "Chartreuse4",
+    "DarkSeaGreen4",
+    "PaleTurquoise4",
+    "SteelBlue",
+    "SteelBlue3",
+    "CornflowerBlue",
+    "Chartreuse3",
+    "DarkSeaGreen4",
+    "CadetBlue",
+    "CadetBlue",
+    "SkyBlue3",
+    "SteelBlue1",
+    "Chartreuse3",
+    "PaleGreen3",
+    "SeaGreen3",
+    "Aquamarine3",
+    "MediumTurquoise",
+    "SteelBlue1",
+    "Chartreuse2",
+    "SeaGreen2",
+    "SeaGreen1",
+    "SeaGreen1",
+    "Aquamarine1",
+    "DarkSlateGray2",
+    "DarkRed",
+    "DeepPink4",
+    "DarkMagenta",
+    "DarkMagenta",
+    "DarkViolet",
+    "Purple",
+    "Orange4",
+    "LightPink4",
+    "Plum4",
+    "MediumPurple3",
+    "MediumPurple3",
+    "SlateBlue1",
+    "Yellow4",
+    "Wheat4",
+    "Grey53",
+    "LightSlateGrey",
+    "MediumPurple",
+    "LightSlateBlue",
+    "Yellow4",
+    "DarkOliveGreen3",
+    "DarkSeaGreen",
+    "LightSkyBlue3",
+    "LightSkyBlue3",
+    "SkyBlue2",
+    "Chartreuse2",
+    "DarkOliveGreen3",
+    "PaleGreen3",
+    "DarkSeaGreen3",
+    "DarkSlateGray3",
+    "SkyBlue1",
+    "Chartreuse1",
+    "LightGreen",
+    "LightGreen",
+    "PaleGreen1",
+    "Aquamarine1",
+    "DarkSlateGray1",
+    "Red3",
+    "DeepPink4",
+    "MediumVioletRed",
+    "Magenta3",
+    "DarkViolet",
+    "Purple",
+    "DarkOrange3",
+    "IndianRed",
+    "HotPink3",
+    "MediumOrchid3",
+    "MediumOrchid",
+    "MediumPurple2",
+    "DarkGoldenrod",
+    "LightSalmon3",
+    "RosyBrown",
+    "Grey63",
+    "MediumPurple2",
+    "MediumPurple1",
+    "Gold3",
+    "DarkKhaki",
+    "NavajoWhite3",
+    "Grey69",
+    "LightSteelBlue3",
+    "LightSteelBlue",
+    "Yellow3",
+    "DarkOliveGreen3",
+    "DarkSeaGreen3",
+    "DarkSeaGreen2",
+    "LightCyan3",
+    "LightSkyBlue1",
+    "GreenYellow",
+    "DarkOliveGreen2",
+    "PaleGreen1",
+    "DarkSeaGreen2",
+    "DarkSeaGreen1",
+    "PaleTurquoise1",
+    "Red3",
+    "DeepPink3",
+    "DeepPink3",
+    "Magenta3",
+    "Magenta3",
+    "Magenta2",
+    "DarkOrange3",
+    "IndianRed",
+    "HotPink3",
+    "HotPink2",
+    "Orchid",
+    "MediumOrchid1",
+    "Orange3",
+    "LightSalmon3",
+    "LightPink3",
+    "Pink3",
+    "Plum3",
+    "Violet",
+    "Gold3",
+    "LightGoldenrod3",
+    "Tan",
+    "MistyRose3",
+    "Thistle3",
+    "Plum2",
+    "Yellow3",
+    "Khaki3",
+    "LightGoldenrod2",
+    "LightYellow3",
+    "Grey84",
+    "LightSteelBlue1",
+    "Yellow2",
+    "DarkOliveGreen1",
+    "DarkOliveGreen1",
+    "DarkSeaGreen1",
+    "Honeydew2",
+    "LightCyan1",
+    "Red1",
+    "DeepPink2",
+    "DeepPink1",
+    "DeepPink1",
+    "Magenta2",
+    "Magenta1",
+    "OrangeRed1",
+    "IndianRed1",
+    "IndianRed1",
+    "HotPink",
+    "HotPink",
+    "MediumOrchid1",
+    "DarkOrange",
+    "Salmon1",
+    "LightCoral",
+    "PaleVioletRed1",
+    "Orchid2",
+    "Orchid1",
+    "Orange1",
+    "SandyBrown",
+    "LightSalmon1",
+    "LightPink1",
+    "Pink1",
+    "Plum1",
+    "Gold1",
+    "LightGoldenrod2",
+    "LightGoldenrod2",
+    "NavajoWhite1",
+    "MistyRose1",
+    "Thistle1",
+    "Yellow1",
+    "LightGoldenrod1",
+    "Khaki1",
+    "Wheat1",
+    "Cornsilk1",
+    "Grey100",
+    "Grey3",
+    "Grey7",
+    "Grey11",
+    "Grey15",
+    "Grey19",
+    "Grey23",
+    "Grey27",
+    "Grey30",
+    "Grey35",
+    "Grey39",
+    "Grey42",
+    "Grey46",
+    "Grey50",
+    "Grey54",
+    "Grey58",
+    "Grey62",
+    "Grey66",
+    "Grey70",
+    "Grey74",
+    "Grey78",
+    "Grey82",
+    "Grey85",
+    "Grey89",
+    "Grey93",
+];
+
+/// Gets the RGB color of the palette entry at `idx`.
+#[must_use]
+pub const fn from_index(idx: u8) -> Color {
+    Color::ansi_256_to_color(idx)
+}
+
+/// Gets the conventional name of the palette entry at `idx`, e.g. `"Orange1"` for `214`.
+#[must_use]
+pub const fn name(idx: u8) -> &'static str {
+    NAMES[idx as usize]
+}
+
+/// Finds the index of the first palette entry with the given name, ignoring case.
+#[must_use]
+pub fn from_name(name: &str) -> Option<u8> {
+    NAMES
+        .iter()
+        .position(|entry| entry.eq_ignore_ascii_case(name))
+        .and_then(|idx| u8::try_from(idx).ok())
+}
+
+/// `Black` (`#000000`).
+pub const BLACK: u8 = 0;
+/// `Maroon` (`#800000`).
+pub const MAROON: u8 = 1;
+/// `Green` (`#008000`).
+pub const GREEN: u8 = 2;
+/// `Olive` (`#808000`).
+pub const OLIVE: u8 = 3;
+/// `Navy` (`#000080`).
+pub const NAVY: u8 = 4;
+/// `Purple` (`#800080`).
+pub const PURPLE: u8 = 5;
+/// `Teal` (`#008080`).
+pub const TEAL: u8 = 6;
+/// `Silver` (`#c0c0c0`).
+pub const SILVER: u8 = 7;
+/// `Grey` (`#808080`).
+pub const GREY: u8 = 8;
+/// `Red` (`#ff0000`).
+pub const RED: u8 = 9;
+/// `Lime` (`#00ff00`).
+pub const LIME: u8 = 10;
+/// `Yellow` (`#ffff00`).
+pub const YELLOW: u8 = 11;
+/// `Blue` (`#0000ff`).
+pub const BLUE: u8 = 12;
+/// `Fuchsia` (`#ff00ff`).
+pub const FUCHSIA: u8 = 13;
+/// `Aqua` (`#00ffff`).
+pub const AQUA: u8 = 14;
+/// `White` (`#ffffff`).
+pub const WHITE: u8 = 15;
+/// `Grey0` (`#000000`).
+pub const GREY0: u8 = 16;
+/// `NavyBlue` (`#00005f`).
+pub const NAVY_BLUE: u8 = 17;
+/// `DarkBlue` (`#000087`).
+pub const DARK_BLUE: u8 = 18;
+/// `Blue3` (`#0000af`).
+pub const BLUE3: u8 = 19;
+/// `Blue3` (`#0000d7`).
+pub const BLUE3_20: u8 = 20;
+/// `Blue1` (`#0000ff`).
+pub const BLUE1: u8 = 21;
+/// `DarkGreen` (`#005f00`).
+pub const DARK_GREEN: u8 = 22;
+/// `DeepSkyBlue4` (`#005f5f`).
+pub const DEEP_SKY_BLUE4: u8 = 23;
+/// `DeepSkyBlue4` (`#005f87`).
+pub const DEEP_SKY_BLUE4_24: u8 = 24;
+/// `DeepSkyBlue4` (`#005faf`).
+pub const DEEP_SKY_BLUE4_25: u8 = 25;
+/// `DodgerBlue3` (`#005fd7`).
+pub const DODGER_BLUE3: u8 = 26;
+/// `DodgerBlue2` (`#005fff`).
+pub const DODGER_BLUE2: u8 = 27;
+/// `Green4` (`#008700`).
+pub const GREEN4: u8 = 28;
+/// `SpringGreen4` (`#00875f`).
+pub const SPRING_GREEN4: u8 = 29;
+/// `Turquoise4` (`#008787`).
+pub const TURQUOISE4: u8 = 30;
+/// `DeepSkyBlue3` (`#0087af`).
+pub const DEEP_SKY_BLUE3: u8 = 31;
+/// `DeepSkyBlue3` (`#0087d7`).
+pub const DEEP_SKY_BLUE3_32: u8 = 32;
+/// `DodgerBlue1` (`#0087ff`).
+pub const DODGER_BLUE1: u8 = 33;
+/// `Green3` (`#00af00`).
+pub const GREEN3: u8 = 34;
+/// `SpringGreen3` (`#00af5f`).
+pub const SPRING_GREEN3: u8 = 35;
+/// `DarkCyan` (`#00af87`).
+pub const DARK_CYAN: u8 = 36;
+/// `LightSeaGreen` (`#00afaf`).
+pub const LIGHT_SEA_GREEN: u8 = 37;
+/// `DeepSkyBlue2` (`#00afd7`).
+pub const DEEP_SKY_BLUE2: u8 = 38;
+/// `DeepSkyBlue1` (`#00afff`).
+pub const DEEP_SKY_BLUE1: u8 = 39;
+/// `Green3` (`#00d700`).
+pub const GREEN3_40: u8 = 40;
+/// `SpringGreen3` (`#00d75f`).
+pub const SPRING_GREEN3_41: u8 = 41;
+/// `SpringGreen2` (`#00d787`).
+pub const SPRING_GREEN2: u8 = 42;
+/// `Cyan3` (`#00d7af`).
+pub const CYAN3: u8 = 43;
+/// `DarkTurquoise` (`#00d7d7`).
+pub const DARK_TURQUOISE: u8 = 44;
+/// `Turquoise2` (`#00d7ff`).
+pub const TURQUOISE2: u8 = 45;
+/// `Green1` (`#00ff00`).
+pub const GREEN1: u8 = 46;
+/// `SpringGreen2` (`#00ff5f`).
+pub const SPRING_GREEN2_47: u8 = 47;
+/// `SpringGreen1` (`#00ff87`).
+pub const SPRING_GREEN1: u8 = 48;
+/// `MediumSpringGreen` (`#00ffaf`).
+pub const MEDIUM_SPRING_GREEN: u8 = 49;
+/// `Cyan2` (`#00ffd7`).
+pub const CYAN2: u8 = 50;
+/// `Cyan1` (`#00ffff`).
+pub const CYAN1: u8 = 51;
+/// `DarkRed` (`#5f0000`).
+pub const DARK_RED: u8 = 52;
+/// `DeepPink4` (`#5f005f`).
+pub const DEEP_PINK4: u8 = 53;
+/// `Purple4` (`#5f0087`).
+pub const PURPLE4: u8 = 54;
+/// `Purple4` (`#5f00af`).
+pub const PURPLE4_55: u8 = 55;
+/// `Purple3` (`#5f00d7`).
+pub const PURPLE3: u8 = 56;
+/// `BlueViolet` (`#5f00ff`).
+pub const BLUE_VIOLET: u8 = 57;
+/// `Orange4` (`#5f5f00`).
+pub const ORANGE4: u8 = 58;
+/// `Grey37` (`#5f5f5f`).
+pub const GREY37: u8 = 59;
+/// `MediumPurple4` (`#5f5f87`).
+pub const MEDIUM_PURPLE4: u8 = 60;
+/// `SlateBlue3` (`#5f5faf`).
+pub const SLATE_BLUE3: u8 = 61;
+/// `SlateBlue3` (`#5f5fd7`).
+pub const SLATE_BLUE3_62: u8 = 62;
+/// `RoyalBlue1` (`#5f5fff`).
+pub const ROYAL_BLUE1: u8 = 63;
+/// `Chartreuse4` (`#5f8700`).
+pub const CHARTREUSE4: u8 = 64;
+/// `DarkSeaGreen4` (`#5f875f`).
+pub const DARK_SEA_GREEN4: u8 = 65;
+/// `PaleTurquoise4` (`#5f8787`).
+pub const PALE_TURQUOISE4: u8 = 66;
+/// `SteelBlue` (`#5f87af`).
+pub const STEEL_BLUE: u8 = 67;
+/// `SteelBlue3` (`#5f87d7`).
+pub const STEEL_BLUE3: u8 = 68;
+/// `CornflowerBlue` (`#5f87ff`).
+pub const CORNFLOWER_BLUE: u8 = 69;
+/// `Chartreuse3` (`#5faf00`).
+pub const CHARTREUSE3: u8 = 70;
+/// `DarkSeaGreen4` (`#5faf5f`).
+pub const DARK_SEA_GREEN4_71: u8 = 71;
+/// `CadetBlue` (`#5faf87`).
+pub const CADET_BLUE: u8 = 72;
+/// `CadetBlue` (`#5fafaf`).
+pub const CADET_BLUE_73: u8 = 73;
+/// `SkyBlue3` (`#5fafd7`).
+pub const SKY_BLUE3: u8 = 74;
+/// `SteelBlue1` (`#5fafff`).
+pub const STEEL_BLUE1: u8 = 75;
+/// `Chartreuse3` (`#5fd700`).
+pub const CHARTREUSE3_76: u8 = 76;
+/// `PaleGreen3` (`#5fd75f`).
+pub const PALE_GREEN3: u8 = 77;
+/// `SeaGreen3` (`#5fd787`).
+pub const SEA_GREEN3: u8 = 78;
+/// `Aquamarine3` (`#5fd7af`).
+pub const AQUAMARINE3: u8 = 79;
+/// `MediumTurquoise` (`#5fd7d7`).
+pub const MEDIUM_TURQUOISE: u8 = 80;
+/// `SteelBlue1` (`#5fd7ff`).
+pub const STEEL_BLUE1_81: u8 = 81;
+/// `Chartreuse2` (`#5fff00`).
+pub const CHARTREUSE2: u8 = 82;
+/// `SeaGreen2` (`#5fff5f`).
+pub const SEA_GREEN2: u8 = 83;
+/// `SeaGreen1` (`#5fff87`).
+pub const SEA_GREEN1: u8 = 84;
+/// `SeaGreen1` (`#5fffaf`).
+pub const SEA_GREEN1_85: u8 = 85;
+/// `Aquamarine1` (`#5fffd7`).
+pub const AQUAMARINE1: u8 = 86;
+/// `DarkSlateGray2` (`#5fffff`).
+pub const DARK_SLATE_GRAY2: u8 = 87;
+/// `DarkRed` (`#870000`).
+pub const DARK_RED_88: u8 = 88;
+/// `DeepPink4` (`#87005f`).
+pub const DEEP_PINK4_89: u8 = 89;
+/// `DarkMagenta` (`#870087`).
+pub const DARK_MAGENTA: u8 = 90;
+/// `DarkMagenta` (`#8700af`).
+pub const DARK_MAGENTA_91: u8 = 91;
+/// `DarkViolet` (`#8700d7`).
+pub const DARK_VIOLET: u8 = 92;
+/// `Purple` (`#8700ff`).
+pub const PURPLE_93: u8 = 93;
+/// `Orange4` (`#875f00`).
+pub const ORANGE4_94: u8 = 94;
+/// `LightPink4` (`#875f5f`).
+pub const LIGHT_PINK4: u8 = 95;
+/// `Plum4` (`#875f87`).
+pub const PLUM4: u8 = 96;
+/// `MediumPurple3` (`#875faf`).
+pub const MEDIUM_PURPLE3: u8 = 97;
+/// `MediumPurple3` (`#875fd7`).
+pub const MEDIUM_PURPLE3_98: u8 = 98;
+/// `SlateBlue1` (`#875fff`).
+pub const SLATE_BLUE1: u8 = 99;
+/// `Yellow4` (`#878700`).
+pub const YELLOW4: u8 = 100;
+/// `Wheat4` (`#87875f`).
+pub const WHEAT4: u8 = 101;
+/// `Grey53` (`#878787`).
+pub const GREY53: u8 = 102;
+/// `LightSlateGrey` (`#8787af`).
+pub const LIGHT_SLATE_GREY: u8 = 103;
+/// `MediumPurple` (`#8787d7`).
+pub const MEDIUM_PURPLE: u8 = 104;
+/// `LightSlateBlue` (`#8787ff`).
+pub const LIGHT_SLATE_BLUE: u8 = 105;
+/// `Yellow4` (`#87af00`).
+pub const YELLOW4_106: u8 = 106;
+/// `DarkOliveGreen3` (`#87af5f`).
+pub const DARK_OLIVE_GREEN3: u8 = 107;
+/// `DarkSeaGreen` (`#87af87`).
+pub const DARK_SEA_GREEN: u8 = 108;
+/// `LightSkyBlue3` (`#87afaf`).
+pub const LIGHT_SKY_BLUE3: u8 = 109;
+/// `LightSkyBlue3` (`#87afd7`).
+pub const LIGHT_SKY_BLUE3_110: u8 = 110;
+/// `SkyBlue2` (`#87afff`).
+pub const SKY_BLUE2: u8 = 111;
+/// `Chartreuse2` (`#87d700`).
+pub const CHARTREUSE2_112: u8 = 112;
+/// `DarkOliveGreen3` (`#87d75f`).
+pub const DARK_OLIVE_GREEN3_113: u8 = 113;
+/// `PaleGreen3` (`#87d787`).
+pub const PALE_GREEN3_114: u8 = 114;
+/// `DarkSeaGreen3` (`#87d7af`).
+pub const DARK_SEA_GREEN3: u8 = 115;
+/// `DarkSlateGray3` (`#87d7d7`).
+pub const DARK_SLATE_GRAY3: u8 = 116;
+/// `SkyBlue1` (`#87d7ff`).
+pub const SKY_BLUE1: u8 = 117;
+/// `Chartreuse1` (`#87ff00`).
+pub const CHARTREUSE1: u8 = 118;
+/// `LightGreen` (`#87ff5f`).
+pub const LIGHT_GREEN: u8 = 119;
+/// `LightGreen` (`#87ff87`).
+pub const LIGHT_GREEN_120: u8 = 120;
+/// `PaleGreen1` (`#87ffaf`).
+pub const PALE_GREEN1: u8 = 121;
+/// `Aquamarine1` (`#87ffd7`).
+pub const AQUAMARINE1_122: u8 = 122;
+/// `DarkSlateGray1` (`#87ffff`).
+pub const DARK_SLATE_GRAY1: u8 = 123;
+/// `Red3` (`#af0000`).
+pub const RED3: u8 = 124;
+/// `DeepPink4` (`#af005f`).
+pub const DEEP_PINK4_125: u8 = 125;
+/// `MediumVioletRed` (`#af0087`).
+pub const MEDIUM_VIOLET_RED: u8 = 126;
+/// `Magenta3` (`#af00af`).
+pub const MAGENTA3: u8 = 127;
+/// `DarkViolet` (`#af00d7`).
+pub const DARK_VIOLET_128: u8 = 128;
+/// `Purple` (`#af00ff`).
+pub const PURPLE_129: u8 = 129;
+/// `DarkOrange3` (`#af5f00`).
+pub const DARK_ORANGE3: u8 = 130;
+/// `IndianRed` (`#af5f5f`).
+pub const INDIAN_RED: u8 = 131;
+/// `HotPink3` (`#af5f87`).
+pub const HOT_PINK3: u8 = 132;
+/// `MediumOrchid3` (`#af5faf`).
+pub const MEDIUM_ORCHID3: u8 = 133;
+/// `MediumOrchid` (`#af5fd7`).
+pub const MEDIUM_ORCHID: u8 = 134;
+/// `MediumPurple2` (`#af5fff`).
+pub const MEDIUM_PURPLE2: u8 = 135;
+/// `DarkGoldenrod` (`#af8700`).
+pub const DARK_GOLDENROD: u8 = 136;
+/// `LightSalmon3` (`#af875f`).
+pub const LIGHT_SALMON3: u8 = 137;
+/// `RosyBrown` (`#af8787`).
+pub const ROSY_BROWN: u8 = 138;
+/// `Grey63` (`#af87af`).
+pub const GREY63: u8 = 139;
+/// `MediumPurple2` (`#af87d7`).
+pub const MEDIUM_PURPLE2_140: u8 = 140;
+/// `MediumPurple1` (`#af87ff`).
+pub const MEDIUM_PURPLE1: u8 = 141;
+/// `Gold3` (`#afaf00`).
+pub const GOLD3: u8 = 142;
+/// `DarkKhaki` (`#afaf5f`).
+pub const DARK_KHAKI: u8 = 143;
+/// `NavajoWhite3` (`#afaf87`).
+pub const NAVAJO_WHITE3: u8 = 144;
+/// `Grey69` (`#afafaf`).
+pub const GREY69: u8 = 145;
+/// `LightSteelBlue3` (`#afafd7`).
+pub const LIGHT_STEEL_BLUE3: u8 = 146;
+/// `LightSteelBlue` (`#afafff`).
+pub const LIGHT_STEEL_BLUE: u8 = 147;
+/// `Yellow3` (`#afd700`).
+pub const YELLOW3: u8 = 148;
+/// `DarkOliveGreen3` (`#afd75f`).
+pub const DARK_OLIVE_GREEN3_149: u8 = 149;
+/// `DarkSeaGreen3` (`#afd787`).
+pub const DARK_SEA_GREEN3_150: u8 = 150;
+/// `DarkSeaGreen2` (`#afd7af`).
+pub const DARK_SEA_GREEN2: u8 = 151;
+/// `LightCyan3` (`#afd7d7`).
+pub const LIGHT_CYAN3: u8 = 152;
+/// `LightSkyBlue1` (`#afd7ff`).
+pub const LIGHT_SKY_BLUE1: u8 = 153;
+/// `GreenYellow` (`#afff00`).
+pub const GREEN_YELLOW: u8 = 154;
+/// `DarkOliveGreen2` (`#afff5f`).
+pub const DARK_OLIVE_GREEN2: u8 = 155;
+/// `PaleGreen1` (`#afff87`).
+pub const PALE_GREEN1_156: u8 = 156;
+/// `DarkSeaGreen2` (`#afffaf`).
+pub const DARK_SEA_GREEN2_157: u8 = 157;
+/// `DarkSeaGreen1` (`#afffd7`).
+pub const DARK_SEA_GREEN1: u8 = 158;
+/// `PaleTurquoise1` (`#afffff`).
+pub const PALE_TURQUOISE1: u8 = 159;
+/// `Red3` (`#d70000`).
+pub const RED3_160: u8 = 160;
+/// `DeepPink3` (`#d7005f`).
+pub const DEEP_PINK3: u8 = 161;
+/// `DeepPink3` (`#d70087`).
+pub const DEEP_PINK3_162: u8 = 162;
+/// `Magenta3` (`#d700af`).
+pub const MAGENTA3_163: u8 = 163;
+/// `Magenta3` (`#d700d7`).
+pub const MAGENTA3_164: u8 = 164;
+/// `Magenta2` (`#d700ff`).
+pub const MAGENTA2: u8 = 165;
+/// `DarkOrange3` (`#d75f00`).
+pub const DARK_ORANGE3_166: u8 = 166;
+/// `IndianRed` (`#d75f5f`).
+pub const INDIAN_RED_167: u8 = 167;
+/// `HotPink3` (`#d75f87`).
+pub const HOT_PINK3_168: u8 = 168;
+/// `HotPink2` (`#d75faf`).
+pub const HOT_PINK2: u8 = 169;
+/// `Orchid` (`#d75fd7`).
+pub const ORCHID: u8 = 170;
+/// `MediumOrchid1` (`#d75fff`).
+pub const MEDIUM_ORCHID1: u8 = 171;
+/// `Orange3` (`#d78700`).
+pub const ORANGE3: u8 = 172;
+/// `LightSalmon3` (`#d7875f`).
+pub const LIGHT_SALMON3_173: u8 = 173;
+/// `LightPink3` (`#d78787`).
+pub const LIGHT_PINK3: u8 = 174;
+/// `Pink3` (`#d787af`).
+pub const PINK3: u8 = 175;
+/// `Plum3` (`#d787d7`).
+pub const PLUM3: u8 = 176;
+/// `Violet` (`#d787ff`).
+pub const VIOLET: u8 = 177;
+/// `Gold3` (`#d7af00`).
+pub const GOLD3_178: u8 = 178;
+/// `LightGoldenrod3` (`#d7af5f`).
+pub const LIGHT_GOLDENROD3: u8 = 179;
+/// `Tan` (`#d7af87`).
+pub const TAN: u8 = 180;
+/// `MistyRose3` (`#d7afaf`).
+pub const MISTY_ROSE3: u8 = 181;
+/// `Thistle3` (`#d7afd7`).
+pub const THISTLE3: u8 = 182;
+/// `Plum2` (`#d7afff`).
+pub const PLUM2: u8 = 183;
+/// `Yellow3` (`#d7d700`).
+pub const YELLOW3_184: u8 = 184;
+/// `Khaki3` (`#d7d75f`).
+pub const KHAKI3: u8 = 185;
+/// `LightGoldenrod2` (`#d7d787`).
+pub const LIGHT_GOLDENROD2: u8 = 186;
+/// `LightYellow3` (`#d7d7af`).
+pub const LIGHT_YELLOW3: u8 = 187;
+/// `Grey84` (`#d7d7d7`).
+pub const GREY84: u8 = 188;
+/// `LightSteelBlue1` (`#d7d7ff`).
+pub const LIGHT_STEEL_BLUE1: u8 = 189;
+/// `Yellow2` (`#d7ff00`).
+pub const YELLOW2: u8 = 190;
+/// `DarkOliveGreen1` (`#d7ff5f`).
+pub const DARK_OLIVE_GREEN1: u8 = 191;
+/// `DarkOliveGreen1` (`#d7ff87`).
+pub const DARK_OLIVE_GREEN1_192: u8 = 192;
+/// `DarkSeaGreen1` (`#d7ffaf`).
+pub const DARK_SEA_GREEN1_193: u8 = 193;
+/// `Honeydew2` (`#d7ffd7`).
+pub const HONEYDEW2: u8 = 194;
+/// `LightCyan1` (`#d7ffff`).
+pub const LIGHT_CYAN1: u8 = 195;
+/// `Red1` (`#ff0000`).
+pub const RED1: u8 = 196;
+/// `DeepPink2` (`#ff005f`).
+pub const DEEP_PINK2: u8 = 197;
+/// `DeepPink1` (`#ff0087`).
+pub const DEEP_PINK1: u8 = 198;
+/// `DeepPink1` (`#ff00af`).
+pub const DEEP_PINK1_199: u8 = 199;
+/// `Magenta2` (`#ff00d7`).
+pub const MAGENTA2_200: u8 = 200;
+/// `Magenta1` (`#ff00ff`).
+pub const MAGENTA1: u8 = 201;
+/// `OrangeRed1` (`#ff5f00`).
+pub const ORANGE_RED1: u8 = 202;
+/// `IndianRed1` (`#ff5f5f`).
+pub const INDIAN_RED1: u8 = 203;
+/// `IndianRed1` (`#ff5f87`).
+pub const INDIAN_RED1_204: u8 = 204;
+/// `HotPink` (`#ff5faf`).
+pub const HOT_PINK: u8 = 205;
+/// `HotPink` (`#ff5fd7`).
+pub const HOT_PINK_206: u8 = 206;
+/// `MediumOrchid1` (`#ff5fff`).
+pub const MEDIUM_ORCHID1_207: u8 = 207;
+/// `DarkOrange` (`#ff8700`).
+pub const DARK_ORANGE: u8 = 208;
+/// `Salmon1` (`#ff875f`).
+pub const SALMON1: u8 = 209;
+/// `LightCoral` (`#ff8787`).
+pub const LIGHT_CORAL: u8 = 210;
+/// `PaleVioletRed1` (`#ff87af`).
+pub const PALE_VIOLET_RED1: u8 = 211;
+/// `Orchid2` (`#ff87d7`).
+pub const ORCHID2: u8 = 212;
+/// `Orchid1` (`#ff87ff`).
+pub const ORCHID1: u8 = 213;
+/// `Orange1` (`#ffaf00`).
+pub const ORANGE1: u8 = 214;
+/// `SandyBrown` (`#ffaf5f`).
+pub const SANDY_BROWN: u8 = 215;
+/// `LightSalmon1` (`#ffaf87`).
+pub const LIGHT_SALMON1: u8 = 216;
+/// `LightPink1` (`#ffafaf`).
+pub const LIGHT_PINK1: u8 = 217;
+/// `Pink1` (`#ffafd7`).
+pub const PINK1: u8 = 218;
+/// `Plum1` (`#ffafff`).
+pub const PLUM1: u8 = 219;
+/// `Gold1` (`#ffd700`).
+pub const GOLD1: u8 = 220;
+/// `LightGoldenrod2` (`#ffd75f`).
+pub const LIGHT_GOLDENROD2_221: u8 = 221;
+/// `LightGoldenrod2` (`#ffd787`).
+pub const LIGHT_GOLDENROD2_222: u8 = 222;
+/// `NavajoWhite1` (`#ffd7af`).
+pub const NAVAJO_WHITE1: u8 = 223;
+/// `MistyRose1` (`#ffd7d7`).
+pub const MISTY_ROSE1: u8 = 224;
+/// `Thistle1` (`#ffd7ff`).
+pub const THISTLE1: u8 = 225;
+/// `Yellow1` (`#ffff00`).
+pub const YELLOW1: u8 = 226;
+/// `LightGoldenrod1` (`#ffff5f`).
+pub const LIGHT_GOLDENROD1: u8 = 227;
+/// `Khaki1` (`#ffff87`).
+pub const KHAKI1: u8 = 228;
+/// `Wheat1` (`#ffffaf`).
+pub const WHEAT1: u8 = 229;
+/// `Cornsilk1` (`#ffffd7`).
+pub const CORNSILK1: u8 = 230;
+/// `Grey100` (`#ffffff`).
+pub const GREY100: u8 = 231;
+/// `Grey3` (`#080808`).
+pub const GREY3: u8 = 232;
+/// `Grey7` (`#121212`).
+pub const GREY7: u8 = 233;
+/// `Grey11` (`#1c1c1c`).
+pub const GREY11: u8 = 234;
+/// `Grey15` (`#262626`).
+pub const GREY15: u8 = 235;
+/// `Grey19` (`#303030`).
+pub const GREY19: u8 = 236;
+/// `Grey23` (`#3a3a3a`).
+pub const GREY23: u8 = 237;
+/// `Grey27` (`#444444`).
+pub const GREY27: u8 = 238;
+/// `Grey30` (`#4e4e4e`).
+pub const GREY30: u8 = 239;
+/// `Grey35` (`#585858`).
+pub const GREY35: u8 = 240;
+/// `Grey39` (`#626262`).
+pub const GREY39: u8 = 241;
+/// `Grey42` (`#6c6c6c`).
+pub const GREY42: u8 = 242;
+/// `Grey46` (`#767676`).
+pub const GREY46: u8 = 243;
+/// `Grey50` (`#808080`).
+pub const GREY50: u8 = 244;
+/// `Grey54` (`#8a8a8a`).
+pub const GREY54: u8 = 245;
+/// `Grey58` (`#949494`).
+pub const GREY58: u8 = 246;
+/// `Grey62` (`#9e9e9e`).
+pub const GREY62: u8 = 247;
+/// `Grey66` (`#a8a8a8`).
+pub const GREY66: u8 = 248;
+/// `Grey70` (`#b2b2b2`).
+pub const GREY70: u8 = 249;
+/// `Grey74` (`#bcbcbc`).
+pub const GREY74: u8 = 250;
+/// `Grey78` (`#c6c6c6`).
+pub const GREY78: u8 = 251;
+/// `Grey82` (`#d0d0d0`).
+pub const GREY82: u8 = 252;
+/// `Grey85` (`#dadada`).
+pub const GREY85: u8 = 253;
+/// `Grey89` (`#e4e4e4`).
+pub const GREY89: u8 = 254;
+/// `Grey93` (`#eeeeee`).
+pub const GREY93: u8 = 255;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn lookups() {
+        assert_eq!(name(BLACK), "Black");
+        assert_eq!(name(BLUE3_20), "Blue3");
+        assert_eq!(name(GREY93), "Grey93");
+        assert_eq!(from_name("blue3"), Some(BLUE3));
+        assert_eq!(from_name("not a color"), None);
+        assert_eq!(from_index(RED1), Color::from_rgb(255, 0, 0));
+        assert_eq!(from_index(GREY100), Color::from_rgb(255, 255, 255));
+    }
+
+    #[test]
+    fn names_match_colors() {
+        assert_eq!(from_index(DEEP_SKY_BLUE4), Color::from_rgb(0, 0x5f, 0x5f));
+        assert_eq!(from_index(GREY3), Color::from_rgb(8, 8, 8));
+        for idx in 0..=u8::MAX {
+            assert!(!name(idx).is_empty());
+        }
+    }
+}
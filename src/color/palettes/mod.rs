@@ -0,0 +1,10 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Ready made color palettes. Each palette module exposes its colors as constants, as an `ALL`
+//! array of `(name, color)` pairs, and as a [`Palette`](crate::Palette) through `palette()`.
+
+pub mod tailwind;
@@ -0,0 +1,781 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! The default color palette of [Tailwind CSS](https://tailwindcss.com/docs/customizing-colors)
+//! (v3.4), with every color family from `50` to `950`.
+//!
+//! ## Example
+//! ```
+//! # use ansirs::{palettes::tailwind, Ansi, Color};
+//! let style = Ansi::from_fg(tailwind::SKY_500).bg(tailwind::SLATE_900);
+//! assert_eq!(tailwind::SKY_500, Color::from_rgb(0x0e, 0xa5, 0xe9));
+//!
+//! let palette = tailwind::palette();
+//! assert_eq!(palette.get("rose-600"), Some(tailwind::ROSE_600));
+//! assert_eq!(tailwind::ALL.len(), 242);
+//! ```
+
+use crate::{Color, Palette};
+
+/// Every color of the palette as `(name, color)` pairs, e.g. `("slate-50", SLATE_50)`, grouped
+/// by color family and ordered from lightest to darkest.
+pub const ALL: [(&str, Color); 242] = [
+    ("slate-50", SLATE_50),
+    ("slate-100", SLATE_100),
+    ("slate-200", SLATE_200),
+    ("slate-300", SLATE_300),
+    ("slate-400", SLATE_400),
+    ("slate-500", SLATE_500),
+    ("slate-600", SLATE_600),
+    ("slate-700", SLATE_700),
+    ("slate-800", SLATE_800),
+    ("slate-900", SLATE_900),
+    ("slate-950", SLATE_950),
+    ("gray-50", GRAY_50),
+    ("gray-100", GRAY_100),
+    ("gray-200", GRAY_200),
+    ("gray-300", GRAY_300),
+    ("gray-400", GRAY_400),
+    ("gray-500", GRAY_500),
+    ("gray-600", GRAY_600),
+    ("gray-700", GRAY_700),
+    ("gray-800", GRAY_800),
+    ("gray-900", GRAY_900),
+    ("gray-950", GRAY_950),
+    ("zinc-50", ZINC_50),
+    ("zinc-100", ZINC_100),
+    ("zinc-200", ZINC_200),
+    ("zinc-300", ZINC_300),
+    ("zinc-400", ZINC_400),
+    ("zinc-500", ZINC_500),
+    ("zinc-600", ZINC_600),
+    ("zinc-700", ZINC_700),
+    ("zinc-800", ZINC_800),
+    ("zinc-900", ZINC_900),
+    ("zinc-950", ZINC_950),
+    ("neutral-50", NEUTRAL_50),
+    ("neutral-100", NEUTRAL_100),
+    ("neutral-200", NEUTRAL_200),
+    ("neutral-300", NEUTRAL_300),
+    ("neutral-400", NEUTRAL_400),
+    ("neutral-500", NEUTRAL_500),
+    ("neutral-600", NEUTRAL_600),
+    ("neutral-700", NEUTRAL_700),
+    ("neutral-800", NEUTRAL_800),
+    ("neutral-900", NEUTRAL_900),
+    ("neutral-950", NEUTRAL_950),
+    ("stone-50", STONE_50),
+    ("stone-100", STONE_100),
+    ("stone-200", STONE_200),
+    ("stone-300", STONE_300),
+    ("stone-400", STONE_400),
+    ("stone-500", STONE_500),
+    ("stone-600", STONE_600),
+    ("stone-700", STONE_700),
+    ("stone-800", STONE_800),
+    ("stone-900", STONE_900),
+    ("stone-950", STONE_950),
+    ("red-50", RED_50),
+    ("red-100", RED_100),
+    ("red-200", RED_200),
+    ("red-300", RED_300),
+    ("red-400", RED_400),
+    ("red-500", RED_500),
+    ("red-600", RED_600),
+    ("red-700", RED_700),
+    ("red-800", RED_800),
+    ("red-900", RED_900),
+    ("red-950", RED_950),
+    ("orange-50", ORANGE_50),
+    ("orange-100", ORANGE_100),
+    ("orange-200", ORANGE_200),
+    ("orange-300", ORANGE_300),
+    ("orange-400", ORANGE_400),
+    ("orange-500", ORANGE_500),
+    ("orange-600", ORANGE_600),
+    ("orange-700", ORANGE_700),
+    ("orange-800", ORANGE_800),
+    ("orange-900", ORANGE_900),
+    ("orange-950", ORANGE_950),
+    ("amber-50", AMBER_50),
+    ("amber-100", AMBER_100),
+    ("amber-200", AMBER_200),
+    ("amber-300", AMBER_300),
+    ("amber-400", AMBER_400),
+    ("amber-500", AMBER_500),
+    ("amber-600", AMBER_600),
+    ("amber-700", AMBER_700),
+    ("amber-800", AMBER_800),
+    ("amber-900", AMBER_900),
+    ("amber-950", AMBER_950),
+    ("yellow-50", YELLOW_50),
+    ("yellow-100", YELLOW_100),
+    ("yellow-200", YELLOW_200),
+    ("yellow-300", YELLOW_300),
+    ("yellow-400", YELLOW_400),
+    ("yellow-500", YELLOW_500),
+    ("yellow-600", YELLOW_600),
+    ("yellow-700", YELLOW_700),
+    ("yellow-800", YELLOW_800),
+    ("yellow-900", YELLOW_900),
+    ("yellow-950", YELLOW_950),
+    ("lime-50", LIME_50),
+    ("lime-100", LIME_100),
+    ("lime-200", LIME_200),
+    ("lime-300", LIME_300),
+    ("lime-400", LIME_400),
+    ("lime-500", LIME_500),
+    ("lime-600", LIME_600),
+    ("lime-700", LIME_700),
+    ("lime-800", LIME_800),
+    ("lime-900", LIME_900),
+    ("lime-950", LIME_950),
+    ("green-50", GREEN_50),
+    ("green-100", GREEN_100),
+    ("green-200", GREEN_200),
+    ("green-300", GREEN_300),
+    ("green-400", GREEN_400),
+    ("green-500", GREEN_500),
+    ("green-600", GREEN_600),
+    ("green-700", GREEN_700),
+    ("green-800", GREEN_800),
+    ("green-900", GREEN_900),
+    ("green-950", GREEN_950),
+    ("emerald-50", EMERALD_50),
+    ("emerald-100", EMERALD_100),
+    ("emerald-200", EMERALD_200),
+    ("emerald-300", EMERALD_300),
+    ("emerald-400", EMERALD_400),
+    ("emerald-500", EMERALD_500),
+    ("emerald-600", EMERALD_600),
+    ("emerald-700", EMERALD_700),
+    ("emerald-800", EMERALD_800),
+    ("emerald-900", EMERALD_900),
+    ("emerald-950", EMERALD_950),
+    ("teal-50", TEAL_50),
+    ("teal-100", TEAL_100),
+    ("teal-200", TEAL_200),
+    ("teal-300", TEAL_300),
+    ("teal-400", TEAL_400),
+    ("teal-500", TEAL_500),
+    ("teal-600", TEAL_600),
+    ("teal-700", TEAL_700),
+    ("teal-800", TEAL_800),
+    ("teal-900", TEAL_900),
+    ("teal-950", TEAL_950),
+    ("cyan-50", CYAN_50),
+    ("cyan-100", CYAN_100),
+    ("cyan-200", CYAN_200),
+    ("cyan-300", CYAN_300),
+    ("cyan-400", CYAN_400),
+    ("cyan-500", CYAN_500),
+    ("cyan-600", CYAN_600),
+    ("cyan-700", CYAN_700),
+    ("cyan-800", CYAN_800),
+    ("cyan-900", CYAN_900),
+    ("cyan-950", CYAN_950),
+    ("sky-50", SKY_50),
+    ("sky-100", SKY_100),
+    ("sky-200", SKY_200),
+    ("sky-300", SKY_300),
+    ("sky-400", SKY_400),
+    ("sky-500", SKY_500),
+    ("sky-600", SKY_600),
+    ("sky-700", SKY_700),
+    ("sky-800", SKY_800),
+    ("sky-900", SKY_900),
+    ("sky-950", SKY_950),
+    ("blue-50", BLUE_50),
+    ("blue-100", BLUE_100),
+    ("blue-200", BLUE_200),
+    ("blue-300", BLUE_300),
+    ("blue-400", BLUE_400),
+    ("blue-500", BLUE_500),
+    ("blue-600", BLUE_600),
+    ("blue-700", BLUE_700),
+    ("blue-800", BLUE_800),
+    ("blue-900", BLUE_900),
+    ("blue-950", BLUE_950),
+    ("indigo-50", INDIGO_50),
+    ("indigo-100", INDIGO_100),
+    ("indigo-200", INDIGO_200),
+    ("indigo-300", INDIGO_300),
+    ("indigo-400", INDIGO_400),
+    ("indigo-500", INDIGO_500),
+    ("indigo-600", INDIGO_600),
+    ("indigo-700", INDIGO_700),
+    ("indigo-800", INDIGO_800),
+    ("indigo-900", INDIGO_900),
+    ("indigo-950", INDIGO_950),
+    ("violet-50", VIOLET_50),
+    ("violet-100", VIOLET_100),
+    ("violet-200", VIOLET_200),
+    ("violet-300", VIOLET_300),
+    ("violet-400", VIOLET_400),
+    ("violet-500", VIOLET_500),
+    ("violet-600", VIOLET_600),
+    ("violet-700", VIOLET_700),
+    ("violet-800", VIOLET_800),
+    ("violet-900", VIOLET_900),
+    ("violet-950", VIOLET_950),
+    ("purple-50", PURPLE_50),
+    ("purple-100", PURPLE_100),
+    ("purple-200", PURPLE_200),
+    ("purple-300", PURPLE_300),
+    ("purple-400", PURPLE_400),
+    ("purple-500", PURPLE_500),
+    ("purple-600", PURPLE_600),
+    ("purple-700", PURPLE_700),
+    ("purple-800", PURPLE_800),
+    ("purple-900", PURPLE_900),
+    ("purple-950", PURPLE_950),
+    ("fuchsia-50", FUCHSIA_50),
+    ("fuchsia-100", FUCHSIA_100),
+    ("fuchsia-200", FUCHSIA_200),
+    ("fuchsia-300", FUCHSIA_300),
+    ("fuchsia-400", FUCHSIA_400),
+    ("fuchsia-500", FUCHSIA_500),
+    ("fuchsia-600", FUCHSIA_600),
+    ("fuchsia-700", FUCHSIA_700),
+    ("fuchsia-800", FUCHSIA_800),
+    ("fuchsia-900", FUCHSIA_900),
+    ("fuchsia-950", FUCHSIA_950),
+    ("pink-50", PINK_50),
+    ("pink-100", PINK_100),
+    ("pink-200", PINK_200),
+    ("pink-300", PINK_300),
+    ("pink-400", PINK_400),
+    ("pink-500", PINK_500),
+    ("pink-600", PINK_600),
+    ("pink-700", PINK_700),
+    ("pink-800", PINK_800),
+    ("pink-900", PINK_900),
+    ("pink-950", PINK_950),
+    ("rose-50", ROSE_50),
+    ("rose-100", ROSE_100),
+    ("rose-200", ROSE_200),
+    ("rose-300", ROSE_300),
+    ("rose-400", ROSE_400),
+    ("rose-500", ROSE_500),
+    ("rose-600", ROSE_600),
+    ("rose-700", ROSE_700),
+    ("rose-800", ROSE_800),
+    ("rose-900", ROSE_900),
+    ("rose-950", ROSE_950),
+];
+
+/// Creates a [`Palette`] named `"Tailwind CSS"` containing every color in [`ALL`].
+#[must_use]
+pub fn palette() -> Palette {
+    let mut palette = ALL.into_iter().collect::<Palette>();
+    palette.set_name("Tailwind CSS");
+    palette
+}
+
+/// `slate-50` (`#f8fafc`).
+pub const SLATE_50: Color = Color::from_rgb(0xf8, 0xfa, 0xfc);
+/// `slate-100` (`#f1f5f9`).
+pub const SLATE_100: Color = Color::from_rgb(0xf1, 0xf5, 0xf9);
+/// `slate-200` (`#e2e8f0`).
+pub const SLATE_200: Color = Color::from_rgb(0xe2, 0xe8, 0xf0);
+/// `slate-300` (`#cbd5e1`).
+pub const SLATE_300: Color = Color::from_rgb(0xcb, 0xd5, 0xe1);
+/// `slate-400` (`#94a3b8`).
+pub const SLATE_400: Color = Color::from_rgb(0x94, 0xa3, 0xb8);
+/// `slate-500` (`#64748b`).
+pub const SLATE_500: Color = Color::from_rgb(0x64, 0x74, 0x8b);
+/// `slate-600` (`#475569`).
+pub const SLATE_600: Color = Color::from_rgb(0x47, 0x55, 0x69);
+/// `slate-700` (`#334155`).
+pub const SLATE_700: Color = Color::from_rgb(0x33, 0x41, 0x55);
+/// `slate-800` (`#1e293b`).
+pub const SLATE_800: Color = Color::from_rgb(0x1e, 0x29, 0x3b);
+/// `slate-900` (`#0f172a`).
+pub const SLATE_900: Color = Color::from_rgb(0x0f, 0x17, 0x2a);
+/// `slate-950` (`#020617`).
+pub const SLATE_950: Color = Color::from_rgb(0x02, 0x06, 0x17);
+/// `gray-50` (`#f9fafb`).
+pub const GRAY_50: Color = Color::from_rgb(0xf9, 0xfa, 0xfb);
+/// `gray-100` (`#f3f4f6`).
+pub const GRAY_100: Color = Color::from_rgb(0xf3, 0xf4, 0xf6);
+/// `gray-200` (`#e5e7eb`).
+pub const GRAY_200: Color = Color::from_rgb(0xe5, 0xe7, 0xeb);
+/// `gray-300` (`#d1d5db`).
+pub const GRAY_300: Color = Color::from_rgb(0xd1, 0xd5, 0xdb);
+/// `gray-400` (`#9ca3af`).
+pub const GRAY_400: Color = Color::from_rgb(0x9c, 0xa3, 0xaf);
+/// `gray-500` (`#6b7280`).
+pub const GRAY_500: Color = Color::from_rgb(0x6b, 0x72, 0x80);
+/// `gray-600` (`#4b5563`).
+pub const GRAY_600: Color = Color::from_rgb(0x4b, 0x55, 0x63);
+/// `gray-700` (`#374151`).
+pub const GRAY_700: Color = Color::from_rgb(0x37, 0x41, 0x51);
+/// `gray-800` (`#1f2937`).
+pub const GRAY_800: Color = Color::from_rgb(0x1f, 0x29, 0x37);
+/// `gray-900` (`#111827`).
+pub const GRAY_900: Color = Color::from_rgb(0x11, 0x18, 0x27);
+/// `gray-950` (`#030712`).
+pub const GRAY_950: Color = Color::from_rgb(0x03, 0x07, 0x12);
+/// `zinc-50` (`#fafafa`).
+pub const ZINC_50: Color = Color::from_rgb(0xfa, 0xfa, 0xfa);
+/// `zinc-100` (`#f4f4f5`).
+pub const ZINC_100: Color = Color::from_rgb(0xf4, 0xf4, 0xf5);
+/// `zinc-200` (`#e4e4e7`).
+pub const ZINC_200: Color = Color::from_rgb(0xe4, 0xe4, 0xe7);
+/// `zinc-300` (`#d4d4d8`).
+pub const ZINC_300: Color = Color::from_rgb(0xd4, 0xd4, 0xd8);
+/// `zinc-400` (`#a1a1aa`).
+pub const ZINC_400: Color = Color::from_rgb(0xa1, 0xa1, 0xaa);
+/// `zinc-500` (`#71717a`).
+pub const ZINC_500: Color = Color::from_rgb(0x71, 0x71, 0x7a);
+/// `zinc-600` (`#52525b`).
+pub const ZINC_600: Color = Color::from_rgb(0x52, 0x52, 0x5b);
+/// `zinc-700` (`#3f3f46`).
+pub const ZINC_700: Color = Color::from_rgb(0x3f, 0x3f, 0x46);
+/// `zinc-800` (`#27272a`).
+pub const ZINC_800: Color = Color::from_rgb(0x27, 0x27, 0x2a);
+/// `zinc-900` (`#18181b`).
+pub const ZINC_900: Color = Color::from_rgb(0x18, 0x18, 0x1b);
+/// `zinc-950` (`#09090b`).
+pub const ZINC_950: Color = Color::from_rgb(0x09, 0x09, 0x0b);
+/// `neutral-50` (`#fafafa`).
+pub const NEUTRAL_50: Color = Color::from_rgb(0xfa, 0xfa, 0xfa);
+/// `neutral-100` (`#f5f5f5`).
+pub const NEUTRAL_100: Color = Color::from_rgb(0xf5, 0xf5, 0xf5);
+/// `neutral-200` (`#e5e5e5`).
+pub const NEUTRAL_200: Color = Color::from_rgb(0xe5, 0xe5, 0xe5);
+/// `neutral-300` (`#d4d4d4`).
+pub const NEUTRAL_300: Color = Color::from_rgb(0xd4, 0xd4, 0xd4);
+/// `neutral-400` (`#a3a3a3`).
+pub const NEUTRAL_400: Color = Color::from_rgb(0xa3, 0xa3, 0xa3);
+/// `neutral-500` (`#737373`).
+pub const NEUTRAL_500: Color = Color::from_rgb(0x73, 0x73, 0x73);
+/// `neutral-600` (`#525252`).
+pub const NEUTRAL_600: Color = Color::from_rgb(0x52, 0x52, 0x52);
+/// `neutral-700` (`#404040`).
+pub const NEUTRAL_700: Color = Color::from_rgb(0x40, 0x40, 0x40);
+/// `neutral-800` (`#262626`).
+pub const NEUTRAL_800: Color = Color::from_rgb(0x26, 0x26, 0x26);
+/// `neutral-900` (`#171717`).
+pub const NEUTRAL_900: Color = Color::from_rgb(0x17, 0x17, 0x17);
+/// `neutral-950` (`#0a0a0a`).
+pub const NEUTRAL_950: Color = Color::from_rgb(0x0a, 0x0a, 0x0a);
+/// `stone-50` (`#fafaf9`).
+pub const STONE_50: Color = Color::from_rgb(0xfa, 0xfa, 0xf9);
+/// `stone-100` (`#f5f5f4`).
+pub const STONE_100: Color = Color::from_rgb(0xf5, 0xf5, 0xf4);
+/// `stone-200` (`#e7e5e4`).
+pub const STONE_200: Color = Color::from_rgb(0xe7, 0xe5, 0xe4);
+/// `stone-300` (`#d6d3d1`).
+pub const STONE_300: Color = Color::from_rgb(0xd6, 0xd3, 0xd1);
+/// `stone-400` (`#a8a29e`).
+pub const STONE_400: Color = Color::from_rgb(0xa8, 0xa2, 0x9e);
+/// `stone-500` (`#78716c`).
+pub const STONE_500: Color = Color::from_rgb(0x78, 0x71, 0x6c);
+/// `stone-600` (`#57534e`).
+pub const STONE_600: Color = Color::from_rgb(0x57, 0x53, 0x4e);
+/// `stone-700` (`#44403c`).
+pub const STONE_700: Color = Color::from_rgb(0x44, 0x40, 0x3c);
+/// `stone-800` (`#292524`).
+pub const STONE_800: Color = Color::from_rgb(0x29, 0x25, 0x24);
+/// `stone-900` (`#1c1917`).
+pub const STONE_900: Color = Color::from_rgb(0x1c, 0x19, 0x17);
+/// `stone-950` (`#0c0a09`).
+pub const STONE_950: Color = Color::from_rgb(0x0c, 0x0a, 0x09);
+/// `red-50` (`#fef2f2`).
+pub const RED_50: Color = Color::from_rgb(0xfe, 0xf2, 0xf2);
+/// `red-100` (`#fee2e2`).
+pub const RED_100: Color = Color::from_rgb(0xfe, 0xe2, 0xe2);
+/// `red-200` (`#fecaca`).
+pub const RED_200: Color = Color::from_rgb(0xfe, 0xca, 0xca);
+/// `red-300` (`#fca5a5`).
+pub const RED_300: Color = Color::from_rgb(0xfc, 0xa5, 0xa5);
+/// `red-400` (`#f87171`).
+pub const RED_400: Color = Color::from_rgb(0xf8, 0x71, 0x71);
+/// `red-500` (`#ef4444`).
+pub const RED_500: Color = Color::from_rgb(0xef, 0x44, 0x44);
+/// `red-600` (`#dc2626`).
+pub const RED_600: Color = Color::from_rgb(0xdc, 0x26, 0x26);
+/// `red-700` (`#b91c1c`).
+pub const RED_700: Color = Color::from_rgb(0xb9, 0x1c, 0x1c);
+/// `red-800` (`#991b1b`).
+pub const RED_800: Color = Color::from_rgb(0x99, 0x1b, 0x1b);
+/// `red-900` (`#7f1d1d`).
+pub const RED_900: Color = Color::from_rgb(0x7f, 0x1d, 0x1d);
+/// `red-950` (`#450a0a`).
+pub const RED_950: Color = Color::from_rgb(0x45, 0x0a, 0x0a);
+/// `orange-50` (`#fff7ed`).
+pub const ORANGE_50: Color = Color::from_rgb(0xff, 0xf7, 0xed);
+/// `orange-100` (`#ffedd5`).
+pub const ORANGE_100: Color = Color::from_rgb(0xff, 0xed, 0xd5);
+/// `orange-200` (`#fed7aa`).
+pub const ORANGE_200: Color = Color::from_rgb(0xfe, 0xd7, 0xaa);
+/// `orange-300` (`#fdba74`).
+pub const ORANGE_300: Color = Color::from_rgb(0xfd, 0xba, 0x74);
+/// `orange-400` (`#fb923c`).
+pub const ORANGE_400: Color = Color::from_rgb(0xfb, 0x92, 0x3c);
+/// `orange-500` (`#f97316`).
+pub const ORANGE_500: Color = Color::from_rgb(0xf9, 0x73, 0x16);
+/// `orange-600` (`#ea580c`).
+pub const ORANGE_600: Color = Color::from_rgb(0xea, 0x58, 0x0c);
+/// `orange-700` (`#c2410c`).
+pub const ORANGE_700: Color = Color::from_rgb(0xc2, 0x41, 0x0c);
+/// `orange-800` (`#9a3412`).
+pub const ORANGE_800: Color = Color::from_rgb(0x9a, 0x34, 0x12);
+/// `orange-900` (`#7c2d12`).
+pub const ORANGE_900: Color = Color::from_rgb(0x7c, 0x2d, 0x12);
+/// `orange-950` (`#431407`).
+pub const ORANGE_950: Color = Color::from_rgb(0x43, 0x14, 0x07);
+/// `amber-50` (`#fffbeb`).
+pub const AMBER_50: Color = Color::from_rgb(0xff, 0xfb, 0xeb);
+/// `amber-100` (`#fef3c7`).
+pub const AMBER_100: Color = Color::from_rgb(0xfe, 0xf3, 0xc7);
+/// `amber-200` (`#fde68a`).
+pub const AMBER_200: Color = Color::from_rgb(0xfd, 0xe6, 0x8a);
+/// `amber-300` (`#fcd34d`).
+pub const AMBER_300: Color = Color::from_rgb(0xfc, 0xd3, 0x4d);
+/// `amber-400` (`#fbbf24`).
+pub const AMBER_400: Color = Color::from_rgb(0xfb, 0xbf, 0x24);
+/// `amber-500` (`#f59e0b`).
+pub const AMBER_500: Color = Color::from_rgb(0xf5, 0x9e, 0x0b);
+/// `amber-600` (`#d97706`).
+pub const AMBER_600: Color = Color::from_rgb(0xd9, 0x77, 0x06);
+/// `amber-700` (`#b45309`).
+pub const AMBER_700: Color = Color::from_rgb(0xb4, 0x53, 0x09);
+/// `amber-800` (`#92400e`).
+pub const AMBER_800: Color = Color::from_rgb(0x92, 0x40, 0x0e);
+/// `amber-900` (`#78350f`).
+pub const AMBER_900: Color = Color::from_rgb(0x78, 0x35, 0x0f);
+/// `amber-950` (`#451a03`).
+pub const AMBER_950: Color = Color::from_rgb(0x45, 0x1a, 0x03);
+/// `yellow-50` (`#fefce8`).
+pub const YELLOW_50: Color = Color::from_rgb(0xfe, 0xfc, 0xe8);
+/// `yellow-100` (`#fef9c3`).
+pub const YELLOW_100: Color = Color::from_rgb(0xfe, 0xf9, 0xc3);
+/// `yellow-200` (`#fef08a`).
+pub const YELLOW_200: Color = Color::from_rgb(0xfe, 0xf0, 0x8a);
+/// `yellow-300` (`#fde047`).
+pub const YELLOW_300: Color = Color::from_rgb(0xfd, 0xe0, 0x47);
+/// `yellow-400` (`#facc15`).
+pub const YELLOW_400: Color = Color::from_rgb(0xfa, 0xcc, 0x15);
+/// `yellow-500` (`#eab308`).
+pub const YELLOW_500: Color = Color::from_rgb(0xea, 0xb3, 0x08);
+/// `yellow-600` (`#ca8a04`).
+pub const YELLOW_600: Color = Color::from_rgb(0xca, 0x8a, 0x04);
+/// `yellow-700` (`#a16207`).
+pub const YELLOW_700: Color = Color::from_rgb(0xa1, 0x62, 0x07);
+/// `yellow-800` (`#854d0e`).
+pub const YELLOW_800: Color = Color::from_rgb(0x85, 0x4d, 0x0e);
+/// `yellow-900` (`#713f12`).
+pub const YELLOW_900: Color = Color::from_rgb(0x71, 0x3f, 0x12);
+/// `yellow-950` (`#422006`).
+pub const YELLOW_950: Color = Color::from_rgb(0x42, 0x20, 0x06);
+/// `lime-50` (`#f7fee7`).
+pub const LIME_50: Color = Color::from_rgb(0xf7, 0xfe, 0xe7);
+/// `lime-100` (`#ecfccb`).
+pub const LIME_100: Color = Color::from_rgb(0xec, 0xfc, 0xcb);
+/// `lime-200` (`#d9f99d`).
+pub const LIME_200: Color = Color::from_rgb(0xd9, 0xf9, 0x9d);
+/// `lime-300` (`#bef264`).
+pub const LIME_300: Color = Color::from_rgb(0xbe, 0xf2, 0x64);
+/// `lime-400` (`#a3e635`).
+pub const LIME_400: Color = Color::from_rgb(0xa3, 0xe6, 0x35);
+/// `lime-500` (`#84cc16`).
+pub const LIME_500: Color = Color::from_rgb(0x84, 0xcc, 0x16);
+/// `lime-600` (`#65a30d`).
+pub const LIME_600: Color = Color::from_rgb(0x65, 0xa3, 0x0d);
+/// `lime-700` (`#4d7c0f`).
+pub const LIME_700: Color = Color::from_rgb(0x4d, 0x7c, 0x0f);
+/// `lime-800` (`#3f6212`).
+pub const LIME_800: Color = Color::from_rgb(0x3f, 0x62, 0x12);
+/// `lime-900` (`#365314`).
+pub const LIME_900: Color = Color::from_rgb(0x36, 0x53, 0x14);
+/// `lime-950` (`#1a2e05`).
+pub const LIME_950: Color = Color::from_rgb(0x1a, 0x2e, 0x05);
+/// `green-50` (`#f0fdf4`).
+pub const GREEN_50: Color = Color::from_rgb(0xf0, 0xfd, 0xf4);
+/// `green-100` (`#dcfce7`).
+pub const GREEN_100: Color = Color::from_rgb(0xdc, 0xfc, 0xe7);
+/// `green-200` (`#bbf7d0`).
+pub const GREEN_200: Color = Color::from_rgb(0xbb, 0xf7, 0xd0);
+/// `green-300` (`#86efac`).
+pub const GREEN_300: Color = Color::from_rgb(0x86, 0xef, 0xac);
+/// `green-400` (`#4ade80`).
+pub const GREEN_400: Color = Color::from_rgb(0x4a, 0xde, 0x80);
+/// `green-500` (`#22c55e`).
+pub const GREEN_500: Color = Color::from_rgb(0x22, 0xc5, 0x5e);
+/// `green-600` (`#16a34a`).
+pub const GREEN_600: Color = Color::from_rgb(0x16, 0xa3, 0x4a);
+/// `green-700` (`#15803d`).
+pub const GREEN_700: Color = Color::from_rgb(0x15, 0x80, 0x3d);
+/// `green-800` (`#166534`).
+pub const GREEN_800: Color = Color::from_rgb(0x16, 0x65, 0x34);
+/// `green-900` (`#14532d`).
+pub const GREEN_900: Color = Color::from_rgb(0x14, 0x53, 0x2d);
+/// `green-950` (`#052e16`).
+pub const GREEN_950: Color = Color::from_rgb(0x05, 0x2e, 0x16);
+/// `emerald-50` (`#ecfdf5`).
+pub const EMERALD_50: Color = Color::from_rgb(0xec, 0xfd, 0xf5);
+/// `emerald-100` (`#d1fae5`).
+pub const EMERALD_100: Color = Color::from_rgb(0xd1, 0xfa, 0xe5);
+/// `emerald-200` (`#a7f3d0`).
+pub const EMERALD_200: Color = Color::from_rgb(0xa7, 0xf3, 0xd0);
+/// `emerald-300` (`#6ee7b7`).
+pub const EMERALD_300: Color = Color::from_rgb(0x6e, 0xe7, 0xb7);
+/// `emerald-400` (`#34d399`).
+pub const EMERALD_400: Color = Color::from_rgb(0x34, 0xd3, 0x99);
+/// `emerald-500` (`#10b981`).
+pub const EMERALD_500: Color = Color::from_rgb(0x10, 0xb9, 0x81);
+/// `emerald-600` (`#059669`).
+pub const EMERALD_600: Color = Color::from_rgb(0x05, 0x96, 0x69);
+/// `emerald-700` (`#047857`).
+pub const EMERALD_700: Color = Color::from_rgb(0x04, 0x78, 0x57);
+/// `emerald-800` (`#065f46`).
+pub const EMERALD_800: Color = Color::from_rgb(0x06, 0x5f, 0x46);
+/// `emerald-900` (`#064e3b`).
+pub const EMERALD_900: Color = Color::from_rgb(0x06, 0x4e, 0x3b);
+/// `emerald-950` (`#022c22`).
+pub const EMERALD_950: Color = Color::from_rgb(0x02, 0x2c, 0x22);
+/// `teal-50` (`#f0fdfa`).
+pub const TEAL_50: Color = Color::from_rgb(0xf0, 0xfd, 0xfa);
+/// `teal-100` (`#ccfbf1`).
+pub const TEAL_100: Color = Color::from_rgb(0xcc, 0xfb, 0xf1);
+/// `teal-200` (`#99f6e4`).
+pub const TEAL_200: Color = Color::from_rgb(0x99, 0xf6, 0xe4);
+/// `teal-300` (`#5eead4`).
+pub const TEAL_300: Color = Color::from_rgb(0x5e, 0xea, 0xd4);
+/// `teal-400` (`#2dd4bf`).
+pub const TEAL_400: Color = Color::from_rgb(0x2d, 0xd4, 0xbf);
+/// `teal-500` (`#14b8a6`).
+pub const TEAL_500: Color = Color::from_rgb(0x14, 0xb8, 0xa6);
+/// `teal-600` (`#0d9488`).
+pub const TEAL_600: Color = Color::from_rgb(0x0d, 0x94, 0x88);
+/// `teal-700` (`#0f766e`).
+pub const TEAL_700: Color = Color::from_rgb(0x0f, 0x76, 0x6e);
+/// `teal-800` (`#115e59`).
+pub const TEAL_800: Color = Color::from_rgb(0x11, 0x5e, 0x59);
+/// `teal-900` (`#134e4a`).
+pub const TEAL_900: Color = Color::from_rgb(0x13, 0x4e, 0x4a);
+/// `teal-950` (`#042f2e`).
+pub const TEAL_950: Color = Color::from_rgb(0x04, 0x2f, 0x2e);
+/// `cyan-50` (`#ecfeff`).
+pub const CYAN_50: Color = Color::from_rgb(0xec, 0xfe, 0xff);
+/// `cyan-100` (`#cffafe`).
+pub const CYAN_100: Color = Color::from_rgb(0xcf, 0xfa, 0xfe);
+/// `cyan-200` (`#a5f3fc`).
+pub const CYAN_200: Color = Color::from_rgb(0xa5, 0xf3, 0xfc);
+/// `cyan-300` (`#67e8f9`).
+pub const CYAN_300: Color = Color::from_rgb(0x67, 0xe8, 0xf9);
+/// `cyan-400` (`#22d3ee`).
+pub const CYAN_400: Color = Color::from_rgb(0x22, 0xd3, 0xee);
+/// `cyan-500` (`#06b6d4`).
+pub const CYAN_500: Color = Color::from_rgb(0x06, 0xb6, 0xd4);
+/// `cyan-600` (`#0891b2`).
+pub const CYAN_600: Color = Color::from_rgb(0x08, 0x91, 0xb2);
+/// `cyan-700` (`#0e7490`).
+pub const CYAN_700: Color = Color::from_rgb(0x0e, 0x74, 0x90);
+/// `cyan-800` (`#155e75`).
+pub const CYAN_800: Color = Color::from_rgb(0x15, 0x5e, 0x75);
+/// `cyan-900` (`#164e63`).
+pub const CYAN_900: Color = Color::from_rgb(0x16, 0x4e, 0x63);
+/// `cyan-950` (`#083344`).
+pub const CYAN_950: Color = Color::from_rgb(0x08, 0x33, 0x44);
+/// `sky-50` (`#f0f9ff`).
+pub const SKY_50: Color = Color::from_rgb(0xf0, 0xf9, 0xff);
+/// `sky-100` (`#e0f2fe`).
+pub const SKY_100: Color = Color::from_rgb(0xe0, 0xf2, 0xfe);
+/// `sky-200` (`#bae6fd`).
+pub const SKY_200: Color = Color::from_rgb(0xba, 0xe6, 0xfd);
+/// `sky-300` (`#7dd3fc`).
+pub const SKY_300: Color = Color::from_rgb(0x7d, 0xd3, 0xfc);
+/// `sky-400` (`#38bdf8`).
+pub const SKY_400: Color = Color::from_rgb(0x38, 0xbd, 0xf8);
+/// `sky-500` (`#0ea5e9`).
+pub const SKY_500: Color = Color::from_rgb(0x0e, 0xa5, 0xe9);
+/// `sky-600` (`#0284c7`).
+pub const SKY_600: Color = Color::from_rgb(0x02, 0x84, 0xc7);
+/// `sky-700` (`#0369a1`).
+pub const SKY_700: Color = Color::from_rgb(0x03, 0x69, 0xa1);
+/// `sky-800` (`#075985`).
+pub const SKY_800: Color = Color::from_rgb(0x07, 0x59, 0x85);
+/// `sky-900` (`#0c4a6e`).
+pub const SKY_900: Color = Color::from_rgb(0x0c, 0x4a, 0x6e);
+/// `sky-950` (`#082f49`).
+pub const SKY_950: Color = Color::from_rgb(0x08, 0x2f, 0x49);
+/// `blue-50` (`#eff6ff`).
+pub const BLUE_50: Color = Color::from_rgb(0xef, 0xf6, 0xff);
+/// `blue-100` (`#dbeafe`).
+pub const BLUE_100: Color = Color::from_rgb(0xdb, 0xea, 0xfe);
+/// `blue-200` (`#bfdbfe`).
+pub const BLUE_200: Color = Color::from_rgb(0xbf, 0xdb, 0xfe);
+/// `blue-300` (`#93c5fd`).
+pub const BLUE_300: Color = Color::from_rgb(0x93, 0xc5, 0xfd);
+/// `blue-400` (`#60a5fa`).
+pub const BLUE_400: Color = Color::from_rgb(0x60, 0xa5, 0xfa);
+/// `blue-500` (`#3b82f6`).
+pub const BLUE_500: Color = Color::from_rgb(0x3b, 0x82, 0xf6);
+/// `blue-600` (`#2563eb`).
+pub const BLUE_600: Color = Color::from_rgb(0x25, 0x63, 0xeb);
+/// `blue-700` (`#1d4ed8`).
+pub const BLUE_700: Color = Color::from_rgb(0x1d, 0x4e, 0xd8);
+/// `blue-800` (`#1e40af`).
+pub const BLUE_800: Color = Color::from_rgb(0x1e, 0x40, 0xaf);
+/// `blue-900` (`#1e3a8a`).
+pub const BLUE_900: Color = Color::from_rgb(0x1e, 0x3a, 0x8a);
+/// `blue-950` (`#172554`).
+pub const BLUE_950: Color = Color::from_rgb(0x17, 0x25, 0x54);
+/// `indigo-50` (`#eef2ff`).
+pub const INDIGO_50: Color = Color::from_rgb(0xee, 0xf2, 0xff);
+/// `indigo-100` (`#e0e7ff`).
+pub const INDIGO_100: Color = Color::from_rgb(0xe0, 0xe7, 0xff);
+/// `indigo-200` (`#c7d2fe`).
+pub const INDIGO_200: Color = Color::from_rgb(0xc7, 0xd2, 0xfe);
+/// `indigo-300` (`#a5b4fc`).
+pub const INDIGO_300: Color = Color::from_rgb(0xa5, 0xb4, 0xfc);
+/// `indigo-400` (`#818cf8`).
+pub const INDIGO_400: Color = Color::from_rgb(0x81, 0x8c, 0xf8);
+/// `indigo-500` (`#6366f1`).
+pub const INDIGO_500: Color = Color::from_rgb(0x63, 0x66, 0xf1);
+/// `indigo-600` (`#4f46e5`).
+pub const INDIGO_600: Color = Color::from_rgb(0x4f, 0x46, 0xe5);
+/// `indigo-700` (`#4338ca`).
+pub const INDIGO_700: Color = Color::from_rgb(0x43, 0x38, 0xca);
+/// `indigo-800` (`#3730a3`).
+pub const INDIGO_800: Color = Color::from_rgb(0x37, 0x30, 0xa3);
+/// `indigo-900` (`#312e81`).
+pub const INDIGO_900: Color = Color::from_rgb(0x31, 0x2e, 0x81);
+/// `indigo-950` (`#1e1b4b`).
+pub const INDIGO_950: Color = Color::from_rgb(0x1e, 0x1b, 0x4b);
+/// `violet-50` (`#f5f3ff`).
+pub const VIOLET_50: Color = Color::from_rgb(0xf5, 0xf3, 0xff);
+/// `violet-100` (`#ede9fe`).
+pub const VIOLET_100: Color = Color::from_rgb(0xed, 0xe9, 0xfe);
+/// `violet-200` (`#ddd6fe`).
+pub const VIOLET_200: Color = Color::from_rgb(0xdd, 0xd6, 0xfe);
+/// `violet-300` (`#c4b5fd`).
+pub const VIOLET_300: Color = Color::from_rgb(0xc4, 0xb5, 0xfd);
+/// `violet-400` (`#a78bfa`).
+pub const VIOLET_400: Color = Color::from_rgb(0xa7, 0x8b, 0xfa);
+/// `violet-500` (`#8b5cf6`).
+pub const VIOLET_500: Color = Color::from_rgb(0x8b, 0x5c, 0xf6);
+/// `violet-600` (`#7c3aed`).
+pub const VIOLET_600: Color = Color::from_rgb(0x7c, 0x3a, 0xed);
+/// `violet-700` (`#6d28d9`).
+pub const VIOLET_700: Color = Color::from_rgb(0x6d, 0x28, 0xd9);
+/// `violet-800` (`#5b21b6`).
+pub const VIOLET_800: Color = Color::from_rgb(0x5b, 0x21, 0xb6);
+/// `violet-900` (`#4c1d95`).
+pub const VIOLET_900: Color = Color::from_rgb(0x4c, 0x1d, 0x95);
+/// `violet-950` (`#2e1065`).
+pub const VIOLET_950: Color = Color::from_rgb(0x2e, 0x10, 0x65);
+/// `purple-50` (`#faf5ff`).
+pub const PURPLE_50: Color = Color::from_rgb(0xfa, 0xf5, 0xff);
+/// `purple-100` (`#f3e8ff`).
+pub const PURPLE_100: Color = Color::from_rgb(0xf3, 0xe8, 0xff);
+/// `purple-200` (`#e9d5ff`).
+pub const PURPLE_200: Color = Color::from_rgb(0xe9, 0xd5, 0xff);
+/// `purple-300` (`#d8b4fe`).
+pub const PURPLE_300: Color = Color::from_rgb(0xd8, 0xb4, 0xfe);
+/// `purple-400` (`#c084fc`).
+pub const PURPLE_400: Color = Color::from_rgb(0xc0, 0x84, 0xfc);
+/// `purple-500` (`#a855f7`).
+pub const PURPLE_500: Color = Color::from_rgb(0xa8, 0x55, 0xf7);
+/// `purple-600` (`#9333ea`).
+pub const PURPLE_600: Color = Color::from_rgb(0x93, 0x33, 0xea);
+/// `purple-700` (`#7e22ce`).
+pub const PURPLE_700: Color = Color::from_rgb(0x7e, 0x22, 0xce);
+/// `purple-800` (`#6b21a8`).
+pub const PURPLE_800: Color = Color::from_rgb(0x6b, 0x21, 0xa8);
+/// `purple-900` (`#581c87`).
+pub const PURPLE_900: Color = Color::from_rgb(0x58, 0x1c, 0x87);
+/// `purple-950` (`#3b0764`).
+pub const PURPLE_950: Color = Color::from_rgb(0x3b, 0x07, 0x64);
+/// `fuchsia-50` (`#fdf4ff`).
+pub const FUCHSIA_50: Color = Color::from_rgb(0xfd, 0xf4, 0xff);
+/// `fuchsia-100` (`#fae8ff`).
+pub const FUCHSIA_100: Color = Color::from_rgb(0xfa, 0xe8, 0xff);
+/// `fuchsia-200` (`#f5d0fe`).
+pub const FUCHSIA_200: Color = Color::from_rgb(0xf5, 0xd0, 0xfe);
+/// `fuchsia-300` (`#f0abfc`).
+pub const FUCHSIA_300: Color = Color::from_rgb(0xf0, 0xab, 0xfc);
+/// `fuchsia-400` (`#e879f9`).
+pub const FUCHSIA_400: Color = Color::from_rgb(0xe8, 0x79, 0xf9);
+/// `fuchsia-500` (`#d946ef`).
+pub const FUCHSIA_500: Color = Color::from_rgb(0xd9, 0x46, 0xef);
+/// `fuchsia-600` (`#c026d3`).
+pub const FUCHSIA_600: Color = Color::from_rgb(0xc0, 0x26, 0xd3);
+/// `fuchsia-700` (`#a21caf`).
+pub const FUCHSIA_700: Color = Color::from_rgb(0xa2, 0x1c, 0xaf);
+/// `fuchsia-800` (`#86198f`).
+pub const FUCHSIA_800: Color = Color::from_rgb(0x86, 0x19, 0x8f);
+/// `fuchsia-900` (`#701a75`).
+pub const FUCHSIA_900: Color = Color::from_rgb(0x70, 0x1a, 0x75);
+/// `fuchsia-950` (`#4a044e`).
+pub const FUCHSIA_950: Color = Color::from_rgb(0x4a, 0x04, 0x4e);
+/// `pink-50` (`#fdf2f8`).
+pub const PINK_50: Color = Color::from_rgb(0xfd, 0xf2, 0xf8);
+/// `pink-100` (`#fce7f3`).
+pub const PINK_100: Color = Color::from_rgb(0xfc, 0xe7, 0xf3);
+/// `pink-200` (`#fbcfe8`).
+pub const PINK_200: Color = Color::from_rgb(0xfb, 0xcf, 0xe8);
+/// `pink-300` (`#f9a8d4`).
+pub const PINK_300: Color = Color::from_rgb(0xf9, 0xa8, 0xd4);
+/// `pink-400` (`#f472b6`).
+pub const PINK_400: Color = Color::from_rgb(0xf4, 0x72, 0xb6);
+/// `pink-500` (`#ec4899`).
+pub const PINK_500: Color = Color::from_rgb(0xec, 0x48, 0x99);
+/// `pink-600` (`#db2777`).
+pub const PINK_600: Color = Color::from_rgb(0xdb, 0x27, 0x77);
+/// `pink-700` (`#be185d`).
+pub const PINK_700: Color = Color::from_rgb(0xbe, 0x18, 0x5d);
+/// `pink-800` (`#9d174d`).
+pub const PINK_800: Color = Color::from_rgb(0x9d, 0x17, 0x4d);
+/// `pink-900` (`#831843`).
+pub const PINK_900: Color = Color::from_rgb(0x83, 0x18, 0x43);
+/// `pink-950` (`#500724`).
+pub const PINK_950: Color = Color::from_rgb(0x50, 0x07, 0x24);
+/// `rose-50` (`#fff1f2`).
+pub const ROSE_50: Color = Color::from_rgb(0xff, 0xf1, 0xf2);
+/// `rose-100` (`#ffe4e6`).
+pub const ROSE_100: Color = Color::from_rgb(0xff, 0xe4, 0xe6);
+/// `rose-200` (`#fecdd3`).
+pub const ROSE_200: Color = Color::from_rgb(0xfe, 0xcd, 0xd3);
+/// `rose-300` (`#fda4af`).
+pub const ROSE_300: Color = Color::from_rgb(0xfd, 0xa4, 0xaf);
+/// `rose-400` (`#fb7185`).
+pub const ROSE_400: Color = Color::from_rgb(0xfb, 0x71, 0x85);
+/// `rose-500` (`#f43f5e`).
+pub const ROSE_500: Color = Color::from_rgb(0xf4, 0x3f, 0x5e);
+/// `rose-600` (`#e11d48`).
+pub const ROSE_600: Color = Color::from_rgb(0xe1, 0x1d, 0x48);
+/// `rose-700` (`#be123c`).
+pub const ROSE_700: Color = Color::from_rgb(0xbe, 0x12, 0x3c);
+/// `rose-800` (`#9f1239`).
+pub const ROSE_800: Color = Color::from_rgb(0x9f, 0x12, 0x39);
+/// `rose-900` (`#881337`).
+pub const ROSE_900: Color = Color::from_rgb(0x88, 0x13, 0x37);
+/// `rose-950` (`#4c0519`).
+pub const ROSE_950: Color = Color::from_rgb(0x4c, 0x05, 0x19);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn palette_contents() {
+        let palette = palette();
+        assert_eq!(palette.name(), "Tailwind CSS");
+        assert_eq!(palette.len(), 22 * 11);
+        assert_eq!(palette.get("slate-50"), Some(SLATE_50));
+        assert_eq!(
+            palette.get("blue-500"),
+            Some(Color::from_hex("#3b82f6").unwrap())
+        );
+        assert_eq!(palette.get("rose-950"), Some(ROSE_950));
+        assert_eq!(palette.get("slate-1000"), None);
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::Color;
+
+/// How much a `warm(1.0)` scales the red and blue channels (in linear light).
+const SHIFT: f32 = 0.3;
+
+impl Color {
+    /// Estimates the correlated color temperature of this color in Kelvin, using a cubic
+    /// approximation from its chromaticity. Lower values are warmer (reddish), higher values
+    /// are cooler (bluish), with `6500` being roughly neutral white.
+    ///
+    /// The estimate is only meaningful for colors that are fairly close to white (roughly
+    /// `2000..12500` K); for strongly saturated colors treat it as a warm/cool score. Returns
+    /// `None` for black, which has no chromaticity.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Color;
+    /// let white = Color::from_rgb(255, 255, 255).temperature().unwrap();
+    /// assert!((white - 6504.0).abs() < 10.0);
+    /// assert!(Color::from_rgb(255, 200, 150).temperature().unwrap() < white);
+    /// assert_eq!(Color::from_rgb(0, 0, 0).temperature(), None);
+    /// ```
+    #[allow(clippy::many_single_char_names, clippy::suboptimal_flops)]
+    #[must_use]
+    pub fn temperature(self) -> Option<f32> {
+        let (r, g, b) = self.to_linear();
+        let x = 0.4124 * r + 0.3576 * g + 0.1805 * b;
+        let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let z = 0.0193 * r + 0.1192 * g + 0.9505 * b;
+        let sum = x + y + z;
+        if sum <= 0.0 {
+            return None;
+        }
+
+        let n = (x / sum - 0.3320) / (0.1858 - y / sum);
+        Some(449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5520.33)
+    }
+
+    /// Creates a warmer version of this color, by boosting red and reducing blue. `amount` is
+    /// clamped to `-1.0..=1.0`, where negative values make the color cooler instead.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::Color;
+    /// let gray = Color::from_rgb(128, 128, 128);
+    /// assert_eq!(gray.warm(0.5), Color::from_rgb(137, 128, 119));
+    /// assert_eq!(gray.cool(0.5), Color::from_rgb(119, 128, 137));
+    /// ```
+    #[must_use]
+    pub fn warm(self, amount: f32) -> Self {
+        let amount = if amount.is_nan() {
+            0.0
+        } else {
+            amount.clamp(-1.0, 1.0)
+        };
+        let (r, g, b) = self.to_linear();
+        Self::from_linear(r * (1.0 + amount * SHIFT), g, b * (1.0 - amount * SHIFT))
+    }
+
+    /// Creates a cooler version of this color, the opposite of [`Color::warm`].
+    #[must_use]
+    pub fn cool(self, amount: f32) -> Self {
+        self.warm(-amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn temperature_ordering() {
+        let candle = Color::from_rgb(255, 147, 41).temperature().unwrap();
+        let white = Color::from_rgb(255, 255, 255).temperature().unwrap();
+        let sky = Color::from_rgb(201, 226, 255).temperature().unwrap();
+        assert!(candle < 2500.0, "{candle}");
+        assert!(candle < white && white < sky);
+        // Grays all have the same chromaticity as white.
+        let gray = Color::from_rgb(60, 60, 60).temperature().unwrap();
+        assert!((gray - white).abs() < 1.0);
+    }
+
+    #[test]
+    fn warm_and_cool() {
+        let gray = Color::from_rgb(128, 128, 128);
+        let before = gray.temperature().unwrap();
+        assert!(gray.warm(0.8).temperature().unwrap() < before);
+        assert!(gray.cool(0.8).temperature().unwrap() > before);
+        assert_eq!(gray.warm(0.0), gray);
+        assert_eq!(gray.warm(5.0), gray.warm(1.0));
+        assert_eq!(gray.warm(-0.3), gray.cool(0.3));
+        assert_eq!(
+            Color::from_rgb(255, 0, 255).warm(1.0),
+            Color::from_rgb(255, 0, 218)
+        );
+    }
+}
@@ -4,7 +4,9 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use crate::{Ansi, Color, ColorParseError, Colors, IntoAnsi};
+#[cfg(feature = "named-colors")]
+use crate::Colors;
+use crate::{Ansi, Color, ColorParseError, IntoAnsi};
 
 /// Trait used to facilitate converting various types to a color.
 pub trait ToColor: std::fmt::Debug {
@@ -12,12 +14,14 @@ pub trait ToColor: std::fmt::Debug {
     fn to_color(&self) -> Color;
 }
 
+#[cfg(feature = "named-colors")]
 impl ToColor for Colors {
     fn to_color(&self) -> Color {
         self.rgb().into()
     }
 }
 
+#[cfg(feature = "named-colors")]
 impl ToColor for &Colors {
     fn to_color(&self) -> Color {
         (*self).rgb().into()
@@ -36,12 +40,14 @@ impl ToColor for &Color {
     }
 }
 
+#[cfg(feature = "named-colors")]
 impl IntoAnsi for Colors {
     fn into_ansi(self) -> Ansi {
         Ansi::from_fg(self)
     }
 }
 
+#[cfg(feature = "named-colors")]
 impl IntoAnsi for &Colors {
     fn into_ansi(self) -> Ansi {
         Ansi::from_fg(*self)
@@ -72,6 +78,25 @@ impl ToColor for (u8, u8, u8) {
     }
 }
 
+impl From<u32> for Color {
+    /// Converts a packed `0xRRGGBB` integer into a [`Color`], ignoring the top byte.
+    fn from(rgb: u32) -> Self {
+        Color::from_u32(rgb)
+    }
+}
+
+impl From<Color> for u32 {
+    fn from(color: Color) -> Self {
+        color.to_u32()
+    }
+}
+
+impl ToColor for u32 {
+    fn to_color(&self) -> Color {
+        Color::from_u32(*self)
+    }
+}
+
 impl TryFrom<&str> for Color {
     type Error = ColorParseError;
 
@@ -0,0 +1,240 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use serde::Serialize;
+
+use crate::ansi::{describe_ansi, BASIC_NAMES, FLAG_NAMES};
+use crate::{Ansi, Color, Theme};
+
+/// The color and style vocabulary of this crate, in a form that serializes to JSON (or any
+/// other `serde` format) for external tools such as shell completions, theme editors and docs
+/// generators.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Vocabulary {
+    /// The 16 basic terminal colors, in palette order, by the names used in style specs.
+    pub basic_colors: Vec<BasicColor>,
+    /// The named colors (see [`Colors`](crate::Colors)). Empty without the `named-colors`
+    /// feature.
+    pub named_colors: Vec<NamedColor>,
+    /// The text attributes and the SGR codes that turn them on and off.
+    pub flags: Vec<FlagCode>,
+    /// The entries of a theme, in order, or `None` when no theme was given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<Vec<ThemeEntry>>,
+}
+
+/// One of the 16 basic terminal colors.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BasicColor {
+    /// The name of the color in style specs, e.g. `bright-red`.
+    pub name: &'static str,
+    /// The palette index, `0..=15`.
+    pub index: u8,
+    /// The standard xterm value of the color, as `#RRGGBB`.
+    pub hex: String,
+}
+
+/// A named color.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NamedColor {
+    /// The name of the color, e.g. `AliceBlue`.
+    pub name: &'static str,
+    /// The color, as `#RRGGBB`.
+    pub hex: String,
+}
+
+/// A text attribute, e.g. bold.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FlagCode {
+    /// The name of the attribute in style specs, e.g. `bold`.
+    pub name: &'static str,
+    /// The SGR code that turns the attribute on.
+    pub on: u8,
+    /// The SGR code that turns the attribute off.
+    pub off: u8,
+}
+
+/// A key of a [`Theme`](struct@Theme) with its style.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ThemeEntry {
+    /// The key of the entry, e.g. `error`.
+    pub key: String,
+    /// The style of the entry as a [style spec](Ansi::parse_spec), e.g. `bold red`.
+    pub spec: String,
+}
+
+impl Vocabulary {
+    /// Collects the vocabulary of this crate, without a theme.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            basic_colors: BASIC_NAMES
+                .iter()
+                .zip(0..)
+                .map(|(name, index)| BasicColor {
+                    name,
+                    index,
+                    hex: Color::ansi_256_to_color(index).as_hex(),
+                })
+                .collect(),
+            named_colors: named_colors(),
+            flags: FLAG_NAMES
+                .iter()
+                .filter_map(|(_, name)| {
+                    let style = Ansi::parse_spec(name).ok()?;
+                    Some(FlagCode {
+                        name,
+                        on: sgr_code(&style.to_string())?,
+                        off: sgr_code(&style.undo_sequence())?,
+                    })
+                })
+                .collect(),
+            theme: None,
+        }
+    }
+
+    /// Builder function to include the entries of `theme`.
+    #[must_use]
+    pub fn with_theme(self, theme: &Theme) -> Self {
+        Self {
+            theme: Some(
+                theme
+                    .iter()
+                    .map(|(key, style)| ThemeEntry {
+                        key: key.to_string(),
+                        spec: describe_ansi(&style),
+                    })
+                    .collect(),
+            ),
+            ..self
+        }
+    }
+
+    /// Serializes this vocabulary to JSON.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        // Serializing plain strings and numbers to JSON can not fail.
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Gets the single SGR code of an escape sequence such as `ESC[22m`.
+fn sgr_code(sequence: &str) -> Option<u8> {
+    sequence
+        .strip_prefix("\x1b[")?
+        .strip_suffix('m')?
+        .parse()
+        .ok()
+}
+
+#[cfg(feature = "named-colors")]
+fn named_colors() -> Vec<NamedColor> {
+    crate::Colors::all_pairs()
+        .map(|(_, color, name)| NamedColor {
+            name,
+            hex: color.as_hex(),
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "named-colors"))]
+fn named_colors() -> Vec<NamedColor> {
+    Vec::new()
+}
+
+/// Dumps the named colors, basic colors and flag codes of this crate as JSON. See
+/// [`Vocabulary`] for the layout.
+///
+/// ## Example
+/// ```
+/// let json = ansirs::introspect::dump_json();
+/// assert!(json.contains(r#"{"name":"bold","on":1,"off":22}"#));
+/// ```
+#[must_use]
+pub fn dump_json() -> String {
+    Vocabulary::new().to_json()
+}
+
+/// Like [`dump_json`], but also includes the entries of `theme`.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi, Theme};
+/// let theme = Theme::new().with("error", Ansi::new().bold().fg_indexed(1));
+/// let json = ansirs::introspect::dump_json_with_theme(&theme);
+/// assert!(json.ends_with(r#""theme":[{"key":"error","spec":"bold red"}]}"#));
+/// ```
+#[must_use]
+pub fn dump_json_with_theme(theme: &Theme) -> String {
+    Vocabulary::new().with_theme(theme).to_json()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn vocabulary() {
+        let vocabulary = Vocabulary::new();
+        assert_eq!(vocabulary.basic_colors.len(), 16);
+        assert_eq!(
+            vocabulary.basic_colors[9],
+            BasicColor {
+                name: "bright-red",
+                index: 9,
+                hex: "#FF0000".to_string()
+            }
+        );
+        assert_eq!(
+            vocabulary
+                .flags
+                .iter()
+                .map(|flag| (flag.name, flag.on, flag.off))
+                .collect::<Vec<_>>(),
+            vec![
+                ("bold", 1, 22),
+                ("dim", 2, 22),
+                ("italic", 3, 23),
+                ("underline", 4, 24),
+                ("blink", 5, 25),
+                ("reverse", 7, 27),
+                ("conceal", 8, 28),
+                ("strike", 9, 29),
+            ]
+        );
+        #[cfg(feature = "named-colors")]
+        assert_eq!(vocabulary.named_colors.len(), crate::Colors::all().count());
+    }
+
+    #[test]
+    fn json() {
+        let theme = Theme::new()
+            .with("plain", Ansi::new())
+            .with("link", Ansi::from_fg((0, 0, 255)).underline());
+        let json = dump_json_with_theme(&theme);
+        assert!(
+            json.starts_with(r##"{"basic_colors":[{"name":"black","index":0,"hex":"#000000"},"##)
+        );
+        assert!(json.ends_with(
+            r#""theme":[{"key":"plain","spec":"default"},{"key":"link","spec":"underline #0000ff"}]}"#
+        ));
+        assert!(!dump_json().contains("theme"));
+
+        for entry in Vocabulary::new().with_theme(&theme).theme.unwrap() {
+            assert_eq!(
+                Ansi::parse_spec(&entry.spec),
+                Ok(theme.get(&entry.key).unwrap())
+            );
+        }
+    }
+}
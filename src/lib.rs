@@ -23,13 +23,69 @@
 
 mod ansi;
 mod color;
-mod styled;
+
+/// Analyzes styled output, e.g. to find the colors another program uses.
+pub mod analyze;
+
+/// Dumps the color and style vocabulary of this crate as JSON. Requires the `serde` feature.
+#[cfg(feature = "serde")]
+pub mod introspect;
+
+/// Styled prompts that read answers from stdin.
+pub mod prompt;
+
+/// Contains the functions and helpers used to produce styled text.
+///
+/// Everything in here is also re-exported from the crate root.
+pub mod styled;
+
+/// Detects the size of the terminal the program is running in, and restores its state on exit.
+pub mod terminal;
+
+/// Helpers for asserting on styled output in tests. Requires the `test-util` feature.
+#[cfg(feature = "test-util")]
+pub mod test_util;
 
 /// Contains code for iterating over named colors.
+#[cfg(feature = "named-colors")]
 pub mod iter {
     pub use crate::color::iter::*;
 }
 
+/// Derives a theme struct from `Ansi` fields with `#[style("...")]` attributes. Requires the
+/// `derive` feature.
+///
+/// Every field is set to its [style spec](Ansi::parse_spec) by the generated `Default`
+/// implementation (fields without one are unstyled), the struct converts into a
+/// [`Theme`](struct@Theme) keyed by field name, and an `env_overrides` method applies
+/// `ANSIRS_STYLE_<FIELD>` variables like [`Theme::env_overrides`] does. Since the struct has a
+/// `Default`, deriving `serde::Deserialize` with `#[serde(default)]` lets a config file
+/// override only some of the styles.
+///
+/// ## Example
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use ansirs::{Ansi, Theme};
+///
+/// #[derive(Theme)]
+/// struct AppTheme {
+///     #[style("bold red")]
+///     error: Ansi,
+///     #[style("underline on #202020")]
+///     path: Ansi,
+///     plain: Ansi,
+/// }
+///
+/// let theme = AppTheme::default().env_overrides();
+/// assert_eq!(theme.error, Ansi::new().fg_indexed(1).bold());
+/// assert_eq!(Theme::from(&theme).get("plain"), Some(Ansi::new()));
+/// # }
+/// ```
+#[cfg(feature = "derive")]
+pub use ansirs_derive::Theme;
+
 pub use ansi::*;
 pub use color::*;
 pub use styled::*;
+pub use terminal::{install_reset_on_exit, install_reset_on_panic};
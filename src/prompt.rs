@@ -0,0 +1,155 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+
+use crate::{AnsiWriter, IntoAnsi};
+
+/// Prints `question` styled with `style` to stdout and reads a line of input from stdin. The
+/// answer is returned without its line ending.
+///
+/// The question is only styled when stdout is a terminal (see [`stdout`](crate::stdout)), so
+/// the prompt stays readable when the output is captured.
+///
+/// ## Errors
+/// - Any error from writing to stdout or reading from stdin.
+/// - [`io::ErrorKind::UnexpectedEof`] if stdin is closed before a line is read.
+///
+/// ## Example
+/// ```no_run
+/// # use ansirs::{prompt, Ansi};
+/// let name = prompt::ask("Project name: ", Ansi::new().bold())?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn ask(question: impl Display, style: impl IntoAnsi) -> io::Result<String> {
+    ask_with(
+        &mut io::stdin().lock(),
+        &mut crate::stdout(),
+        question,
+        style,
+    )
+}
+
+/// Asks a yes or no `question`, followed by a `[y/n]` hint with `y` styled with `yes_style`
+/// and `n` with `no_style`. The question is asked again until the answer is `y`, `yes`, `n` or
+/// `no` (in any case). Styling works like it does for [`ask`].
+///
+/// ## Errors
+/// - Any error from writing to stdout or reading from stdin. A closed stdin counts as `no`.
+///
+/// ## Example
+/// ```no_run
+/// # use ansirs::{prompt, Ansi};
+/// if prompt::confirm("Overwrite the file?", Ansi::green(), Ansi::red())? {
+///     // ...
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn confirm(
+    question: impl Display,
+    yes_style: impl IntoAnsi,
+    no_style: impl IntoAnsi,
+) -> io::Result<bool> {
+    confirm_with(
+        &mut io::stdin().lock(),
+        &mut crate::stdout(),
+        question,
+        yes_style,
+        no_style,
+    )
+}
+
+/// Implementation of [`ask`] over any input and output.
+fn ask_with<W: Write>(
+    input: &mut impl BufRead,
+    output: &mut AnsiWriter<W>,
+    question: impl Display,
+    style: impl IntoAnsi,
+) -> io::Result<String> {
+    output.write_styled(question, style)?;
+    output.flush()?;
+
+    let mut answer = String::new();
+    if input.read_line(&mut answer)? == 0 {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    let len = answer.trim_end_matches(['\n', '\r']).len();
+    answer.truncate(len);
+    Ok(answer)
+}
+
+/// Implementation of [`confirm`] over any input and output.
+fn confirm_with<W: Write>(
+    input: &mut impl BufRead,
+    output: &mut AnsiWriter<W>,
+    question: impl Display,
+    yes_style: impl IntoAnsi,
+    no_style: impl IntoAnsi,
+) -> io::Result<bool> {
+    let (yes_style, no_style) = (yes_style.into_ansi(), no_style.into_ansi());
+    let question = format!(
+        "{question} [{}/{}] ",
+        output.paint("y", yes_style),
+        output.paint("n", no_style)
+    );
+    loop {
+        let answer = match ask_with(input, output, &question, crate::Ansi::new()) {
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => return Ok(false),
+            answer => answer?,
+        };
+        match answer.trim().to_ascii_lowercase().as_str() {
+            "y" | "yes" => return Ok(true),
+            "n" | "no" => return Ok(false),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Ansi, ColorSupport};
+    use pretty_assertions::assert_eq;
+
+    fn output(writer: AnsiWriter<Vec<u8>>) -> String {
+        String::from_utf8(writer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn asking() {
+        let mut out = AnsiWriter::new(Vec::new(), ColorSupport::TrueColor);
+        let mut input = "Ferris\r\nrest\n".as_bytes();
+        let answer = ask_with(&mut input, &mut out, "Name? ", Ansi::new().bold()).unwrap();
+        assert_eq!(answer, "Ferris");
+        assert_eq!(output(out), "\x1b[1mName? \x1b[0m");
+
+        let mut plain = AnsiWriter::new(Vec::new(), ColorSupport::None);
+        let mut input = "".as_bytes();
+        let error = ask_with(&mut input, &mut plain, "Name? ", Ansi::new().bold()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(output(plain), "Name? ");
+    }
+
+    #[test]
+    fn confirming() {
+        let mut out = AnsiWriter::new(Vec::new(), ColorSupport::None);
+        let mut input = "maybe\n YES \n".as_bytes();
+        let yes = confirm_with(&mut input, &mut out, "Go?", Ansi::green(), Ansi::red());
+        assert!(yes.unwrap());
+        assert_eq!(output(out), "Go? [y/n] Go? [y/n] ");
+
+        let mut out = AnsiWriter::new(Vec::new(), ColorSupport::Ansi16);
+        let mut input = "n\n".as_bytes();
+        let no = confirm_with(&mut input, &mut out, "Go?", Ansi::green(), Ansi::red());
+        assert!(!no.unwrap());
+        assert_eq!(output(out), "Go? [\x1b[92my\x1b[0m/\x1b[91mn\x1b[0m] ");
+
+        let mut out = AnsiWriter::new(Vec::new(), ColorSupport::None);
+        let mut input = "".as_bytes();
+        assert!(!confirm_with(&mut input, &mut out, "Go?", Ansi::green(), Ansi::red()).unwrap());
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::context::resolve;
+use super::width::{terminal_width, visible_width};
+use crate::{style_text, IntoAnsi};
+
+/// Width used by [`banner`] when no width is given and the terminal width is unknown.
+const FALLBACK_WIDTH: usize = 80;
+
+/// Erases from the cursor to the end of the line. Most terminals fill the erased cells with the
+/// current background color.
+const ERASE_TO_END: &str = "\x1b[K";
+
+/// Renders `text` centered on a line that is `width` columns wide, with the padding included
+/// in the styled region so a background color fills the whole line. Useful as a section header
+/// in long output.
+///
+/// When `width` is `None` the [terminal width](crate::terminal_width) is used, or 80 columns
+/// if it is unknown. Text wider than the line is not truncated.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{banner, Ansi};
+/// let header = banner("Tests", Ansi::new().bg((0, 0, 255)), Some(11));
+/// assert_eq!(header, "\x1b[48;2;0;0;255m   Tests   \x1b[0m");
+///
+/// // println!("{}", banner("Results", Ansi::new().bold().bg((0, 0, 255)), None));
+/// ```
+#[must_use]
+pub fn banner(text: impl std::fmt::Display, style: impl IntoAnsi, width: Option<usize>) -> String {
+    let text = text.to_string();
+    let width = width.or_else(terminal_width).unwrap_or(FALLBACK_WIDTH);
+    let padding = width.saturating_sub(visible_width(&text));
+    let before = padding / 2;
+    let after = padding - before;
+
+    style_text(format!("{:before$}{text}{:after$}", "", ""), style)
+}
+
+/// Renders `text` with `style` extended to the right edge of the terminal, so the background
+/// color of a status bar or a selected row spans the whole line instead of stopping at the
+/// text.
+///
+/// The line is padded with spaces up to the [terminal width](crate::terminal_width). When the
+/// width is unknown the line ends with an erase-to-end-of-line sequence instead, which fills
+/// the rest of the line with the background color on terminals that support it. Text that is
+/// not styled, e.g. because styling is [suppressed](crate::suppress_styles), is returned as-is.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{fill_line, Ansi};
+/// println!("{}", fill_line(" main.rs [+]", Ansi::new().bg((40, 40, 90))));
+/// ```
+#[must_use]
+pub fn fill_line(text: impl std::fmt::Display, style: impl IntoAnsi) -> String {
+    fill_to(text, style, terminal_width())
+}
+
+/// Implementation of [`fill_line`] for a known, or unknown, line width.
+fn fill_to(text: impl std::fmt::Display, style: impl IntoAnsi, width: Option<usize>) -> String {
+    let text = text.to_string();
+    let style = style.into_ansi();
+    if resolve(style).is_default() {
+        return text;
+    }
+
+    match width {
+        Some(width) => {
+            let padding = width.saturating_sub(visible_width(&text));
+            style_text(format!("{text}{:padding$}", ""), style)
+        }
+        None => style_text(format!("{text}{ERASE_TO_END}"), style),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Ansi;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn centered() {
+        let style = Ansi::new().bold();
+        assert_eq!(banner("ab", style, Some(6)), "\x1b[1m  ab  \x1b[0m");
+        assert_eq!(banner("ab", style, Some(5)), "\x1b[1m ab  \x1b[0m");
+        assert_eq!(banner("long", style, Some(2)), "\x1b[1mlong\x1b[0m");
+        assert_eq!(banner("", Ansi::new(), Some(3)), "   ");
+        assert_eq!(
+            visible_width(&banner(Ansi::red().paint_text("x"), style, Some(4))),
+            4
+        );
+    }
+
+    #[test]
+    fn filled() {
+        let style = Ansi::new().bg_indexed(4);
+        assert_eq!(fill_to("ab", style, Some(5)), "\x1b[48;5;4mab   \x1b[0m");
+        assert_eq!(fill_to("abc", style, Some(2)), "\x1b[48;5;4mabc\x1b[0m");
+        assert_eq!(fill_to("ab", style, None), "\x1b[48;5;4mab\x1b[K\x1b[0m");
+        assert_eq!(fill_to("ab", Ansi::new(), Some(5)), "ab");
+        crate::without_styles(|| assert_eq!(fill_to("ab", style, None), "ab"));
+        crate::with_default_style(style, || {
+            assert_eq!(fill_to("a", Ansi::new(), Some(2)), "\x1b[48;5;4ma \x1b[0m");
+        });
+    }
+
+    #[test]
+    fn auto_width() {
+        let width = terminal_width().unwrap_or(FALLBACK_WIDTH);
+        assert_eq!(visible_width(&banner("hi", Ansi::new(), None)), width);
+    }
+}
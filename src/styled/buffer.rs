@@ -0,0 +1,130 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{Ansi, ResetPolicy};
+
+/// Internal helper for rendering text where consecutive pieces carry different styles.
+///
+/// Switching from one style to another emits a single escape of the form `ESC[0;...m` so that
+/// nothing bleeds from the previous style, and only one trailing [`Ansi::reset`] is written
+/// when the buffer is finished. With [`ResetPolicy::Targeted`] the `0` is replaced by the
+/// codes that turn off the previous style, and the trailing reset by the codes that turn off
+/// the last one.
+#[derive(Debug, Default)]
+pub(crate) struct StyledBuffer {
+    out: String,
+    current: Ansi,
+    policy: ResetPolicy,
+}
+
+impl StyledBuffer {
+    /// Creates a new, empty, [`StyledBuffer`] using the current thread's reset policy.
+    pub(crate) fn new() -> Self {
+        Self {
+            policy: super::reset_policy(),
+            ..Self::default()
+        }
+    }
+
+    /// Appends `text` to the buffer, styled by `style` resolved against the current context
+    /// (the ambient default style, and whether styles are suppressed).
+    pub(crate) fn push(&mut self, text: &str, style: Ansi) {
+        if text.is_empty() {
+            return;
+        }
+        let style = super::context::resolve(style);
+
+        if style != self.current {
+            if style.is_default() {
+                self.end_current();
+            } else {
+                crate::with_sequence(style, |sequence| {
+                    if self.current.is_default() {
+                        self.out.push_str(sequence);
+                    } else {
+                        // Reset first, in the same sequence, so nothing bleeds from `current`.
+                        self.out.push_str("\x1b[");
+                        match self.policy {
+                            ResetPolicy::Full => self.out.push('0'),
+                            ResetPolicy::Targeted => self
+                                .current
+                                .write_undo_codes(&mut self.out)
+                                .expect("Failed to write! to string"),
+                        }
+                        self.out.push(';');
+                        self.out.push_str(&sequence[2..]);
+                    }
+                });
+            }
+            self.current = style;
+        }
+
+        self.out.push_str(text);
+    }
+
+    /// Finishes the buffer, appending a reset if any style is still active.
+    pub(crate) fn finish(mut self) -> String {
+        self.end_current();
+        self.out
+    }
+
+    /// Ends the current style, according to the reset policy.
+    fn end_current(&mut self) {
+        self.policy
+            .write_end(self.current, &mut self.out)
+            .expect("Failed to write! to string");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transitions() {
+        let mut buffer = StyledBuffer::new();
+        buffer.push("a", Ansi::new().bold());
+        buffer.push("b", Ansi::new().bold());
+        buffer.push("c", Ansi::new().italic());
+        buffer.push("", Ansi::red());
+        buffer.push("d", Ansi::new());
+        buffer.push("e", Ansi::new().italic());
+        assert_eq!(
+            buffer.finish(),
+            "\u{1b}[1mab\u{1b}[0;3mc\u{1b}[0md\u{1b}[3me\u{1b}[0m"
+        );
+    }
+
+    #[test]
+    fn context() {
+        let renderers = || {
+            (
+                crate::styled_join(["a", "b"], ",", Ansi::new().bold(), Ansi::new()),
+                crate::kv_list([("k", "v")], Ansi::new(), Ansi::new().bold()),
+            )
+        };
+        let dimmed = crate::with_default_style(Ansi::new().dim(), renderers);
+        assert_eq!(
+            dimmed,
+            (
+                "\x1b[1;2ma\x1b[0;2m,\x1b[0;1;2mb\x1b[0m".to_string(),
+                "\x1b[2mk: \x1b[0;1;2mv\x1b[0m".to_string(),
+            )
+        );
+        assert_eq!(
+            crate::without_styles(renderers),
+            ("a,b".to_string(), "k: v".to_string())
+        );
+    }
+
+    #[test]
+    fn unstyled() {
+        let mut buffer = StyledBuffer::new();
+        buffer.push("plain", Ansi::new());
+        assert_eq!(buffer.finish(), "plain");
+    }
+}
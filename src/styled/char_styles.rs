@@ -0,0 +1,270 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::ops::{Bound, Range, RangeBounds};
+
+use super::buffer::StyledBuffer;
+use crate::Ansi;
+
+/// Maps ranges of a string to the [`Ansi`] style they should be drawn with, which is useful for
+/// syntax or diagnostic highlighting where many small, possibly overlapping, ranges carry
+/// different styles.
+///
+/// Ranges are byte offsets into the text. A range that does not fall on character boundaries is
+/// widened to the characters it touches (or, with the `unicode` feature, to the grapheme
+/// clusters it touches), and ranges past the end of the text are clamped, so a style never
+/// splits a character. When ranges overlap, the one that was set last wins for the
+/// overlapping part. Setting a range to [`Ansi::new`] removes any styling from it.
+///
+/// Rendering only emits an escape sequence where the style actually changes, so adjacent ranges
+/// with equal styles are merged.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi, CharStyles};
+/// let source = "let x = 42;";
+/// let mut styles = CharStyles::new(source);
+/// styles
+///     .set(0..3, Ansi::new().bold())
+///     .set(8..10, Ansi::from_fg((0, 0, 255)))
+///     .set(4..5, Ansi::new().bold());
+///
+/// assert_eq!(styles.style_at(9), Ansi::from_fg((0, 0, 255)));
+/// assert_eq!(
+///     styles.render(),
+///     "\x1b[1mlet\x1b[0m \x1b[1mx\x1b[0m = \x1b[38;2;0;0;255m42\x1b[0m;"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharStyles<'a> {
+    text: &'a str,
+    /// Sorted, non-overlapping, non-empty spans with a non-default style.
+    spans: Vec<(Range<usize>, Ansi)>,
+}
+
+impl<'a> CharStyles<'a> {
+    /// Creates a new [`CharStyles`] for `text`, with no styling.
+    #[must_use]
+    pub const fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            spans: Vec::new(),
+        }
+    }
+
+    /// Gets the text being styled.
+    #[must_use]
+    pub const fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Styles the given byte `range` of the text with `style`, replacing whatever styles were
+    /// previously set for it.
+    pub fn set<R: RangeBounds<usize>>(&mut self, range: R, style: Ansi) -> &mut Self {
+        let range = self.snap(range);
+        if range.is_empty() {
+            return self;
+        }
+
+        let mut spans = Vec::with_capacity(self.spans.len() + 2);
+        for (span, span_style) in self.spans.drain(..) {
+            if span.end <= range.start || span.start >= range.end {
+                spans.push((span, span_style));
+                continue;
+            }
+            if span.start < range.start {
+                spans.push((span.start..range.start, span_style));
+            }
+            if span.end > range.end {
+                spans.push((range.end..span.end, span_style));
+            }
+        }
+        if !style.is_default() {
+            spans.push((range, style));
+        }
+        spans.sort_by_key(|(span, _)| span.start);
+
+        // Merge touching spans with the same style, so they are rendered as a single run.
+        self.spans = Vec::with_capacity(spans.len());
+        for (span, style) in spans {
+            match self.spans.last_mut() {
+                Some((last, last_style)) if last.end == span.start && *last_style == style => {
+                    last.end = span.end;
+                }
+                _ => self.spans.push((span, style)),
+            }
+        }
+        self
+    }
+
+    /// Gets the style of the character containing the byte at `idx`.
+    #[must_use]
+    pub fn style_at(&self, idx: usize) -> Ansi {
+        self.spans
+            .iter()
+            .find(|(span, _)| span.contains(&idx))
+            .map_or_else(Ansi::new, |(_, style)| *style)
+    }
+
+    /// Iterates over the styled spans of the text (in order), as `(range, style)` pairs. Parts of
+    /// the text without styling are skipped, and touching ranges with the same style are
+    /// returned as one span.
+    pub fn spans(&self) -> impl Iterator<Item = (Range<usize>, Ansi)> + '_ {
+        self.spans.iter().cloned()
+    }
+
+    /// Renders the text with all of its styles applied.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut buffer = StyledBuffer::new();
+        let mut cursor = 0;
+        for (span, style) in &self.spans {
+            buffer.push(&self.text[cursor..span.start], Ansi::new());
+            buffer.push(&self.text[span.clone()], *style);
+            cursor = span.end;
+        }
+        buffer.push(&self.text[cursor..], Ansi::new());
+        buffer.finish()
+    }
+
+    /// Turns any range into a byte range covering whole characters within the text.
+    fn snap<R: RangeBounds<usize>>(&self, range: R) -> Range<usize> {
+        let len = self.text.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+
+        let mut start = start.min(len);
+        let mut end = end.min(len).max(start);
+        while !self.text.is_char_boundary(start) {
+            start -= 1;
+        }
+        while !self.text.is_char_boundary(end) {
+            end += 1;
+        }
+
+        #[cfg(feature = "unicode")]
+        {
+            use unicode_segmentation::UnicodeSegmentation;
+
+            let boundaries = self
+                .text
+                .grapheme_indices(true)
+                .map(|(idx, _)| idx)
+                .chain(std::iter::once(len));
+            let (char_start, char_end) = (start, end);
+            for boundary in boundaries {
+                if boundary <= char_start {
+                    start = boundary;
+                }
+                if boundary >= char_end {
+                    end = boundary;
+                    break;
+                }
+            }
+        }
+
+        start..end
+    }
+}
+
+impl std::fmt::Display for CharStyles<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn overlaps() {
+        let bold = Ansi::new().bold();
+        let red = Ansi::red();
+        let mut styles = CharStyles::new("abcdefgh");
+        styles.set(1..7, bold).set(3..5, red);
+        assert_eq!(
+            styles.spans().collect::<Vec<_>>(),
+            vec![(1..3, bold), (3..5, red), (5..7, bold)]
+        );
+
+        styles.set(..4, Ansi::new());
+        assert_eq!(
+            styles.spans().collect::<Vec<_>>(),
+            vec![(4..5, red), (5..7, bold)]
+        );
+
+        styles.set(2.., bold);
+        assert_eq!(styles.spans().collect::<Vec<_>>(), vec![(2..8, bold)]);
+        assert_eq!(styles.style_at(1), Ansi::new());
+        assert_eq!(styles.style_at(7), bold);
+    }
+
+    #[test]
+    fn merges_touching_spans() {
+        let bold = Ansi::new().bold();
+        let mut styles = CharStyles::new("abcdef");
+        for idx in 0..6 {
+            styles.set(idx..=idx, bold);
+        }
+        assert_eq!(styles.spans().collect::<Vec<_>>(), vec![(0..6, bold)]);
+
+        styles.set(2..3, Ansi::red()).set(2..3, bold);
+        assert_eq!(styles.spans().collect::<Vec<_>>(), vec![(0..6, bold)]);
+        assert_eq!(styles.render(), "\x1b[1mabcdef\x1b[0m");
+    }
+
+    #[test]
+    fn snapping() {
+        let mut styles = CharStyles::new("aéb");
+        styles
+            .set(2..=2, Ansi::new().italic())
+            .set(9..20, Ansi::red());
+        assert_eq!(
+            styles.spans().collect::<Vec<_>>(),
+            vec![(1..3, Ansi::new().italic())]
+        );
+        assert_eq!(styles.render(), "a\u{1b}[3mé\u{1b}[0mb");
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn grapheme_snapping() {
+        // `e` followed by a combining acute accent, styling only the `e` takes the accent along.
+        let mut styles = CharStyles::new("ae\u{301}b");
+        styles.set(1..2, Ansi::new().bold());
+        assert_eq!(
+            styles.spans().collect::<Vec<_>>(),
+            vec![(1..4, Ansi::new().bold())]
+        );
+        styles.set(3..4, Ansi::new().italic());
+        assert_eq!(
+            styles.spans().collect::<Vec<_>>(),
+            vec![(1..4, Ansi::new().italic())]
+        );
+    }
+
+    #[test]
+    fn merges_equal_neighbours() {
+        let mut styles = CharStyles::new("abcd");
+        styles
+            .set(0..1, Ansi::new().bold())
+            .set(1..2, Ansi::new().bold())
+            .set(2..3, Ansi::new().dim());
+        assert_eq!(styles.to_string(), "\u{1b}[1mab\u{1b}[0;2mc\u{1b}[0md");
+        assert_eq!(CharStyles::new("plain").render(), "plain");
+        assert_eq!(styles.text(), "abcd");
+    }
+}
@@ -0,0 +1,424 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+use crate::{Ansi, BrightPolicy, CodeOrder, FlushPolicy, IntoAnsi, LineEnding, ResetPolicy};
+
+thread_local! {
+    static DEFAULT_STYLE: Cell<Ansi> = const { Cell::new(Ansi::new()) };
+    static SUPPRESSED: Cell<usize> = const { Cell::new(0) };
+    static RESET_POLICY: Cell<ResetPolicy> = const { Cell::new(ResetPolicy::Full) };
+    static BRIGHT_POLICY: Cell<BrightPolicy> = const { Cell::new(BrightPolicy::Codes) };
+    static CODE_ORDER: Cell<CodeOrder> = const { Cell::new(CodeOrder::Canonical) };
+    static LINE_ENDING: Cell<LineEnding> = const { Cell::new(LineEnding::Lf) };
+    static FLUSH_POLICY: Cell<FlushPolicy> = const { Cell::new(FlushPolicy::Manual) };
+}
+
+/// Runs `f` with `style` as the ambient default style of the current thread.
+///
+/// Everything styled inside `f` with [`style_text`](crate::style_text) (and the functions built
+/// on it), [`style_text_cow`](crate::style_text_cow), [`Painted`](crate::Painted),
+/// [`swrite!`](crate::swrite) or the renderers in this crate, e.g.
+/// [`styled_join`](crate::styled_join) and [`CharStyles`](crate::CharStyles), has `style`
+/// merged underneath its own style (see [`Ansi::merge`]), so a whole section of output can be
+/// dimmed, for example, without passing a base style through every call. Calls can be nested,
+/// in which case the inner style is merged over the outer one. The previous default is
+/// restored when `f` returns or panics.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{style_text, with_default_style, Ansi};
+/// let verbose = with_default_style(Ansi::new().dim(), || {
+///     style_text("details", Ansi::red())
+/// });
+/// assert_eq!(verbose, style_text("details", Ansi::red().dim()));
+/// ```
+pub fn with_default_style<S: IntoAnsi, R>(style: S, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous default style on drop, so a panic in `f` does not leak it.
+    struct Restore(Ansi);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            DEFAULT_STYLE.with(|current| current.set(self.0));
+        }
+    }
+
+    let style = style.into_ansi();
+    let _restore =
+        Restore(DEFAULT_STYLE.with(|current| current.replace(current.get().merge(style))));
+    f()
+}
+
+/// Gets the ambient default style of the current thread, set by [`with_default_style`]. This
+/// is [`Ansi::new`] outside of it.
+#[must_use]
+pub fn default_style() -> Ansi {
+    DEFAULT_STYLE.with(Cell::get)
+}
+
+/// Guard that disables styling on the current thread until it is dropped, see
+/// [`suppress_styles`].
+#[must_use = "styles are only suppressed until the guard is dropped"]
+#[derive(Debug)]
+pub struct StyleSuppression {
+    // The suppression is per thread, so the guard must not move to another one.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for StyleSuppression {
+    fn drop(&mut self) {
+        SUPPRESSED.with(|count| count.set(count.get().saturating_sub(1)));
+    }
+}
+
+/// Disables escape sequences in everything styled on the current thread for as long as the
+/// returned guard is alive, which lets tests assert on plain output from code that styles its
+/// output. Guards can be nested.
+///
+/// This covers [`style_text`](crate::style_text) and everything built on it (including
+/// [`styled_println`](crate::styled_println)), [`Painted`](crate::Painted),
+/// [`swrite!`](crate::swrite), [`Ansi::paint_text`] and the renderers in this crate. Writing
+/// an [`Ansi`] directly with `{}` is not affected.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{style_text, suppress_styles, Ansi};
+/// let _plain = suppress_styles();
+/// assert_eq!(style_text("ok", Ansi::new().bold()), "ok");
+/// ```
+pub fn suppress_styles() -> StyleSuppression {
+    SUPPRESSED.with(|count| count.set(count.get() + 1));
+    StyleSuppression {
+        _not_send: PhantomData,
+    }
+}
+
+/// Runs `f` with styling disabled on the current thread, see [`suppress_styles`].
+///
+/// ## Example
+/// ```
+/// # use ansirs::{style_text, without_styles, Ansi};
+/// let out = without_styles(|| style_text("ok", Ansi::red()));
+/// assert_eq!(out, "ok");
+/// assert_ne!(style_text("ok", Ansi::red()), "ok");
+/// ```
+pub fn without_styles<R>(f: impl FnOnce() -> R) -> R {
+    let _guard = suppress_styles();
+    f()
+}
+
+/// Returns `false` while styling is disabled on the current thread by [`suppress_styles`] or
+/// [`without_styles`].
+#[must_use]
+pub fn styles_enabled() -> bool {
+    SUPPRESSED.with(Cell::get) == 0
+}
+
+/// Runs `f` with `policy` deciding how styled text is ended on the current thread. The previous
+/// policy is restored when `f` returns or panics.
+///
+/// With [`ResetPolicy::Targeted`], text styled inside `f` with
+/// [`style_text`](crate::style_text) (and the functions built on it),
+/// [`style_text_cow`](crate::style_text_cow), [`Painted`](crate::Painted),
+/// [`swrite!`](crate::swrite) and the renderers in this crate ends with only the codes that
+/// turn off its own attributes, instead of a full reset, so it composes with styling that the
+/// surrounding program has active, within the limits described on [`ResetPolicy::Targeted`].
+/// [`Ansi::paint_text`] always ends with a full reset.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{style_text, with_reset_policy, Ansi, ResetPolicy};
+/// let out = with_reset_policy(ResetPolicy::Targeted, || style_text("ok", Ansi::new().bold()));
+/// assert_eq!(out, "\x1b[1mok\x1b[22m");
+/// ```
+pub fn with_reset_policy<R>(policy: ResetPolicy, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous policy on drop, so a panic in `f` does not leak it.
+    struct Restore(ResetPolicy);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            RESET_POLICY.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(RESET_POLICY.with(|current| current.replace(policy)));
+    f()
+}
+
+/// Gets the [`ResetPolicy`] of the current thread, set by [`with_reset_policy`]. This is
+/// [`ResetPolicy::Full`] outside of it.
+#[must_use]
+pub fn reset_policy() -> ResetPolicy {
+    RESET_POLICY.with(Cell::get)
+}
+
+/// Runs `f` with `policy` deciding how bright colors are written by the 16-color renderer
+/// ([`Ansi::ansi16_sequence`] and [`Ansi::paint_text_16`]) on the current thread. The previous
+/// policy is restored when `f` returns or panics.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{with_bright_policy, Ansi, Ansi16, BrightPolicy};
+/// let style = Ansi::new().fg_indexed(Ansi16::BrightYellow.index());
+/// let out = with_bright_policy(BrightPolicy::Bold, || style.paint_text_16("warn"));
+/// assert_eq!(out, "\x1b[1;33mwarn\x1b[0m");
+/// ```
+pub fn with_bright_policy<R>(policy: BrightPolicy, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous policy on drop, so a panic in `f` does not leak it.
+    struct Restore(BrightPolicy);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            BRIGHT_POLICY.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(BRIGHT_POLICY.with(|current| current.replace(policy)));
+    f()
+}
+
+/// Gets the [`BrightPolicy`] of the current thread, set by [`with_bright_policy`]. This is
+/// [`BrightPolicy::Codes`] outside of it.
+#[must_use]
+pub fn bright_policy() -> BrightPolicy {
+    BRIGHT_POLICY.with(Cell::get)
+}
+
+/// Runs `f` with `order` deciding the order in which the SGR parameters of every escape
+/// sequence written on the current thread appear, including `{}` formatting of an [`Ansi`].
+/// The previous order is restored when `f` returns or panics.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{with_code_order, Ansi, CodeOrder};
+/// let style = Ansi::new().fg_indexed(1).bold();
+/// assert_eq!(style.to_string(), "\x1b[1;38;5;1m");
+/// let out = with_code_order(CodeOrder::ColorsFirst, || style.to_string());
+/// assert_eq!(out, "\x1b[38;5;1;1m");
+/// ```
+pub fn with_code_order<R>(order: CodeOrder, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous order on drop, so a panic in `f` does not leak it.
+    struct Restore(CodeOrder);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CODE_ORDER.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(CODE_ORDER.with(|current| current.replace(order)));
+    f()
+}
+
+/// Gets the [`CodeOrder`] of the current thread, set by [`with_code_order`]. This is
+/// [`CodeOrder::Canonical`] outside of it.
+#[must_use]
+pub fn code_order() -> CodeOrder {
+    CODE_ORDER.with(Cell::get)
+}
+
+/// Runs `f` with `line_ending` used by [`styled_print`](crate::styled_print) and
+/// [`styled_println`](crate::styled_println) on the current thread, for the line they end and
+/// for newlines in the styled text, like
+/// [`AnsiWriter::with_line_ending`](crate::AnsiWriter::with_line_ending) does for a writer.
+/// The previous line ending is restored when `f` returns or panics.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{styled_println, with_line_ending, Ansi, LineEnding};
+/// with_line_ending(LineEnding::native(), || styled_println("done", Ansi::green()));
+/// ```
+pub fn with_line_ending<R>(line_ending: LineEnding, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous line ending on drop, so a panic in `f` does not leak it.
+    struct Restore(LineEnding);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            LINE_ENDING.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(LINE_ENDING.with(|current| current.replace(line_ending)));
+    f()
+}
+
+/// Gets the [`LineEnding`] of the current thread, set by [`with_line_ending`]. This is
+/// [`LineEnding::Lf`] outside of it.
+#[must_use]
+pub fn line_ending() -> LineEnding {
+    LINE_ENDING.with(Cell::get)
+}
+
+/// Runs `f` with `policy` deciding when [`styled_print`](crate::styled_print) and
+/// [`styled_println`](crate::styled_println) flush stdout on the current thread, e.g.
+/// [`FlushPolicy::EveryWrite`] so progress output shows up right away. The previous policy is
+/// restored when `f` returns or panics.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{styled_print, with_flush_policy, Ansi, FlushPolicy};
+/// with_flush_policy(FlushPolicy::EveryWrite, || styled_print("50%\r", Ansi::blue()));
+/// ```
+pub fn with_flush_policy<R>(policy: FlushPolicy, f: impl FnOnce() -> R) -> R {
+    /// Restores the previous policy on drop, so a panic in `f` does not leak it.
+    struct Restore(FlushPolicy);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            FLUSH_POLICY.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(FLUSH_POLICY.with(|current| current.replace(policy)));
+    f()
+}
+
+/// Gets the [`FlushPolicy`] of the current thread, set by [`with_flush_policy`]. This is
+/// [`FlushPolicy::Manual`] outside of it.
+#[must_use]
+pub fn flush_policy() -> FlushPolicy {
+    FLUSH_POLICY.with(Cell::get)
+}
+
+/// Resolves the style that should actually be emitted for `style` in the current context.
+pub(crate) fn resolve(style: Ansi) -> Ansi {
+    if styles_enabled() {
+        default_style().merge(style)
+    } else {
+        Ansi::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn nesting_and_restoring() {
+        assert_eq!(default_style(), Ansi::new());
+        with_default_style(Ansi::new().dim(), || {
+            assert_eq!(default_style(), Ansi::new().dim());
+            with_default_style(Ansi::from_bg((1, 1, 1)), || {
+                assert_eq!(default_style(), Ansi::from_bg((1, 1, 1)).dim());
+                assert_eq!(
+                    resolve(Ansi::new().bold()),
+                    Ansi::from_bg((1, 1, 1)).dim().bold()
+                );
+            });
+            assert_eq!(default_style(), Ansi::new().dim());
+        });
+        assert_eq!(default_style(), Ansi::new());
+    }
+
+    #[test]
+    fn restored_after_panic() {
+        let result = std::panic::catch_unwind(|| {
+            with_default_style(Ansi::red(), || panic!("boom"));
+        });
+        assert!(result.is_err());
+        assert_eq!(default_style(), Ansi::new());
+    }
+
+    #[test]
+    fn suppression() {
+        assert!(styles_enabled());
+        let outer = suppress_styles();
+        without_styles(|| {
+            assert!(!styles_enabled());
+            assert_eq!(crate::style_text("a", Ansi::red()), "a");
+            assert_eq!(crate::Painted::new("b", Ansi::red()).to_string(), "b");
+            assert_eq!(Ansi::red().paint_text("c"), "c");
+            let mut styles = crate::CharStyles::new("de");
+            styles.set(0..1, Ansi::red());
+            assert_eq!(styles.render(), "de");
+        });
+        assert!(!styles_enabled());
+        drop(outer);
+        assert!(styles_enabled());
+        assert_ne!(crate::style_text("a", Ansi::red()), "a");
+    }
+
+    #[test]
+    fn reset_policies() {
+        assert_eq!(reset_policy(), ResetPolicy::Full);
+        let style = Ansi::from_fg((1, 2, 3)).italic();
+        let out = with_reset_policy(ResetPolicy::Targeted, || {
+            assert_eq!(reset_policy(), ResetPolicy::Targeted);
+            let mut styles = crate::CharStyles::new("abc");
+            styles.set(0..1, Ansi::new().bold()).set(1..2, style);
+            (
+                crate::style_text("a", style),
+                crate::style_text_cow("b", Ansi::new().dim()).into_owned(),
+                crate::Painted::new("c", style).to_string(),
+                styles.render(),
+            )
+        });
+        assert_eq!(reset_policy(), ResetPolicy::Full);
+        assert_eq!(
+            out,
+            (
+                "\x1b[3;38;2;1;2;3ma\x1b[23;39m".to_string(),
+                "\x1b[2mb\x1b[22m".to_string(),
+                "\x1b[3;38;2;1;2;3mc\x1b[23;39m".to_string(),
+                "\x1b[1ma\x1b[22;3;38;2;1;2;3mb\x1b[23;39mc".to_string(),
+            )
+        );
+    }
+
+    #[test]
+    fn print_options() {
+        assert_eq!(
+            (line_ending(), flush_policy()),
+            (LineEnding::Lf, FlushPolicy::Manual)
+        );
+        let options = with_line_ending(LineEnding::CrLf, || {
+            with_flush_policy(FlushPolicy::Line, || {
+                let ending = line_ending().apply("a\nb\r\n".to_string());
+                (
+                    ending,
+                    flush_policy().flushes("a"),
+                    flush_policy().flushes("\n"),
+                )
+            })
+        });
+        assert_eq!(options, ("a\r\nb\r\n".to_string(), false, true));
+        assert_eq!(
+            (line_ending(), flush_policy()),
+            (LineEnding::Lf, FlushPolicy::Manual)
+        );
+    }
+
+    #[test]
+    fn suppression_is_per_thread() {
+        let _guard = suppress_styles();
+        let other = std::thread::spawn(|| crate::style_text("a", Ansi::red()))
+            .join()
+            .unwrap();
+        assert_eq!(other, "\x1b[38;2;255;0;0ma\x1b[0m");
+    }
+
+    #[test]
+    fn applies_to_styling_functions() {
+        let out = with_default_style(Ansi::new().dim(), || {
+            (
+                crate::style_text("a", Ansi::new()),
+                crate::style_text_cow("b", Ansi::new().bold()).into_owned(),
+                crate::Painted::new("c", Ansi::new()).to_string(),
+            )
+        });
+        assert_eq!(
+            out,
+            (
+                "\x1b[2ma\x1b[0m".to_string(),
+                "\x1b[1;2mb\x1b[0m".to_string(),
+                "\x1b[2mc\x1b[0m".to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,324 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::buffer::StyledBuffer;
+use crate::{Ansi, IntoAnsi};
+
+/// Creates a [`DiffLines`] showing the line by line changes from `old` to `new`, like a
+/// unified diff: removed lines start with a red `-`, added lines with a green `+`, and the
+/// unchanged lines around them are dimmed.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{styled, strip_ansi};
+/// let diff = styled::diff_lines("a\nb\nc", "a\nB\nc").to_string();
+/// assert_eq!(strip_ansi(&diff), "@@ -1,3 +1,3 @@\n a\n-b\n+B\n c");
+/// ```
+#[must_use]
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> DiffLines<'a> {
+    DiffLines::new(old, new)
+}
+
+/// A styled, line based, diff between two texts. Created by [`diff_lines`].
+///
+/// The diff is computed when it is displayed. It finds the longest common subsequence of
+/// lines, which takes time and memory proportional to the product of the number of lines that
+/// differ, so it is meant for files and snapshots rather than huge inputs. Nothing is displayed
+/// when the texts have the same lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffLines<'a> {
+    old: &'a str,
+    new: &'a str,
+    added: Ansi,
+    removed: Ansi,
+    unchanged: Ansi,
+    header: Ansi,
+    context: usize,
+}
+
+/// A single line of a diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+impl<'a> DiffLines<'a> {
+    /// Creates a new [`DiffLines`] using the default styles and 3 lines of context.
+    #[must_use]
+    pub fn new(old: &'a str, new: &'a str) -> Self {
+        Self {
+            old,
+            new,
+            added: Ansi::green(),
+            removed: Ansi::red(),
+            unchanged: Ansi::new().dim(),
+            header: Ansi::from_fg((0, 255, 255)),
+            context: 3,
+        }
+    }
+
+    /// Builder function to set the style used for added lines.
+    #[must_use]
+    pub fn added(self, style: impl IntoAnsi) -> Self {
+        Self {
+            added: style.into_ansi(),
+            ..self
+        }
+    }
+
+    /// Builder function to set the style used for removed lines.
+    #[must_use]
+    pub fn removed(self, style: impl IntoAnsi) -> Self {
+        Self {
+            removed: style.into_ansi(),
+            ..self
+        }
+    }
+
+    /// Builder function to set the style used for unchanged context lines.
+    #[must_use]
+    pub fn unchanged(self, style: impl IntoAnsi) -> Self {
+        Self {
+            unchanged: style.into_ansi(),
+            ..self
+        }
+    }
+
+    /// Builder function to set the style used for the `@@ -1,3 +1,4 @@` hunk headers.
+    #[must_use]
+    pub fn header(self, style: impl IntoAnsi) -> Self {
+        Self {
+            header: style.into_ansi(),
+            ..self
+        }
+    }
+
+    /// Builder function to set how many unchanged lines are shown around each change.
+    /// Changes that are closer together than twice this are shown in the same hunk.
+    #[must_use]
+    pub fn context(self, lines: usize) -> Self {
+        Self {
+            context: lines,
+            ..self
+        }
+    }
+
+    /// Checks if `old` and `new` have the same lines, in which case nothing is displayed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.old.lines().eq(self.new.lines())
+    }
+
+    /// Renders the diff into a [`String`].
+    #[must_use]
+    pub fn render(&self) -> String {
+        let lines = diff(
+            &self.old.lines().collect::<Vec<_>>(),
+            &self.new.lines().collect::<Vec<_>>(),
+        );
+
+        let mut buffer = StyledBuffer::new();
+        let mut first = true;
+        for hunk in hunks(&lines, self.context) {
+            let hunk_lines = &lines[hunk.clone()];
+            let (old_start, new_start) =
+                lines[..hunk.start]
+                    .iter()
+                    .fold((0, 0), |(o, n), line| match line {
+                        Line::Same(_) => (o + 1, n + 1),
+                        Line::Removed(_) => (o + 1, n),
+                        Line::Added(_) => (o, n + 1),
+                    });
+            let old_len = hunk_lines
+                .iter()
+                .filter(|line| !matches!(line, Line::Added(_)))
+                .count();
+            let new_len = hunk_lines
+                .iter()
+                .filter(|line| !matches!(line, Line::Removed(_)))
+                .count();
+
+            if !first {
+                buffer.push("\n", Ansi::new());
+            }
+            first = false;
+            buffer.push(
+                &format!(
+                    "@@ -{} +{} @@",
+                    range(old_start, old_len),
+                    range(new_start, new_len)
+                ),
+                self.header,
+            );
+
+            for line in hunk_lines {
+                buffer.push("\n", Ansi::new());
+                let (gutter, text, style) = match line {
+                    Line::Same(text) => (" ", text, self.unchanged),
+                    Line::Removed(text) => ("-", text, self.removed),
+                    Line::Added(text) => ("+", text, self.added),
+                };
+                buffer.push(gutter, style);
+                buffer.push(text, style);
+            }
+        }
+        buffer.finish()
+    }
+}
+
+impl std::fmt::Display for DiffLines<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+/// Formats the `start,len` part of a hunk header, where `start` is the number of lines before
+/// the hunk. Like `diff -u`, an empty range points at the line before it.
+fn range(start: usize, len: usize) -> String {
+    match len {
+        0 => format!("{start},0"),
+        1 => format!("{}", start + 1),
+        len => format!("{},{len}", start + 1),
+    }
+}
+
+/// Computes the lines of the diff from `old` to `new`, using the longest common subsequence.
+fn diff<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<Line<'a>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let (old_rest, new_rest) = (&old[prefix..], &new[prefix..]);
+    let suffix = old_rest
+        .iter()
+        .rev()
+        .zip(new_rest.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_mid = &old_rest[..old_rest.len() - suffix];
+    let new_mid = &new_rest[..new_rest.len() - suffix];
+
+    // `common[i][j]` is the length of the longest common subsequence of `old_mid[i..]` and
+    // `new_mid[j..]`.
+    let width = new_mid.len() + 1;
+    let mut common = vec![0_usize; (old_mid.len() + 1) * width];
+    for i in (0..old_mid.len()).rev() {
+        for j in (0..new_mid.len()).rev() {
+            common[i * width + j] = if old_mid[i] == new_mid[j] {
+                common[(i + 1) * width + j + 1] + 1
+            } else {
+                common[(i + 1) * width + j].max(common[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut lines = old[..prefix]
+        .iter()
+        .map(|line| Line::Same(line))
+        .collect::<Vec<_>>();
+    let (mut i, mut j) = (0, 0);
+    while i < old_mid.len() || j < new_mid.len() {
+        if i < old_mid.len() && j < new_mid.len() && old_mid[i] == new_mid[j] {
+            lines.push(Line::Same(old_mid[i]));
+            i += 1;
+            j += 1;
+        } else if j == new_mid.len()
+            || (i < old_mid.len() && common[(i + 1) * width + j] >= common[i * width + j + 1])
+        {
+            lines.push(Line::Removed(old_mid[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new_mid[j]));
+            j += 1;
+        }
+    }
+    lines.extend(
+        old_rest[old_rest.len() - suffix..]
+            .iter()
+            .map(|line| Line::Same(line)),
+    );
+    lines
+}
+
+/// Groups the changed lines, with `context` unchanged lines around them, into hunks.
+fn hunks(lines: &[Line<'_>], context: usize) -> Vec<std::ops::Range<usize>> {
+    let mut hunks: Vec<std::ops::Range<usize>> = Vec::new();
+    for (idx, _) in lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Line::Same(_)))
+    {
+        let start = idx.saturating_sub(context);
+        let end = (idx + 1 + context).min(lines.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.end => last.end = end,
+            _ => hunks.push(start..end),
+        }
+    }
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strip_ansi;
+    use pretty_assertions::assert_eq;
+
+    fn plain(diff: DiffLines<'_>) -> String {
+        strip_ansi(&diff.render()).into_owned()
+    }
+
+    #[test]
+    fn styles() {
+        let diff = diff_lines("a\nb", "a\nc")
+            .added(Ansi::new().bold())
+            .removed(Ansi::new().italic())
+            .unchanged(Ansi::new())
+            .header(Ansi::new());
+        assert_eq!(
+            diff.to_string(),
+            "@@ -1,2 +1,2 @@\n a\n\x1b[3m-b\x1b[0m\n\x1b[1m+c\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn same() {
+        let diff = diff_lines("a\nb\n", "a\nb");
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+        assert!(!diff_lines("a", "b").is_empty());
+    }
+
+    #[test]
+    fn additions_and_removals() {
+        assert_eq!(plain(diff_lines("", "a\nb")), "@@ -0,0 +1,2 @@\n+a\n+b");
+        assert_eq!(plain(diff_lines("a\nb", "")), "@@ -1,2 +0,0 @@\n-a\n-b");
+        assert_eq!(
+            plain(diff_lines("a\nb\nc\nd", "a\nc\nx\nd")),
+            "@@ -1,4 +1,4 @@\n a\n-b\n c\n+x\n d"
+        );
+    }
+
+    #[test]
+    fn hunks_and_context() {
+        let old = (1..=20)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let new = old.replace("\n3\n", "\nthree\n").replace("\n18\n", "\n");
+        assert_eq!(
+            plain(diff_lines(&old, &new).context(1)),
+            "@@ -2,3 +2,3 @@\n 2\n-3\n+three\n 4\n@@ -17,3 +17,2 @@\n 17\n-18\n 19"
+        );
+        assert_eq!(
+            plain(diff_lines(&old, &new).context(0)),
+            "@@ -3 +3 @@\n-3\n+three\n@@ -18 +17,0 @@\n-18"
+        );
+        assert_eq!(
+            plain(diff_lines("a\nb\nc\nd\ne", "a\nB\nc\nD\ne").context(1)),
+            "@@ -1,5 +1,5 @@\n a\n-b\n+B\n c\n-d\n+D\n e"
+        );
+    }
+}
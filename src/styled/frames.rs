@@ -0,0 +1,174 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{style_text, Ansi, IntoAnsi};
+
+/// The text of each frame used by [`cycle_frames`]. A single string is treated as one frame
+/// that is repeated, while a list of strings (like spinner characters) is cycled through.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Frames(Vec<String>);
+
+impl Frames {
+    /// Gets the text of each frame.
+    #[must_use]
+    pub fn as_slice(&self) -> &[String] {
+        &self.0
+    }
+}
+
+impl From<&str> for Frames {
+    fn from(text: &str) -> Self {
+        Self(vec![text.to_string()])
+    }
+}
+
+impl From<String> for Frames {
+    fn from(text: String) -> Self {
+        Self(vec![text])
+    }
+}
+
+impl From<&[&str]> for Frames {
+    fn from(frames: &[&str]) -> Self {
+        Self(frames.iter().map(ToString::to_string).collect())
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for Frames {
+    fn from(frames: [&str; N]) -> Self {
+        Self(frames.iter().map(ToString::to_string).collect())
+    }
+}
+
+impl From<Vec<&str>> for Frames {
+    fn from(frames: Vec<&str>) -> Self {
+        Self(frames.into_iter().map(ToString::to_string).collect())
+    }
+}
+
+impl From<Vec<String>> for Frames {
+    fn from(frames: Vec<String>) -> Self {
+        Self(frames)
+    }
+}
+
+/// Creates an endless iterator of pre-rendered frames, where each frame advances both the
+/// frame text and the style taken from `palette`. Use [`Gradient::steps`](crate::Gradient::steps)
+/// to cycle through a gradient.
+///
+/// The iterator never ends on its own (unless there are no frames), so callers driving their
+/// own animation loop should use [`Iterator::take`] or stop iterating when they are done.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{cycle_frames, Ansi};
+/// let mut frames = cycle_frames(["|", "/", "-", "\\"], [Ansi::red(), Ansi::blue()]);
+/// assert_eq!(frames.next().unwrap(), "\x1b[38;2;255;0;0m|\x1b[0m");
+/// assert_eq!(frames.next().unwrap(), "\x1b[38;2;0;0;255m/\x1b[0m");
+/// assert_eq!(frames.next().unwrap(), "\x1b[38;2;255;0;0m-\x1b[0m");
+/// ```
+pub fn cycle_frames<S: IntoAnsi>(
+    frames: impl Into<Frames>,
+    palette: impl IntoIterator<Item = S>,
+) -> CycleFrames {
+    CycleFrames {
+        frames: frames.into(),
+        palette: palette.into_iter().map(IntoAnsi::into_ansi).collect(),
+        index: 0,
+    }
+}
+
+/// Iterator returned by [`cycle_frames`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleFrames {
+    frames: Frames,
+    palette: Vec<Ansi>,
+    index: usize,
+}
+
+impl CycleFrames {
+    /// Gets the number of frames it takes for both the text and the style to return to
+    /// where they started.
+    #[must_use]
+    pub fn period(&self) -> usize {
+        fn gcd(a: usize, b: usize) -> usize {
+            if b == 0 {
+                a
+            } else {
+                gcd(b, a % b)
+            }
+        }
+
+        let frames = self.frames.0.len();
+        let styles = self.palette.len().max(1);
+        if frames == 0 {
+            return 0;
+        }
+        frames / gcd(frames, styles) * styles
+    }
+}
+
+impl Iterator for CycleFrames {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.frames.0.is_empty() {
+            return None;
+        }
+
+        let text = &self.frames.0[self.index % self.frames.0.len()];
+        let style = if self.palette.is_empty() {
+            Ansi::new()
+        } else {
+            self.palette[self.index % self.palette.len()]
+        };
+        self.index = self.index.wrapping_add(1);
+
+        Some(style_text(text, style))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Gradient;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn single_text() {
+        let gradient = Gradient::new((0, 0, 0), (255, 255, 255));
+        let frames = cycle_frames("Loading", gradient.steps(2))
+            .take(3)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            frames,
+            vec![
+                "\u{1b}[38;2;0;0;0mLoading\u{1b}[0m".to_string(),
+                "\u{1b}[38;2;255;255;255mLoading\u{1b}[0m".to_string(),
+                "\u{1b}[38;2;0;0;0mLoading\u{1b}[0m".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn period() {
+        let frames = cycle_frames(vec!["a", "b", "c", "d"], [Ansi::red(), Ansi::blue()]);
+        assert_eq!(frames.period(), 4);
+        let frames = cycle_frames(vec!["a", "b", "c"], [Ansi::red(), Ansi::blue()]);
+        assert_eq!(frames.period(), 6);
+        let frames = cycle_frames(Vec::<String>::new(), [Ansi::red()]);
+        assert_eq!(frames.period(), 0);
+    }
+
+    #[test]
+    fn empty_inputs() {
+        let mut frames = cycle_frames(Vec::<String>::new(), [Ansi::red()]);
+        assert_eq!(frames.next(), None);
+        let mut frames = cycle_frames("x", Vec::<Ansi>::new());
+        assert_eq!(frames.next(), Some("x".to_string()));
+        assert_eq!(frames.next(), Some("x".to_string()));
+    }
+}
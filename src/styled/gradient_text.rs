@@ -0,0 +1,81 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::buffer::StyledBuffer;
+use crate::{Ansi, Ramp};
+
+/// Styles each character of `text` by its position along `style`, so a
+/// [`Gradient`](crate::Gradient) sweeps across the text from its first to its last character.
+/// Escape sequences already in `text` are treated as text, so `text` should be plain.
+///
+/// Neighbouring characters that get the same style are written as a single run, with one
+/// escape sequence, so slow gradients over long text stay compact.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{gradient_text, Ansi, Gradient};
+/// let rainbow = Gradient::new((255, 0, 0), (0, 0, 255)).then((0, 255, 0));
+/// println!("{}", gradient_text("Hello, world!", &rainbow));
+///
+/// assert_eq!(gradient_text("abc", Ansi::new().bold()), "\x1b[1mabc\x1b[0m");
+/// ```
+#[allow(clippy::cast_precision_loss)]
+#[must_use]
+pub fn gradient_text(text: &str, style: impl Ramp) -> String {
+    let count = text.chars().count();
+    let position = |idx: usize| {
+        if count > 1 {
+            idx as f32 / (count - 1) as f32
+        } else {
+            0.0
+        }
+    };
+
+    let mut buffer = StyledBuffer::new();
+    let mut run_start = 0;
+    let mut run_style = Ansi::new();
+    for (idx, (offset, _)) in text.char_indices().enumerate() {
+        let style = style.style_at(position(idx));
+        if idx == 0 {
+            run_style = style;
+        } else if style != run_style {
+            buffer.push(&text[run_start..offset], run_style);
+            run_start = offset;
+            run_style = style;
+        }
+    }
+    buffer.push(&text[run_start..], run_style);
+    buffer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnsiTokenizer, Gradient, Token};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn runs() {
+        let gradient = Gradient::new((0, 0, 0), (2, 2, 2));
+        assert_eq!(
+            gradient_text("aaabbbccc", &gradient),
+            "\x1b[38;2;0;0;0maa\x1b[0;38;2;1;1;1mabbb\x1b[0;38;2;2;2;2mccc\x1b[0m"
+        );
+        assert_eq!(gradient_text("", &gradient), "");
+        assert_eq!(gradient_text("x", &gradient), "\x1b[38;2;0;0;0mx\x1b[0m");
+    }
+
+    #[test]
+    fn long_text_is_compact() {
+        let text = "lorem ipsum dolor sit amet ".repeat(20);
+        let styled = gradient_text(&text, Gradient::new((0, 0, 0), (0, 0, 40)));
+        let escapes = AnsiTokenizer::new(&styled)
+            .filter(|token| !matches!(token, Token::Text(_)))
+            .count();
+        assert_eq!(escapes, 42);
+        assert_eq!(crate::strip_ansi(&styled), text);
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::buffer::StyledBuffer;
+use crate::IntoAnsi;
+
+/// Joins `items` with `separator`, styling the items with `item_style` and the separators
+/// with `sep_style`. Every change between the two styles is a single escape sequence that
+/// resets the previous style and sets the next, or just a reset when the next style is the
+/// default, so an unstyled separator is preceded by a reset after every item. When both
+/// styles are the same, the whole output is styled once, with a single trailing reset.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{styled_join, Ansi};
+/// let tags = styled_join(["rust", "cli"], ", ", Ansi::new().bold(), Ansi::new());
+/// assert_eq!(tags, "\x1b[1mrust\x1b[0m, \x1b[1mcli\x1b[0m");
+/// ```
+pub fn styled_join<I>(
+    items: I,
+    separator: impl std::fmt::Display,
+    item_style: impl IntoAnsi,
+    sep_style: impl IntoAnsi,
+) -> String
+where
+    I: IntoIterator,
+    I::Item: std::fmt::Display,
+{
+    let item_style = item_style.into_ansi();
+    let sep_style = sep_style.into_ansi();
+    let separator = separator.to_string();
+
+    let mut buffer = StyledBuffer::new();
+    for (idx, item) in items.into_iter().enumerate() {
+        if idx > 0 {
+            buffer.push(&separator, sep_style);
+        }
+        buffer.push(&item.to_string(), item_style);
+    }
+
+    buffer.finish()
+}
+
+/// Trait used to add a `styled_join` "extension method" to any iterator whose items
+/// implement [`Display`](std::fmt::Display), as a convenience to call [`styled_join`].
+pub trait StyledJoin: Iterator {
+    /// Join the items of this iterator using the given separator and styles.
+    fn styled_join(
+        self,
+        separator: impl std::fmt::Display,
+        item_style: impl IntoAnsi,
+        sep_style: impl IntoAnsi,
+    ) -> String;
+}
+
+impl<I> StyledJoin for I
+where
+    I: Iterator,
+    I::Item: std::fmt::Display,
+{
+    fn styled_join(
+        self,
+        separator: impl std::fmt::Display,
+        item_style: impl IntoAnsi,
+        sep_style: impl IntoAnsi,
+    ) -> String {
+        styled_join(self, separator, item_style, sep_style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Ansi;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn join_with_styles() {
+        let joined = styled_join(
+            ["a", "b", "c"],
+            ", ",
+            Ansi::from_fg((0, 255, 0)),
+            Ansi::new().italic(),
+        );
+        assert_eq!(
+            joined,
+            "\u{1b}[38;2;0;255;0ma\u{1b}[0;3m, \u{1b}[0;38;2;0;255;0mb\u{1b}[0;3m, \u{1b}[0;38;2;0;255;0mc\u{1b}[0m"
+        );
+    }
+
+    #[test]
+    fn same_styles_merge() {
+        let style = Ansi::new().bold();
+        let joined = ["a", "b"].iter().styled_join("|", style, style);
+        assert_eq!(joined, "\u{1b}[1ma|b\u{1b}[0m");
+    }
+
+    #[test]
+    fn unstyled_and_empty() {
+        assert_eq!(styled_join(1..=3, "-", Ansi::new(), Ansi::new()), "1-2-3");
+        assert_eq!(
+            styled_join(Vec::<String>::new(), ", ", Ansi::red(), Ansi::red()),
+            ""
+        );
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::buffer::StyledBuffer;
+use crate::{visible_width, Ansi, IntoAnsi};
+
+/// Renders `pairs` as `key: value` lines with the values aligned in one column, styling the
+/// keys (and their colons) with `key_style` and the values with `value_style`. Keys are padded
+/// to the visible width of the longest one, and every further line of a multi-line value is
+/// indented to the value column. Empty lines are not padded and there is no trailing newline.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{kv_list, strip_ansi, Ansi};
+/// let info = kv_list(
+///     [("name", "ansirs"), ("version", "0.1.7"), ("authors", "Tony\nothers")],
+///     Ansi::new().bold(),
+///     Ansi::new(),
+/// );
+/// assert_eq!(
+///     strip_ansi(&info),
+///     "name:    ansirs\nversion: 0.1.7\nauthors: Tony\n         others"
+/// );
+/// ```
+pub fn kv_list<I, K, V>(pairs: I, key_style: impl IntoAnsi, value_style: impl IntoAnsi) -> String
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: std::fmt::Display,
+    V: std::fmt::Display,
+{
+    let key_style = key_style.into_ansi();
+    let value_style = value_style.into_ansi();
+    let pairs: Vec<(String, String)> = pairs
+        .into_iter()
+        .map(|(key, value)| (format!("{key}:"), value.to_string()))
+        .collect();
+
+    let column = pairs
+        .iter()
+        .map(|(key, _)| visible_width(key))
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let indent = " ".repeat(column);
+
+    let mut buffer = StyledBuffer::new();
+    for (idx, (key, value)) in pairs.iter().enumerate() {
+        if idx > 0 {
+            buffer.push("\n", Ansi::new());
+        }
+        buffer.push(key, key_style);
+        for (line_idx, line) in value.split('\n').enumerate() {
+            let padding = if line_idx == 0 {
+                &indent[visible_width(key)..]
+            } else {
+                buffer.push("\n", Ansi::new());
+                &indent
+            };
+            // Empty lines are not padded, so they carry no trailing whitespace.
+            if !line.is_empty() {
+                buffer.push(padding, Ansi::new());
+                buffer.push(line, value_style);
+            }
+        }
+    }
+    buffer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn alignment() {
+        let styled_key = Ansi::red().paint_text("id");
+        let out = kv_list(
+            [(styled_key.as_str(), "1"), ("path", "a\n\nb")],
+            Ansi::new(),
+            Ansi::new(),
+        );
+        assert_eq!(out, format!("{styled_key}:   1\npath: a\n\n      b"));
+        assert_eq!(
+            kv_list(Vec::<(&str, &str)>::new(), Ansi::red(), Ansi::red()),
+            ""
+        );
+    }
+
+    #[test]
+    fn styles() {
+        let out = kv_list(
+            [("k", 1), ("key", 2)],
+            Ansi::new().bold(),
+            Ansi::new().dim(),
+        );
+        assert_eq!(
+            out,
+            "\x1b[1mk:\x1b[0m   \x1b[2m1\x1b[0m\n\x1b[1mkey:\x1b[0m \x1b[2m2\x1b[0m"
+        );
+    }
+}
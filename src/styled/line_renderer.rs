@@ -0,0 +1,220 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::fmt::Write as _;
+
+use super::width::columns;
+use crate::{Ansi, AnsiTokenizer, Token};
+
+/// Largest number of unchanged columns between two changes that are written again instead of
+/// moving the cursor over them, which takes about as many bytes.
+const MERGE_GAP: usize = 3;
+
+/// Redraws a line that changes often, e.g. a status bar, by only writing the cells that differ
+/// from the previously rendered line, instead of the whole line.
+///
+/// Each call to [`render`](LineRenderer::render) returns the escape sequences and text that turn
+/// the previous line into the new one: the cursor is moved to each changed part with an
+/// absolute column (`ESC[nG`), styles are switched with the shortest SGR sequence, and the rest
+/// of the old line is erased when the new one is shorter. The cursor must be on the line, but
+/// can be at any column. The output always ends with the default style, and the cursor after
+/// the last written cell.
+///
+/// Only SGR styling is kept from the input. Other escape sequences (e.g. hyperlinks) and
+/// control characters are dropped, since they take up no columns.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi, LineRenderer};
+/// let mut renderer = LineRenderer::new();
+/// assert_eq!(renderer.render("load 0.50"), "\x1b[Gload 0.50\x1b[K");
+/// assert_eq!(renderer.render("load 0.75"), "\x1b[8G75");
+///
+/// let busy = format!("load {}", Ansi::red().paint_text("1.75"));
+/// assert_eq!(renderer.render(&busy), "\x1b[6G\x1b[38;2;255;0;0m1.75\x1b[0m");
+/// assert_eq!(renderer.render(&busy), "");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineRenderer {
+    /// The cells of the previous line, one per column, or `None` if it is not known.
+    previous: Option<Vec<Cell>>,
+}
+
+/// One column of a line. The second column of a wide character has empty text and width `0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cell {
+    text: String,
+    style: Ansi,
+    width: usize,
+}
+
+impl LineRenderer {
+    /// Creates a new [`LineRenderer`]. The first line it renders is written in full.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forgets the previous line, so the next line is written in full, e.g. after the screen
+    /// was cleared or other output was printed over the line.
+    pub fn invalidate(&mut self) {
+        self.previous = None;
+    }
+
+    /// Gets the output that turns the previously rendered line into `line`, and remembers
+    /// `line` for the next call. The output is empty when nothing changed.
+    #[must_use]
+    pub fn render(&mut self, line: &str) -> String {
+        let cells = parse_cells(line);
+        let (previous, known) = match &self.previous {
+            Some(previous) => (previous.as_slice(), true),
+            None => ([].as_slice(), false),
+        };
+        let changed = |column: usize| previous.get(column) != cells.get(column);
+
+        let mut out = String::new();
+        let mut style = Ansi::new();
+        let mut cursor = None;
+        let mut column = 0;
+        while column < cells.len() {
+            if !changed(column) {
+                column += 1;
+                continue;
+            }
+
+            let mut start = column;
+            while start > 0 && cells[start].width == 0 {
+                start -= 1;
+            }
+            let mut end = column + 1;
+            let mut probe = end;
+            while probe < cells.len() && probe - end <= MERGE_GAP {
+                if changed(probe) {
+                    end = probe + 1;
+                }
+                probe += 1;
+            }
+
+            if cursor != Some(start) {
+                move_to(&mut out, start);
+            }
+            let mut position = start;
+            for cell in cells[start..end].iter().filter(|cell| cell.width > 0) {
+                set_style(&mut out, &mut style, cell.style);
+                out.push_str(&cell.text);
+                position += cell.width;
+            }
+            cursor = Some(position);
+            column = position;
+        }
+
+        if !known || cells.len() < previous.len() {
+            set_style(&mut out, &mut style, Ansi::new());
+            if cursor != Some(cells.len()) {
+                move_to(&mut out, cells.len());
+            }
+            out.push_str("\x1b[K");
+        }
+        set_style(&mut out, &mut style, Ansi::new());
+
+        self.previous = Some(cells);
+        out
+    }
+}
+
+/// Splits a styled line into cells, one per column.
+fn parse_cells(line: &str) -> Vec<Cell> {
+    let mut cells = Vec::new();
+    let mut active = Ansi::new();
+    for token in AnsiTokenizer::new(line) {
+        let Token::Text(text) = token else {
+            if let Some(params) = token.sgr_params() {
+                active.apply_sgr_params(params);
+            }
+            continue;
+        };
+        for (piece, width) in columns(text).filter(|(_, width)| *width > 0) {
+            cells.push(Cell {
+                text: piece.to_string(),
+                style: active,
+                width,
+            });
+            cells.extend((1..width).map(|_| Cell {
+                text: String::new(),
+                style: active,
+                width: 0,
+            }));
+        }
+    }
+    cells
+}
+
+/// Moves the cursor to the 0 based `column` of the current line.
+fn move_to(out: &mut String, column: usize) {
+    if column == 0 {
+        out.push_str("\x1b[G");
+    } else {
+        write!(out, "\x1b[{}G", column + 1).expect("Failed to write! to string");
+    }
+}
+
+/// Switches from the `current` style to `to`.
+fn set_style(out: &mut String, current: &mut Ansi, to: Ansi) {
+    current
+        .write_transition(&to, out)
+        .expect("Failed to write! to string");
+    *current = to;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn changes() {
+        let mut renderer = LineRenderer::new();
+        assert_eq!(renderer.render(""), "\x1b[G\x1b[K");
+        assert_eq!(renderer.render("abcdefghij"), "\x1b[Gabcdefghij");
+        assert_eq!(renderer.render("aXcdefghiY"), "\x1b[2GX\x1b[10GY");
+        assert_eq!(renderer.render("aYcdZfghiY"), "\x1b[2GYcdZ");
+        assert_eq!(renderer.render("aYc"), "\x1b[4G\x1b[K");
+        assert_eq!(renderer.render("aYcd"), "\x1b[4Gd");
+
+        renderer.invalidate();
+        assert_eq!(renderer.render("aYcd"), "\x1b[GaYcd\x1b[K");
+    }
+
+    #[test]
+    fn styles() {
+        let mut renderer = LineRenderer::new();
+        let bold = Ansi::new().bold();
+        let line = format!("{} ok", bold.paint_text("12"));
+        assert_eq!(renderer.render(&line), "\x1b[G\x1b[1m12\x1b[0m ok\x1b[K");
+
+        let line = format!("{} ok", bold.italic().paint_text("12"));
+        assert_eq!(renderer.render(&line), "\x1b[G\x1b[1;3m12\x1b[0m");
+        let line = format!(
+            "{}{}",
+            bold.italic().paint_text("12"),
+            bold.paint_text(" ok")
+        );
+        assert_eq!(renderer.render(&line), "\x1b[3G\x1b[1m ok\x1b[0m");
+        assert_eq!(
+            renderer.render("\x1b[1;3m13\x1b[23m ok\x1b]8;;x\x1b\\"),
+            "\x1b[2G\x1b[1;3m3\x1b[0m"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn wide_characters() {
+        let mut renderer = LineRenderer::new();
+        let _ = renderer.render("a\u{4e2d}b");
+        assert_eq!(renderer.render("a\u{6587}b"), "\x1b[2G\u{6587}");
+        assert_eq!(renderer.render("axyb"), "\x1b[2Gxy");
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::io::BufRead;
+
+use crate::{style_text, Ansi};
+
+/// Iterator adapter that styles each line of its source using a per-line closure.
+///
+/// Created by [`StyleLines::styled_lines`] or [`StyleReadLines::styled_lines`]. When the source
+/// is a `str` the items are [`String`]s, when the source is a reader the items are
+/// [`std::io::Result<String>`] so that read errors can still be handled by the caller.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi, StyleLines};
+/// let output = "ok\nerror: bad thing\nok";
+/// let lines = output
+///     .styled_lines(|line| {
+///         if line.starts_with("error") {
+///             Ansi::red()
+///         } else {
+///             Ansi::new()
+///         }
+///     })
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(lines[0], "ok");
+/// assert_eq!(lines[1], "\x1b[38;2;255;0;0merror: bad thing\x1b[0m");
+/// ```
+pub struct StyledLines<I, F> {
+    lines: I,
+    style: F,
+}
+
+impl<I, F> StyledLines<I, F> {
+    /// Creates a new [`StyledLines`] from an iterator of lines and the closure used to style them.
+    pub fn new(lines: I, style: F) -> Self {
+        Self { lines, style }
+    }
+}
+
+impl<F> Iterator for StyledLines<std::str::Lines<'_>, F>
+where
+    F: FnMut(&str) -> Ansi,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        Some(style_text(line, (self.style)(line)))
+    }
+}
+
+impl<R, F> Iterator for StyledLines<std::io::Lines<R>, F>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Ansi,
+{
+    type Item = std::io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.lines.next()?;
+        Some(line.map(|line| {
+            let style = (self.style)(&line);
+            style_text(line, style)
+        }))
+    }
+}
+
+/// Trait used to add a `styled_lines` "extension method" to string slices.
+pub trait StyleLines {
+    /// Splits this text into lines and styles each one using the [`Ansi`] returned by `style`.
+    fn styled_lines<F>(&self, style: F) -> StyledLines<std::str::Lines<'_>, F>
+    where
+        F: FnMut(&str) -> Ansi;
+}
+
+impl StyleLines for str {
+    fn styled_lines<F>(&self, style: F) -> StyledLines<std::str::Lines<'_>, F>
+    where
+        F: FnMut(&str) -> Ansi,
+    {
+        StyledLines::new(self.lines(), style)
+    }
+}
+
+/// Trait used to add a `styled_lines` "extension method" to any [`BufRead`] reader, which
+/// makes it easy to restyle the output of a child process line by line.
+pub trait StyleReadLines: BufRead + Sized {
+    /// Reads this reader line by line and styles each one using the [`Ansi`] returned by `style`.
+    fn styled_lines<F>(self, style: F) -> StyledLines<std::io::Lines<Self>, F>
+    where
+        F: FnMut(&str) -> Ansi,
+    {
+        StyledLines::new(self.lines(), style)
+    }
+}
+
+impl<R: BufRead> StyleReadLines for R {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn trace_style(line: &str) -> Ansi {
+        if line.starts_with(' ') {
+            Ansi::new().italic()
+        } else {
+            Ansi::red()
+        }
+    }
+
+    #[test]
+    fn str_lines() {
+        let text = "panicked at 'oops'\n  at main.rs:10\n  at lib.rs:20";
+        let lines = text.styled_lines(trace_style).collect::<Vec<_>>();
+        assert_eq!(
+            lines,
+            vec![
+                "\u{1b}[38;2;255;0;0mpanicked at 'oops'\u{1b}[0m".to_string(),
+                "\u{1b}[3m  at main.rs:10\u{1b}[0m".to_string(),
+                "\u{1b}[3m  at lib.rs:20\u{1b}[0m".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn reader_lines() {
+        let reader = std::io::Cursor::new("first\n  second\n");
+        let lines = reader
+            .styled_lines(trace_style)
+            .collect::<std::io::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            lines,
+            vec![
+                "\u{1b}[38;2;255;0;0mfirst\u{1b}[0m".to_string(),
+                "\u{1b}[3m  second\u{1b}[0m".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn unstyled_lines_are_untouched() {
+        let text = String::from("a\n\nb");
+        let lines = text.styled_lines(|_| Ansi::new()).collect::<Vec<_>>();
+        assert_eq!(lines, vec!["a", "", "b"]);
+    }
+}
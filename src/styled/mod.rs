@@ -4,8 +4,56 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::borrow::Cow;
+use std::io::IsTerminal;
+
 use crate::{Ansi, IntoAnsi};
 
+mod banner;
+mod buffer;
+mod char_styles;
+mod context;
+mod diff;
+mod frames;
+mod gradient_text;
+mod join;
+mod kv_list;
+mod line_renderer;
+mod lines;
+mod number;
+mod painted;
+mod progress;
+mod rules;
+mod status;
+mod stream;
+mod theme;
+mod tree;
+mod width;
+
+pub use banner::{banner, fill_line};
+pub use char_styles::CharStyles;
+pub use context::{
+    bright_policy, code_order, default_style, flush_policy, line_ending, reset_policy,
+    styles_enabled, suppress_styles, with_bright_policy, with_code_order, with_default_style,
+    with_flush_policy, with_line_ending, with_reset_policy, without_styles, StyleSuppression,
+};
+pub use diff::{diff_lines, DiffLines};
+pub use frames::{cycle_frames, CycleFrames, Frames};
+pub use gradient_text::gradient_text;
+pub use join::{styled_join, StyledJoin};
+pub use kv_list::kv_list;
+pub use line_renderer::LineRenderer;
+pub use lines::{StyleLines, StyleReadLines, StyledLines};
+pub use number::{number, StyledNumber};
+pub use painted::Painted;
+pub use progress::progress_bar;
+pub use rules::StyleRules;
+pub use status::{status, StyledOption, StyledResult};
+pub use stream::{stderr, stdout, AnsiWriter, ColorChoice, ColorSupport, FlushPolicy, LineEnding};
+pub use theme::{Theme, STYLE_ENV_PREFIX};
+pub use tree::Tree;
+pub use width::{slice_visible, strip_ansi, terminal_width, visible_width};
+
 /// `string` Module
 ///
 /// This module contains some experiments with storing a formatted string, i.e. a
@@ -23,7 +71,7 @@ pub fn style_text<S: IntoAnsi>(text: impl std::fmt::Display, style: S) -> String
     if actual.is_empty() {
         actual
     } else {
-        let ansi: Ansi = style.into_ansi();
+        let ansi: Ansi = context::resolve(style.into_ansi());
         #[cfg(feature = "trace")]
         {
             let style = format!("{ansi:?}");
@@ -32,25 +80,84 @@ pub fn style_text<S: IntoAnsi>(text: impl std::fmt::Display, style: S) -> String
         if ansi.is_default() {
             actual
         } else {
-            format!("{}{}{}", ansi, text, Ansi::reset())
+            crate::with_sequence(ansi, |sequence| {
+                format!("{sequence}{actual}{}", reset_policy().end(ansi))
+            })
         }
     }
 }
 
-/// Shortcut to call `print!` with the output of `style_text`.
+/// Like [`style_text`], but for text that is already a string. When no styling is applied
+/// (the style is the default, or the text is empty) the text is returned as it was given,
+/// without allocating or copying.
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use ansirs::{style_text_cow, Ansi};
+/// assert!(matches!(style_text_cow("plain", Ansi::new()), Cow::Borrowed("plain")));
+/// assert_eq!(style_text_cow("bold", Ansi::new().bold()), "\x1b[1mbold\x1b[0m");
+/// ```
+pub fn style_text_cow<'a, S: IntoAnsi>(text: impl Into<Cow<'a, str>>, style: S) -> Cow<'a, str> {
+    let text = text.into();
+    if text.is_empty() {
+        return text;
+    }
+
+    let ansi = context::resolve(style.into_ansi());
+    if ansi.is_default() {
+        text
+    } else {
+        Cow::Owned(crate::with_sequence(ansi, |sequence| {
+            format!("{sequence}{text}{}", reset_policy().end(ansi))
+        }))
+    }
+}
+
+/// Styles `text` (e.g. a token or password) as concealed for stdout, see
+/// [`ColorSupport::secret`]. Unless stdout is a terminal that gets styled output, the text is
+/// masked with one `*` per column instead, so the secret never ends up in a file, log or CI
+/// output where the conceal attribute would be ignored.
+///
+/// Use [`AnsiWriter::secret`] for text written to other streams.
+///
+/// ```
+/// # use ansirs::{secret, without_styles};
+/// assert_eq!(without_styles(|| secret("hunter2")), "*******");
+/// ```
+#[must_use]
+pub fn secret(text: &str) -> String {
+    ColorSupport::detect(std::io::stdout().is_terminal()).secret(text)
+}
+
+/// Shortcut to call `print!` with the output of `style_text`. Newlines are written with the
+/// [`line_ending`] of the current thread, and stdout is flushed according to its
+/// [`flush_policy`].
 pub fn styled_print<S: IntoAnsi>(text: impl std::fmt::Display, style: S) {
-    print!("{}", style_text(text, style));
+    print_lines(style_text(text, style));
 }
 
-/// Shortcut to call `println!` with the output of `style_text`.
+/// Shortcut to call `println!` with the output of `style_text`. The line ends with the
+/// [`line_ending`] of the current thread, and stdout is flushed according to its
+/// [`flush_policy`].
 #[cfg_attr(feature = "trace", tracing::instrument(skip(text, style), fields(text = %text, styled)))]
 pub fn styled_println<S: IntoAnsi>(text: impl std::fmt::Display, style: S) {
-    let styled = style_text(text, style);
+    let mut styled = style_text(text, style);
     #[cfg(feature = "trace")]
     {
         tracing::Span::current().record("styled", styled.as_str());
     }
-    println!("{styled}");
+    styled.push('\n');
+    print_lines(styled);
+}
+
+/// Prints styled text with the line ending and flush policy of the current thread.
+fn print_lines(styled: String) {
+    let styled = line_ending().apply(styled);
+    print!("{styled}");
+    if flush_policy().flushes(&styled) {
+        // `print!` panics when stdout can not be written to, a failed flush is ignored.
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+    }
 }
 
 /// Trait used to add a `style` "extension method" to any type that implements [`Display`](std::fmt::Display)
@@ -94,6 +201,31 @@ pub trait StyledString {
     /// Checks if the original / **visible** text is empty
     #[must_use]
     fn is_empty(&self) -> bool;
+
+    /// Gets the part of the formatted value that covers the visible columns in `range`, still
+    /// styled. See [`slice_visible`] for how styling and wide characters are handled.
+    #[must_use]
+    fn slice_visible(&self, range: impl std::ops::RangeBounds<usize>) -> String
+    where
+        Self: Sized,
+    {
+        slice_visible(&self.value(), range)
+    }
+
+    /// Writes the formatted value of this [`StyledString`] to `out` as bytes, without building
+    /// it as a [`String`] first. See [`Ansi::write_bytes`].
+    ///
+    /// ## Errors
+    /// Any error from writing to `out`.
+    fn write_bytes(&self, out: &mut impl std::io::Write) -> std::io::Result<()>
+    where
+        Self: Sized,
+    {
+        match self.style() {
+            Some(style) => style.write_bytes(out, self.raw().as_bytes()),
+            None => out.write_all(self.raw().as_bytes()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -101,6 +233,39 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn secrets() {
+        let terminal = ColorSupport::Ansi16;
+        assert_eq!(terminal.secret(""), "");
+        assert_eq!(terminal.secret("abc"), "\x1b[8mabc\x1b[0m");
+        assert_eq!(ColorSupport::None.secret("abc"), "***");
+        let _plain = suppress_styles();
+        assert_eq!(terminal.secret("pässwörd"), "********");
+        assert_eq!(secret("pässwörd"), "********");
+        assert_eq!(secret(""), "");
+    }
+
+    #[test]
+    fn style_text_cow_borrows_when_unstyled() {
+        assert!(matches!(
+            style_text_cow("text", Ansi::new()),
+            Cow::Borrowed("text")
+        ));
+        assert!(matches!(style_text_cow("", Ansi::red()), Cow::Borrowed("")));
+
+        let owned = String::from("text");
+        let ptr = owned.as_ptr();
+        let Cow::Owned(same) = style_text_cow(owned, Ansi::new()) else {
+            panic!("Expected the owned string back");
+        };
+        assert_eq!(same.as_ptr(), ptr);
+
+        assert_eq!(
+            style_text_cow(String::from("x"), Ansi::red()),
+            style_text("x", Ansi::red())
+        );
+    }
+
     const DISPLAY_PRE: &str = "\u{1b}[";
     const DISPLAY_SUF: &str = "m";
 
@@ -164,10 +329,13 @@ mod tests {
             style.strike()
         });
         // Why the fuck cant i get this to work in another project.
-        let _styled_colors = style_text(&first, crate::Colors::Yellow.into_ansi());
-        let _styled_colors = style_text(&first, crate::Colors::Yellow);
-        let yellow = crate::Colors::Yellow.into_color();
-        let _styled_color = style_text(&first, yellow.into_ansi());
+        #[cfg(feature = "named-colors")]
+        {
+            let _styled_colors = style_text(&first, crate::Colors::Yellow.into_ansi());
+            let _styled_colors = style_text(&first, crate::Colors::Yellow);
+            let yellow = crate::Colors::Yellow.into_color();
+            let _styled_color = style_text(&first, yellow.into_ansi());
+        }
 
         let manual_prefix = format!(
             "{}{}{}",
@@ -0,0 +1,206 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{style_text, Ansi, IntoAnsi};
+
+/// Creates a [`StyledNumber`] for `value`, which is styled based on its sign: green for
+/// positive values, red for negative values and dimmed for zero.
+///
+/// ## Example
+/// ```
+/// # use ansirs::styled;
+/// let balance = styled::number(-1234567).thousands(',');
+/// assert_eq!(balance.to_string(), "\x1b[38;2;255;0;0m-1,234,567\x1b[0m");
+/// ```
+pub fn number<T>(value: T) -> StyledNumber<T>
+where
+    T: std::fmt::Display + PartialOrd + Default,
+{
+    StyledNumber::new(value)
+}
+
+/// A number that is styled based on its sign when displayed. Created by [`number`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StyledNumber<T> {
+    value: T,
+    positive: Ansi,
+    negative: Ansi,
+    zero: Ansi,
+    separator: Option<char>,
+    precision: Option<usize>,
+}
+
+impl<T> StyledNumber<T>
+where
+    T: std::fmt::Display + PartialOrd + Default,
+{
+    /// Creates a new [`StyledNumber`] using the default styles.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            positive: Ansi::green(),
+            negative: Ansi::red(),
+            zero: Ansi::new().dim(),
+            separator: None,
+            precision: None,
+        }
+    }
+
+    /// Builder function to set the style used for positive values.
+    #[must_use]
+    pub fn positive(self, style: impl IntoAnsi) -> Self {
+        Self {
+            positive: style.into_ansi(),
+            ..self
+        }
+    }
+
+    /// Builder function to set the style used for negative values.
+    #[must_use]
+    pub fn negative(self, style: impl IntoAnsi) -> Self {
+        Self {
+            negative: style.into_ansi(),
+            ..self
+        }
+    }
+
+    /// Builder function to set the style used for zero (and values that cannot be
+    /// compared, like `NaN`).
+    #[must_use]
+    pub fn zero(self, style: impl IntoAnsi) -> Self {
+        Self {
+            zero: style.into_ansi(),
+            ..self
+        }
+    }
+
+    /// Builder function to group the integer digits in thousands using `separator`.
+    #[must_use]
+    pub fn thousands(self, separator: char) -> Self {
+        Self {
+            separator: Some(separator),
+            ..self
+        }
+    }
+
+    /// Builder function to set the number of decimal places that are displayed. This has
+    /// no effect on integer values.
+    #[must_use]
+    pub fn precision(self, precision: usize) -> Self {
+        Self {
+            precision: Some(precision),
+            ..self
+        }
+    }
+
+    /// Gets the style that will be used for the current value.
+    #[must_use]
+    pub fn current_style(&self) -> Ansi {
+        let zero = T::default();
+        if self.value > zero {
+            self.positive
+        } else if self.value < zero {
+            self.negative
+        } else {
+            self.zero
+        }
+    }
+
+    /// Gets the unstyled, formatted text of the current value.
+    #[must_use]
+    pub fn text(&self) -> String {
+        let text = match self.precision {
+            Some(precision) => format!("{:.precision$}", self.value),
+            None => self.value.to_string(),
+        };
+
+        match self.separator {
+            Some(separator) => group_thousands(&text, separator),
+            None => text,
+        }
+    }
+}
+
+impl<T> std::fmt::Display for StyledNumber<T>
+where
+    T: std::fmt::Display + PartialOrd + Default,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", style_text(self.text(), self.current_style()))
+    }
+}
+
+/// Inserts `separator` between every group of three digits in the integer part of `text`.
+fn group_thousands(text: &str, separator: char) -> String {
+    let (sign, rest) = match text.strip_prefix(['-', '+']) {
+        Some(rest) => text.split_at(text.len() - rest.len()),
+        None => ("", text),
+    };
+    let digits_end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (digits, tail) = rest.split_at(digits_end);
+
+    let mut grouped = String::with_capacity(text.len() + digits.len() / 3);
+    grouped.push_str(sign);
+    for (idx, c) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+    grouped.push_str(tail);
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn default_styles() {
+        assert_eq!(
+            number(5).to_string(),
+            "\u{1b}[38;2;0;255;0m5\u{1b}[0m".to_string()
+        );
+        assert_eq!(
+            number(-5).to_string(),
+            "\u{1b}[38;2;255;0;0m-5\u{1b}[0m".to_string()
+        );
+        assert_eq!(number(0).to_string(), "\u{1b}[2m0\u{1b}[0m".to_string());
+        assert_eq!(number(0.0).to_string(), "\u{1b}[2m0\u{1b}[0m".to_string());
+    }
+
+    #[test]
+    fn custom_styles() {
+        let n = number(12.5)
+            .positive(Ansi::new().bold())
+            .negative(Ansi::new())
+            .zero(Ansi::new());
+        assert_eq!(n.to_string(), "\u{1b}[1m12.5\u{1b}[0m");
+        assert_eq!(n.current_style(), Ansi::new().bold());
+        assert_eq!(number(-3).negative(Ansi::new()).to_string(), "-3");
+    }
+
+    #[test]
+    fn thousands() {
+        assert_eq!(number(1_234_567).thousands(',').text(), "1,234,567");
+        assert_eq!(number(-123_456).thousands(',').text(), "-123,456");
+        assert_eq!(number(123).thousands(',').text(), "123");
+        assert_eq!(
+            number(-9_876_543.256).thousands('_').precision(2).text(),
+            "-9_876_543.26"
+        );
+        assert_eq!(number(1000.5).thousands(' ').text(), "1 000.5");
+    }
+
+    #[test]
+    fn precision() {
+        assert_eq!(number(1.0 / 3.0).precision(3).text(), "0.333");
+        assert_eq!(number(42).precision(3).text(), "42");
+    }
+}
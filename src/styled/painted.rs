@@ -0,0 +1,221 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::{context::reset_policy, width::write_padded};
+use crate::{Ansi, IntoAnsi};
+
+/// A value together with the style it should be displayed with.
+///
+/// Unlike [`style_text`](crate::style_text), which builds a new [`String`], a [`Painted`]
+/// writes the escape sequence, the value and the reset straight to the formatter, so nothing
+/// is allocated. This is what [`swrite!`](crate::swrite) and [`swriteln!`](crate::swriteln)
+/// are built on.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi, Painted};
+/// let count = Painted::new(42, Ansi::new().bold());
+/// assert_eq!(format!("found {count} items"), "found \x1b[1m42\x1b[0m items");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Painted<T> {
+    value: T,
+    style: Ansi,
+}
+
+impl<T> Painted<T> {
+    /// Creates a new [`Painted`] displaying `value` with `style`.
+    #[must_use]
+    pub fn new<S: IntoAnsi>(value: T, style: S) -> Self {
+        Self {
+            value,
+            style: style.into_ansi(),
+        }
+    }
+
+    /// Gets the value being displayed.
+    #[must_use]
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Gets the style the value is displayed with.
+    #[must_use]
+    pub const fn style(&self) -> Ansi {
+        self.style
+    }
+}
+
+/// Formatting flags are applied to the value rather than to the escape sequences, so `{:>8}`
+/// pads to eight visible columns and `{:+.2}` is handled by the value's own `Display`. Padding
+/// is written outside of the styling, except for `{:06}`, which zero pads the value itself.
+/// Only when a width is given is the value formatted into a temporary [`String`], to measure
+/// it.
+impl<T: std::fmt::Display> std::fmt::Display for Painted<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let style = super::context::resolve(self.style);
+        if f.width().is_some() {
+            let text = format_flags(f, &self.value);
+            return write_padded(f, &text, |f, text| {
+                if style.is_default() {
+                    return f.write_str(text);
+                }
+                style.write_sequence(f)?;
+                f.write_str(text)?;
+                reset_policy().write_end(style, f)
+            });
+        }
+
+        if style.is_default() {
+            return self.value.fmt(f);
+        }
+        style.write_sequence(f)?;
+        self.value.fmt(f)?;
+        reset_policy().write_end(style, f)
+    }
+}
+
+/// Formats `value` with every flag of `f` except its width, fill and alignment, which are left
+/// to [`write_padded`]. A zero padded value is padded to the width here, as only the value
+/// knows where its sign ends.
+fn format_flags(f: &std::fmt::Formatter<'_>, value: &impl std::fmt::Display) -> String {
+    let width = if f.sign_aware_zero_pad() {
+        f.width().unwrap_or(0)
+    } else {
+        0
+    };
+    // Format strings must be literals, so there is one per combination of flags.
+    macro_rules! format_with {
+        ($flags:literal) => {
+            match f.precision() {
+                Some(precision) => format!(
+                    concat!("{:", $flags, "width$.precision$}"),
+                    value,
+                    width = width,
+                    precision = precision
+                ),
+                None => format!(concat!("{:", $flags, "width$}"), value, width = width),
+            }
+        };
+    }
+
+    match (f.sign_plus(), f.alternate(), f.sign_aware_zero_pad()) {
+        (false, false, false) => format_with!(""),
+        (true, false, false) => format_with!("+"),
+        (false, true, false) => format_with!("#"),
+        (true, true, false) => format_with!("+#"),
+        (false, false, true) => format_with!("0"),
+        (true, false, true) => format_with!("+0"),
+        (false, true, true) => format_with!("#0"),
+        (true, true, true) => format_with!("+#0"),
+    }
+}
+
+/// Writes formatted, styled, text into a [`std::fmt::Write`] or [`std::io::Write`], like
+/// [`write!`] but taking a style (anything implementing [`IntoAnsi`](crate::IntoAnsi)) before
+/// the format string.
+///
+/// The escape sequence, the formatted arguments and the reset are written straight into the
+/// writer, without building an intermediate [`String`]. Nothing extra is written when the
+/// style is the default.
+///
+/// ## Example
+/// ```
+/// # use std::fmt::Write;
+/// # use ansirs::{swrite, Ansi};
+/// let mut out = String::new();
+/// swrite!(out, Ansi::new().bold(), "{} of {}", 3, 10).unwrap();
+/// assert_eq!(out, "\x1b[1m3 of 10\x1b[0m");
+/// ```
+#[macro_export]
+macro_rules! swrite {
+    ($dst:expr, $style:expr, $($arg:tt)*) => {
+        $dst.write_fmt(::std::format_args!(
+            "{}",
+            $crate::Painted::new(::std::format_args!($($arg)*), $style)
+        ))
+    };
+}
+
+/// Like [`swrite!`](crate::swrite), but appends a newline after the reset.
+///
+/// ## Example
+/// ```
+/// # use std::io::Write;
+/// # use ansirs::{swriteln, Ansi};
+/// let mut out = Vec::new();
+/// swriteln!(out, Ansi::red(), "error: {}", "oops").unwrap();
+/// assert_eq!(out, b"\x1b[38;2;255;0;0merror: oops\x1b[0m\n");
+/// ```
+#[macro_export]
+macro_rules! swriteln {
+    ($dst:expr, $style:expr, $($arg:tt)*) => {
+        $dst.write_fmt(::std::format_args!(
+            "{}\n",
+            $crate::Painted::new(::std::format_args!($($arg)*), $style)
+        ))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn painted() {
+        let painted = Painted::new("hi", Ansi::new().italic());
+        assert_eq!(painted.to_string(), "\x1b[3mhi\x1b[0m");
+        assert_eq!(*painted.value(), "hi");
+        assert_eq!(painted.style(), Ansi::new().italic());
+        assert_eq!(Painted::new(1.5, Ansi::new()).to_string(), "1.5");
+    }
+
+    #[test]
+    fn formatter_flags() {
+        let painted = Painted::new("abc", Ansi::new().bold());
+        assert_eq!(format!("[{painted:>5}]"), "[  \x1b[1mabc\x1b[0m]");
+        assert_eq!(format!("[{painted:*^6}]"), "[*\x1b[1mabc\x1b[0m**]");
+        assert_eq!(format!("[{painted:4.2}]"), "[\x1b[1mab\x1b[0m  ]");
+        assert_eq!(format!("[{painted:.1}]"), "[\x1b[1ma\x1b[0m]");
+        assert_eq!(format!("[{painted:2}]"), "[\x1b[1mabc\x1b[0m]");
+
+        let number = Painted::new(1.23456, Ansi::new().dim());
+        assert_eq!(format!("{number:>6.2}"), "  \x1b[2m1.23\x1b[0m");
+        assert_eq!(format!("{:<4}|", Painted::new(7, Ansi::new())), "7   |");
+
+        let signed = Painted::new(42, Ansi::new().bold());
+        assert_eq!(format!("{signed:+6}|"), "\x1b[1m+42\x1b[0m   |");
+        assert_eq!(format!("{signed:>+6}"), "   \x1b[1m+42\x1b[0m");
+        assert_eq!(format!("{signed:06}"), "\x1b[1m000042\x1b[0m");
+        assert_eq!(
+            format!("{:+07.1}", Painted::new(-1.25, Ansi::new())),
+            "-0001.2"
+        );
+        assert_eq!(format!("{:#06}", Painted::new(255, Ansi::new())), "000255");
+    }
+
+    #[test]
+    fn macros() {
+        use std::fmt::Write as _;
+
+        let mut out = String::new();
+        swrite!(out, Ansi::new().bold(), "{}", 1).unwrap();
+        swrite!(out, Ansi::new(), " plain ").unwrap();
+        swriteln!(&mut out, || Ansi::new().dim(), "{x:>3}", x = 2).unwrap();
+        assert_eq!(out, "\x1b[1m1\x1b[0m plain \x1b[2m  2\x1b[0m\n");
+    }
+
+    #[test]
+    fn io_writer() {
+        use std::io::Write as _;
+
+        let mut out = Vec::new();
+        swrite!(out, Ansi::red(), "a").unwrap();
+        swriteln!(out, Ansi::new(), "b").unwrap();
+        assert_eq!(out, b"\x1b[38;2;255;0;0ma\x1b[0mb\n");
+    }
+}
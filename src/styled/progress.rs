@@ -0,0 +1,114 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::buffer::StyledBuffer;
+use crate::Ramp;
+
+/// Block characters used for partially filled cells, indexed by eighths.
+const PARTIAL_BLOCKS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+const FULL_BLOCK: char = '█';
+
+/// Renders a progress bar `width` cells wide that is `fraction` (clamped to `0.0..=1.0`)
+/// filled.
+///
+/// Each filled cell is styled by `style` at the cell's position along the **whole** bar, so a
+/// [`Gradient`](crate::Gradient) is revealed as the bar fills up, while a plain
+/// [`Ansi`](crate::Ansi) colors every cell the same. The unfilled part of the bar is padded
+/// with unstyled spaces so the output always has the same visible width, which makes it easy
+/// to redraw using a carriage return.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{progress_bar, Ansi};
+/// let bar = progress_bar(0.5, 4, Ansi::green());
+/// assert_eq!(bar, "\x1b[38;2;0;255;0m██\x1b[0m  ");
+///
+/// // print!("\r[{}]", bar);
+/// ```
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+#[must_use]
+pub fn progress_bar(fraction: f32, width: usize, style: impl Ramp) -> String {
+    let fraction = if fraction.is_nan() {
+        0.0
+    } else {
+        fraction.clamp(0.0, 1.0)
+    };
+    let eighths = (fraction * (width * 8) as f32).round() as usize;
+    let full = eighths / 8;
+    let partial = eighths % 8;
+    let position = |cell: usize| {
+        if width > 1 {
+            cell as f32 / (width - 1) as f32
+        } else {
+            0.0
+        }
+    };
+
+    let mut buffer = StyledBuffer::new();
+    let mut cell_text = [0u8; 4];
+    for cell in 0..full {
+        buffer.push(
+            FULL_BLOCK.encode_utf8(&mut cell_text),
+            style.style_at(position(cell)),
+        );
+    }
+    let mut used = full;
+    if partial > 0 {
+        buffer.push(
+            PARTIAL_BLOCKS[partial].encode_utf8(&mut cell_text),
+            style.style_at(position(full)),
+        );
+        used += 1;
+    }
+
+    let mut bar = buffer.finish();
+    bar.extend(std::iter::repeat_n(' ', width - used));
+    bar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Ansi, Gradient};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty_and_full() {
+        assert_eq!(progress_bar(0.0, 3, Ansi::red()), "   ");
+        assert_eq!(
+            progress_bar(1.0, 3, Ansi::red()),
+            "\u{1b}[38;2;255;0;0m███\u{1b}[0m"
+        );
+        assert_eq!(progress_bar(2.0, 0, Ansi::red()), "");
+        assert_eq!(progress_bar(f32::NAN, 2, Ansi::red()), "  ");
+    }
+
+    #[test]
+    fn partial_blocks() {
+        assert_eq!(
+            progress_bar(0.3, 2, Ansi::new().bold()),
+            "\u{1b}[1m▋\u{1b}[0m "
+        );
+        assert_eq!(progress_bar(0.5, 1, Ansi::new()), "▌");
+    }
+
+    #[test]
+    fn gradient_ramp() {
+        let gradient = Gradient::new((0, 0, 0), (200, 0, 0));
+        assert_eq!(
+            progress_bar(1.0, 3, &gradient),
+            "\u{1b}[38;2;0;0;0m█\u{1b}[0;38;2;100;0;0m█\u{1b}[0;38;2;200;0;0m█\u{1b}[0m"
+        );
+        assert_eq!(
+            progress_bar(0.5, 3, gradient),
+            "\u{1b}[38;2;0;0;0m█\u{1b}[0;38;2;100;0;0m▌\u{1b}[0m "
+        );
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{style_text, Ansi, IntoAnsi};
+
+type Predicate<T> = Box<dyn Fn(&T) -> bool>;
+
+/// An ordered list of `(predicate, style)` pairs used to pick a style based on a value.
+///
+/// Rules are checked in the order they were added and the first matching rule wins. If no
+/// rule matches, the fallback style (empty by default) is used.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi, StyleRules};
+/// let latency = StyleRules::new()
+///     .rule(|ms: &u32| *ms > 500, Ansi::red())
+///     .rule(|ms: &u32| *ms > 200, Ansi::from_fg((255, 255, 0)))
+///     .fallback(Ansi::green());
+///
+/// assert_eq!(latency.style_for(&750), Ansi::red());
+/// assert_eq!(latency.style_for(&300), Ansi::from_fg((255, 255, 0)));
+/// assert_eq!(latency.apply(&20, "20ms"), "\x1b[38;2;0;255;0m20ms\x1b[0m");
+/// ```
+pub struct StyleRules<T: ?Sized> {
+    rules: Vec<(Predicate<T>, Ansi)>,
+    fallback: Ansi,
+}
+
+impl<T: ?Sized> StyleRules<T> {
+    /// Creates a new, empty, set of rules.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            fallback: Ansi::new(),
+        }
+    }
+
+    /// Builder function to add a rule, which will be checked after all previously added rules.
+    #[must_use]
+    pub fn rule(mut self, predicate: impl Fn(&T) -> bool + 'static, style: impl IntoAnsi) -> Self {
+        self.rules.push((Box::new(predicate), style.into_ansi()));
+        self
+    }
+
+    /// Builder function to set the style used when no rule matches.
+    #[must_use]
+    pub fn fallback(self, style: impl IntoAnsi) -> Self {
+        Self {
+            fallback: style.into_ansi(),
+            ..self
+        }
+    }
+
+    /// Gets the style of the first rule matching `value`, or the fallback style.
+    #[must_use]
+    pub fn style_for(&self, value: &T) -> Ansi {
+        self.rules
+            .iter()
+            .find(|(predicate, _)| predicate(value))
+            .map_or(self.fallback, |(_, style)| *style)
+    }
+
+    /// Styles `text` using the style chosen for `value`.
+    #[must_use]
+    pub fn apply(&self, value: &T, text: impl std::fmt::Display) -> String {
+        style_text(text, self.style_for(value))
+    }
+
+    /// Gets the number of rules, not counting the fallback.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Checks if no rules have been added.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+impl<T: ?Sized> Default for StyleRules<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> std::fmt::Debug for StyleRules<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StyleRules")
+            .field(
+                "rules",
+                &self
+                    .rules
+                    .iter()
+                    .map(|(_, style)| style)
+                    .collect::<Vec<_>>(),
+            )
+            .field("fallback", &self.fallback)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn first_match_wins() {
+        let rules = StyleRules::new()
+            .rule(|n: &i32| *n > 10, Ansi::red())
+            .rule(|n: &i32| *n > 5, Ansi::blue());
+        assert_eq!(rules.style_for(&20), Ansi::red());
+        assert_eq!(rules.style_for(&7), Ansi::blue());
+        assert_eq!(rules.style_for(&1), Ansi::new());
+        assert_eq!(rules.apply(&1, "one"), "one");
+        assert_eq!(rules.len(), 2);
+    }
+
+    #[test]
+    fn unsized_values() {
+        let rules = StyleRules::<str>::new()
+            .rule(|s| s.starts_with("ERR"), Ansi::new().bold())
+            .fallback(Ansi::new().dim());
+        assert_eq!(rules.apply("ERR: nope", "x"), "\u{1b}[1mx\u{1b}[0m");
+        assert_eq!(rules.apply("fine", "y"), "\u{1b}[2my\u{1b}[0m");
+    }
+
+    #[test]
+    fn empty() {
+        let rules = StyleRules::<u8>::default();
+        assert!(rules.is_empty());
+        assert_eq!(rules.style_for(&0), Ansi::new());
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{style_text, Ansi, IntoAnsi};
+
+/// Renders a check mark for `ok`, a green `✓` when it is `true` and a red `✗` when it is
+/// `false`, for status columns and check-style output.
+///
+/// ## Example
+/// ```
+/// # use ansirs::styled;
+/// assert_eq!(styled::status(true), "\x1b[38;2;0;255;0m✓\x1b[0m");
+/// assert_eq!(styled::status(false), "\x1b[38;2;255;0;0m✗\x1b[0m");
+/// ```
+#[must_use]
+pub fn status(ok: bool) -> String {
+    if ok {
+        style_text('✓', Ansi::green())
+    } else {
+        style_text('✗', Ansi::red())
+    }
+}
+
+/// Trait used to add a `styled_ok_err` "extension method" to any [`Result`] whose values
+/// implement [`Display`](std::fmt::Display).
+pub trait StyledResult {
+    /// Displays the `Ok` value styled with `ok_style`, or the `Err` value styled with
+    /// `err_style`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Ansi, StyledResult};
+    /// let parsed = "12".parse::<u8>();
+    /// assert_eq!(
+    ///     parsed.styled_ok_err(Ansi::green(), Ansi::red()),
+    ///     "\x1b[38;2;0;255;0m12\x1b[0m"
+    /// );
+    /// ```
+    fn styled_ok_err(&self, ok_style: impl IntoAnsi, err_style: impl IntoAnsi) -> String;
+}
+
+impl<T, E> StyledResult for Result<T, E>
+where
+    T: std::fmt::Display,
+    E: std::fmt::Display,
+{
+    fn styled_ok_err(&self, ok_style: impl IntoAnsi, err_style: impl IntoAnsi) -> String {
+        match self {
+            Ok(value) => style_text(value, ok_style),
+            Err(error) => style_text(error, err_style),
+        }
+    }
+}
+
+/// Trait used to add a `styled_some_none` "extension method" to any [`Option`] whose value
+/// implements [`Display`](std::fmt::Display).
+pub trait StyledOption {
+    /// Displays the `Some` value styled with `some_style`, or `none_text` styled with
+    /// `none_style` when there is no value.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Ansi, StyledOption};
+    /// let owner: Option<&str> = None;
+    /// assert_eq!(
+    ///     owner.styled_some_none(Ansi::new(), Ansi::new().dim(), "-"),
+    ///     "\x1b[2m-\x1b[0m"
+    /// );
+    /// ```
+    fn styled_some_none(
+        &self,
+        some_style: impl IntoAnsi,
+        none_style: impl IntoAnsi,
+        none_text: &str,
+    ) -> String;
+}
+
+impl<T> StyledOption for Option<T>
+where
+    T: std::fmt::Display,
+{
+    fn styled_some_none(
+        &self,
+        some_style: impl IntoAnsi,
+        none_style: impl IntoAnsi,
+        none_text: &str,
+    ) -> String {
+        match self {
+            Some(value) => style_text(value, some_style),
+            None => style_text(none_text, none_style),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn results() {
+        let ok: Result<u8, String> = Ok(1);
+        let err: Result<u8, String> = Err("bad".to_string());
+        assert_eq!(
+            ok.styled_ok_err(Ansi::new().bold(), Ansi::red()),
+            "\x1b[1m1\x1b[0m"
+        );
+        assert_eq!(
+            err.styled_ok_err(Ansi::new().bold(), Ansi::new().italic()),
+            "\x1b[3mbad\x1b[0m"
+        );
+        assert_eq!(err.styled_ok_err(Ansi::new(), Ansi::new()), "bad");
+    }
+
+    #[test]
+    fn options() {
+        assert_eq!(
+            Some(2.5).styled_some_none(Ansi::new().bold(), Ansi::new(), "n/a"),
+            "\x1b[1m2.5\x1b[0m"
+        );
+        assert_eq!(
+            None::<u8>.styled_some_none(Ansi::new().bold(), Ansi::new(), "n/a"),
+            "n/a"
+        );
+    }
+
+    #[test]
+    fn statuses() {
+        assert_eq!(crate::strip_ansi(&status(true)), "✓");
+        assert_eq!(crate::strip_ansi(&status(false)), "✗");
+        assert_eq!(crate::without_styles(|| status(true)), "✓");
+    }
+}
@@ -0,0 +1,535 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::fmt::Display;
+use std::io::{self, IsTerminal, Write};
+
+use super::context::resolve;
+use crate::{reset_policy, AttributeFallback, IntoAnsi};
+
+/// How many colors an output stream can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorSupport {
+    /// No styling at all, e.g. when the output goes to a file.
+    None,
+    /// The 16 basic colors, see [`Ansi16`](crate::Ansi16).
+    Ansi16,
+    /// The 256 indexed colors.
+    Ansi256,
+    /// 24-bit RGB colors.
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Detects the color support of a stream from the environment, given whether the stream is a
+    /// terminal.
+    ///
+    /// - `FORCE_COLOR` or `CLICOLOR_FORCE` (set to anything but `0`) turn colors on even when
+    ///   the stream is not a terminal.
+    /// - Otherwise `NO_COLOR` (set to anything), a stream that is not a terminal, or
+    ///   `TERM=dumb` turn them off.
+    /// - `COLORTERM=truecolor` (or `24bit`) means [`ColorSupport::TrueColor`], a `TERM` ending
+    ///   in `256color` means [`ColorSupport::Ansi256`], anything else
+    ///   [`ColorSupport::Ansi16`].
+    #[must_use]
+    pub fn detect(is_terminal: bool) -> Self {
+        Self::from_env(is_terminal, |name| std::env::var(name).ok())
+    }
+
+    /// Detects the color support using `var` to read environment variables.
+    fn from_env(is_terminal: bool, var: impl Fn(&str) -> Option<String>) -> Self {
+        let forced = ["FORCE_COLOR", "CLICOLOR_FORCE"]
+            .into_iter()
+            .any(|name| var(name).is_some_and(|value| value != "0"));
+        let disabled = var("NO_COLOR").is_some_and(|value| !value.is_empty())
+            || !is_terminal
+            || var("TERM").as_deref() == Some("dumb");
+        if disabled && !forced {
+            return ColorSupport::None;
+        }
+        Self::level(var)
+    }
+
+    /// Gets the number of colors the terminal claims to support, assuming colors are wanted.
+    fn level(var: impl Fn(&str) -> Option<String>) -> Self {
+        let colorterm = var("COLORTERM").unwrap_or_default();
+        if colorterm.eq_ignore_ascii_case("truecolor") || colorterm.eq_ignore_ascii_case("24bit") {
+            ColorSupport::TrueColor
+        } else if var("TERM").is_some_and(|term| term.ends_with("256color")) {
+            ColorSupport::Ansi256
+        } else {
+            ColorSupport::Ansi16
+        }
+    }
+
+    /// Styles `text` with `style`, using only the colors this level supports. The style is
+    /// resolved against the current context first, like [`style_text`](crate::style_text)
+    /// does.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Ansi, ColorSupport};
+    /// let style = Ansi::from_fg((255, 0, 0));
+    /// assert_eq!(ColorSupport::None.paint("x", style), "x");
+    /// assert_eq!(ColorSupport::Ansi16.paint("x", style), "\x1b[91mx\x1b[0m");
+    /// assert_eq!(ColorSupport::Ansi256.paint("x", style), "\x1b[38;5;196mx\x1b[0m");
+    /// ```
+    #[must_use]
+    pub fn paint(self, text: impl Display, style: impl IntoAnsi) -> String {
+        let style = resolve(style.into_ansi());
+        let text = text.to_string();
+        if text.is_empty() || style.is_default() {
+            return text;
+        }
+
+        let style = match self {
+            ColorSupport::None => return text,
+            ColorSupport::Ansi16 => return style.paint_text_16(&text),
+            ColorSupport::Ansi256 => style.to_ansi256(),
+            ColorSupport::TrueColor => style,
+        };
+        format!("{style}{text}{}", reset_policy().end(style))
+    }
+}
+
+impl ColorSupport {
+    /// Styles `text` (e.g. a token or password) as concealed, so terminals do not show it
+    /// while it can still be selected and copied. Without color support, or while styling is
+    /// disabled (see [`suppress_styles`](crate::suppress_styles)), the conceal attribute would
+    /// not be interpreted, so the text is masked with one `*` per column instead and the
+    /// secret never ends up in the output.
+    ///
+    /// Terminals that ignore the conceal attribute show the text, only use this where that is
+    /// acceptable.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::ColorSupport;
+    /// assert_eq!(ColorSupport::TrueColor.secret("hunter2"), "\x1b[8mhunter2\x1b[0m");
+    /// assert_eq!(ColorSupport::None.secret("hunter2"), "*******");
+    /// ```
+    #[must_use]
+    pub fn secret(self, text: &str) -> String {
+        if self == ColorSupport::None || !crate::styles_enabled() {
+            "*".repeat(crate::visible_width(text))
+        } else {
+            self.paint(text, crate::Ansi::new().conceal())
+        }
+    }
+}
+
+/// Whether a [`AnsiWriter`] should use colors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorChoice {
+    /// Use the color support detected for the stream.
+    #[default]
+    Auto,
+    /// Always use colors, even when the stream is not a terminal.
+    Always,
+    /// Never use colors.
+    Never,
+}
+
+/// The line ending an [`AnsiWriter`] writes after [`AnsiWriter::writeln_styled`], and for
+/// newlines in styled text. See [`with_line_ending`](crate::with_line_ending) for
+/// [`styled_println`](crate::styled_println).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineEnding {
+    /// `\n`.
+    #[default]
+    Lf,
+    /// `\r\n`, e.g. for serial consoles, raw mode terminals and Windows tools that expect it.
+    CrLf,
+}
+
+impl LineEnding {
+    /// Gets the line ending of the platform this was compiled for: [`LineEnding::CrLf`] on
+    /// Windows and [`LineEnding::Lf`] everywhere else.
+    #[must_use]
+    pub const fn native() -> Self {
+        if cfg!(windows) {
+            LineEnding::CrLf
+        } else {
+            LineEnding::Lf
+        }
+    }
+
+    /// Gets the characters of this line ending.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+
+    /// Writes every newline of `text` with this line ending.
+    pub(crate) fn apply(self, text: String) -> String {
+        if self == LineEnding::CrLf && text.contains('\n') {
+            text.replace("\r\n", "\n").replace('\n', "\r\n")
+        } else {
+            text
+        }
+    }
+}
+
+/// When an [`AnsiWriter`] flushes its inner writer after a styled write. See
+/// [`with_flush_policy`](crate::with_flush_policy) for [`styled_print`](crate::styled_print).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FlushPolicy {
+    /// Only when [`flush`](Write::flush) is called, leaving buffering to the inner writer.
+    #[default]
+    Manual,
+    /// After every styled write that contains a line ending, like a line buffered terminal.
+    /// This keeps line based output in order when stdout is a pipe, e.g. into `tee`.
+    Line,
+    /// After every styled write, so partial lines such as progress output show up right away,
+    /// e.g. on Windows consoles.
+    EveryWrite,
+}
+
+impl FlushPolicy {
+    /// Returns `true` if a styled write of `text` should be followed by a flush.
+    pub(crate) fn flushes(self, text: &str) -> bool {
+        match self {
+            FlushPolicy::Manual => false,
+            FlushPolicy::Line => text.contains('\n'),
+            FlushPolicy::EveryWrite => true,
+        }
+    }
+}
+
+/// A writer that styles text according to its own [`ColorSupport`], so each output stream
+/// makes its own decision about colors. Get one for the standard streams with [`stdout`] and
+/// [`stderr`], or wrap any writer with [`AnsiWriter::new`].
+///
+/// Everything written through [`Write`] goes to the inner writer unchanged. The
+/// [`LineEnding`], [`FlushPolicy`] and [`AttributeFallback`] of a writer only apply to its
+/// styled writes.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi, AnsiWriter, ColorSupport};
+/// let mut log = AnsiWriter::new(Vec::new(), ColorSupport::None);
+/// log.writeln_styled("done", Ansi::green()).unwrap();
+/// assert_eq!(log.into_inner(), b"done\n");
+/// ```
+#[derive(Debug)]
+pub struct AnsiWriter<W> {
+    inner: W,
+    detected: ColorSupport,
+    support: ColorSupport,
+    line_ending: LineEnding,
+    flush_policy: FlushPolicy,
+    fallback: AttributeFallback,
+}
+
+impl<W: Write> AnsiWriter<W> {
+    /// Wraps `inner`, styling text with the given color support. Lines end with
+    /// [`LineEnding::Lf`], flushing is left to the caller and every attribute is assumed to be
+    /// supported.
+    pub fn new(inner: W, support: ColorSupport) -> Self {
+        Self {
+            inner,
+            detected: support,
+            support,
+            line_ending: LineEnding::default(),
+            flush_policy: FlushPolicy::default(),
+            fallback: AttributeFallback::new(),
+        }
+    }
+
+    /// Overrides the color support of this writer. [`ColorChoice::Auto`] goes back to the
+    /// support it was created with.
+    #[must_use]
+    pub fn with_choice(mut self, choice: ColorChoice) -> Self {
+        self.support = match choice {
+            ColorChoice::Auto => self.detected,
+            ColorChoice::Always => match self.detected {
+                ColorSupport::None => ColorSupport::level(|name| std::env::var(name).ok()),
+                detected => detected,
+            },
+            ColorChoice::Never => ColorSupport::None,
+        };
+        self
+    }
+
+    /// Builder function to set the line ending of styled writes.
+    #[must_use]
+    pub fn with_line_ending(self, line_ending: LineEnding) -> Self {
+        Self {
+            line_ending,
+            ..self
+        }
+    }
+
+    /// Builder function to set when styled writes flush the inner writer.
+    #[must_use]
+    pub fn with_flush_policy(self, flush_policy: FlushPolicy) -> Self {
+        Self {
+            flush_policy,
+            ..self
+        }
+    }
+
+    /// Builder function to set the fallbacks for the attributes the output can not show.
+    #[must_use]
+    pub fn with_attribute_fallback(self, fallback: AttributeFallback) -> Self {
+        Self { fallback, ..self }
+    }
+
+    /// Gets the line ending of styled writes.
+    #[must_use]
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    /// Gets when styled writes flush the inner writer.
+    #[must_use]
+    pub fn flush_policy(&self) -> FlushPolicy {
+        self.flush_policy
+    }
+
+    /// Gets the color support this writer styles text with.
+    #[must_use]
+    pub fn support(&self) -> ColorSupport {
+        self.support
+    }
+
+    /// Styles `text` the way this writer would write it: unsupported attributes are replaced
+    /// by their fallbacks, then the text is styled with [`ColorSupport::paint`].
+    #[must_use]
+    pub fn paint(&self, text: impl Display, style: impl IntoAnsi) -> String {
+        self.support
+            .paint(text, self.fallback.apply(style.into_ansi()))
+    }
+
+    /// Conceals `text` the way this writer would write it, see [`ColorSupport::secret`]. When
+    /// the [`AttributeFallback`] of this writer marks
+    /// [`AnsiFlags::CONCEAL`](crate::AnsiFlags::CONCEAL) as unsupported, the text is masked
+    /// like it is without color support, so the secret is not shown on terminals that can not
+    /// conceal it.
+    #[must_use]
+    pub fn secret(&self, text: &str) -> String {
+        if self.fallback.is_supported(crate::AnsiFlags::CONCEAL) {
+            self.support.secret(text)
+        } else {
+            ColorSupport::None.secret(text)
+        }
+    }
+
+    /// Writes `text` styled with `style`.
+    ///
+    /// ## Errors
+    /// - Any error returned by the inner writer.
+    pub fn write_styled(&mut self, text: impl Display, style: impl IntoAnsi) -> io::Result<()> {
+        let styled = self.paint(text, style);
+        self.write_lines(styled)
+    }
+
+    /// Writes `text` styled with `style`, followed by a newline.
+    ///
+    /// ## Errors
+    /// - Any error returned by the inner writer.
+    pub fn writeln_styled(&mut self, text: impl Display, style: impl IntoAnsi) -> io::Result<()> {
+        let mut styled = self.paint(text, style);
+        styled.push('\n');
+        self.write_lines(styled)
+    }
+
+    /// Writes styled text with this writer's line ending, and flushes according to its flush
+    /// policy.
+    fn write_lines(&mut self, styled: String) -> io::Result<()> {
+        let styled = self.line_ending.apply(styled);
+        self.inner.write_all(styled.as_bytes())?;
+        if self.flush_policy.flushes(&styled) {
+            self.inner.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Gets a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets the inner writer back.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for AnsiWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Gets an [`AnsiWriter`] for stdout, with its color support detected from whether stdout is
+/// a terminal (see [`ColorSupport::detect`]).
+///
+/// ## Example
+/// ```
+/// # use ansirs::{stdout, Ansi};
+/// stdout().writeln_styled("Build finished", Ansi::green()).unwrap();
+/// ```
+#[must_use]
+pub fn stdout() -> AnsiWriter<io::Stdout> {
+    let stream = io::stdout();
+    let support = ColorSupport::detect(stream.is_terminal());
+    AnsiWriter::new(stream, support)
+}
+
+/// Gets an [`AnsiWriter`] for stderr, with its color support detected from whether stderr is
+/// a terminal (see [`ColorSupport::detect`]). This is independent of [`stdout`], so errors
+/// stay colored on the terminal while stdout is piped to a file.
+#[must_use]
+pub fn stderr() -> AnsiWriter<io::Stderr> {
+    let stream = io::stderr();
+    let support = ColorSupport::detect(stream.is_terminal());
+    AnsiWriter::new(stream, support)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Ansi, AnsiFlags};
+    use pretty_assertions::assert_eq;
+
+    fn detect(is_terminal: bool, vars: &[(&str, &str)]) -> ColorSupport {
+        ColorSupport::from_env(is_terminal, |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value).to_string())
+        })
+    }
+
+    #[test]
+    fn detection() {
+        assert_eq!(detect(false, &[]), ColorSupport::None);
+        assert_eq!(detect(true, &[]), ColorSupport::Ansi16);
+        assert_eq!(
+            detect(true, &[("TERM", "xterm-256color")]),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(
+            detect(true, &[("TERM", "xterm"), ("COLORTERM", "truecolor")]),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(detect(true, &[("TERM", "dumb")]), ColorSupport::None);
+        assert_eq!(detect(true, &[("NO_COLOR", "1")]), ColorSupport::None);
+        assert_eq!(detect(true, &[("NO_COLOR", "")]), ColorSupport::Ansi16);
+        assert_eq!(
+            detect(false, &[("FORCE_COLOR", "1"), ("COLORTERM", "24bit")]),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            detect(false, &[("CLICOLOR_FORCE", "0")]),
+            ColorSupport::None
+        );
+    }
+
+    #[test]
+    fn painting() {
+        let style = Ansi::from_fg((255, 0, 0)).bold();
+        assert_eq!(ColorSupport::None.paint("x", style), "x");
+        assert_eq!(ColorSupport::Ansi16.paint("x", style), "\x1b[1;91mx\x1b[0m");
+        assert_eq!(
+            ColorSupport::Ansi256.paint("x", style),
+            "\x1b[1;38;5;196mx\x1b[0m"
+        );
+        assert_eq!(
+            ColorSupport::TrueColor.paint("x", style),
+            crate::style_text("x", style)
+        );
+        assert_eq!(ColorSupport::TrueColor.paint("", style), "");
+        crate::without_styles(|| assert_eq!(ColorSupport::TrueColor.paint("x", style), "x"));
+    }
+
+    /// Records the writes and flushes made to it.
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl Write for Recorder {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.push(String::from_utf8_lossy(buf).into_owned());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.0.push("<flush>".to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn line_endings_and_flushing() {
+        let mut writer = AnsiWriter::new(Recorder::default(), ColorSupport::None)
+            .with_line_ending(LineEnding::CrLf)
+            .with_flush_policy(FlushPolicy::Line);
+        writer.write_styled("a\nb\r\n", Ansi::red()).unwrap();
+        writer.write_styled("50%", Ansi::red()).unwrap();
+        writer.writeln_styled("c", Ansi::red()).unwrap();
+        writer.write_all(b"raw\n").unwrap();
+        assert_eq!(
+            writer.get_ref().0,
+            ["a\r\nb\r\n", "<flush>", "50%", "c\r\n", "<flush>", "raw\n"]
+        );
+
+        let mut writer = AnsiWriter::new(Recorder::default(), ColorSupport::None)
+            .with_flush_policy(FlushPolicy::EveryWrite);
+        assert_eq!(writer.line_ending(), LineEnding::Lf);
+        writer.write_styled("50%", Ansi::red()).unwrap();
+        writer.writeln_styled("", Ansi::red()).unwrap();
+        assert_eq!(writer.get_ref().0, ["50%", "<flush>", "\n", "<flush>"]);
+        assert_eq!(writer.flush_policy(), FlushPolicy::EveryWrite);
+    }
+
+    #[test]
+    fn writers() {
+        let mut colored = AnsiWriter::new(Vec::new(), ColorSupport::Ansi256);
+        colored.writeln_styled("a", Ansi::new().bold()).unwrap();
+        colored.write_styled(1, Ansi::new().italic()).unwrap();
+        colored.write_all(b"!").unwrap();
+        assert_eq!(
+            String::from_utf8(colored.into_inner()).unwrap(),
+            "\x1b[1ma\x1b[0m\n\x1b[3m1\x1b[0m!"
+        );
+
+        let mut degraded = AnsiWriter::new(Vec::new(), ColorSupport::Ansi16)
+            .with_attribute_fallback(
+                AttributeFallback::new().fallback(AnsiFlags::CONCEAL, AnsiFlags::DIM),
+            );
+        degraded.write_styled("b", Ansi::new().conceal()).unwrap();
+        assert_eq!(degraded.secret("pw"), "**");
+        assert_eq!(degraded.into_inner(), b"\x1b[2mb\x1b[0m");
+        let dropped = AnsiWriter::new(Vec::new(), ColorSupport::TrueColor)
+            .with_attribute_fallback(AttributeFallback::new().unsupported(AnsiFlags::CONCEAL));
+        assert_eq!(dropped.secret("hunter2"), "*******");
+        let concealed = AnsiWriter::new(Vec::new(), ColorSupport::TrueColor);
+        assert_eq!(concealed.secret("pw"), "\x1b[8mpw\x1b[0m");
+
+        let writer = AnsiWriter::new(Vec::new(), ColorSupport::Ansi256);
+        let writer = writer.with_choice(ColorChoice::Never);
+        assert_eq!(writer.support(), ColorSupport::None);
+        let writer = writer.with_choice(ColorChoice::Always);
+        assert_eq!(writer.support(), ColorSupport::Ansi256);
+        let writer = writer.with_choice(ColorChoice::Auto);
+        assert_eq!(writer.support(), ColorSupport::Ansi256);
+        assert!(AnsiWriter::new(Vec::new(), ColorSupport::None)
+            .with_choice(ColorChoice::Always)
+            .support()
+            .ge(&ColorSupport::Ansi16));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{Ansi, IntoAnsi};
+use crate::{
+    styled::width::{truncate_to_precision, write_padded},
+    Ansi, IntoAnsi,
+};
 
 /// A "compact" version of pretty string that is meant to improve performance for `value` calls (which I figured
 /// would be the most common use case for this type) by immediately calculating it and storing it. This means that
@@ -85,7 +88,11 @@ impl From<&CompactPrettyString> for String {
 
 impl std::fmt::Display for CompactPrettyString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        let text = truncate_to_precision(f, self.raw());
+        write_padded(f, text, |f, text| match self.style() {
+            Some(style) if text.len() != self.1 => f.write_str(&style.paint_text(text)),
+            _ => f.write_str(&self.0),
+        })
     }
 }
 
@@ -6,7 +6,10 @@
 
 use once_cell::sync::OnceCell;
 
-use crate::{Ansi, IntoAnsi};
+use crate::{
+    styled::width::{truncate_to_precision, write_padded},
+    Ansi, IntoAnsi,
+};
 
 /// Fourth iteration of a styled string type. This one uses `once_cell::Lazy` to calculate
 /// the styled text only when needed. Unfortunately this does mean that the text and the
@@ -80,7 +83,14 @@ impl LazyPrettyString {
 
 impl std::fmt::Display for LazyPrettyString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value())
+        let text = truncate_to_precision(f, self.raw());
+        write_padded(f, text, |f, text| {
+            if text.len() == self.0.len() {
+                f.write_str(self.value())
+            } else {
+                f.write_str(&self.1.paint_text(text))
+            }
+        })
     }
 }
 
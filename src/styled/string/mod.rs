@@ -45,6 +45,48 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn formatter_flags() {
+        let style = Ansi::new().bold();
+        let expected = [
+            ("{:>7}", "  \x1b[1mhello\x1b[0m"),
+            ("{:-^8}", "-\x1b[1mhello\x1b[0m--"),
+            ("{:6.3}", "\x1b[1mhel\x1b[0m   "),
+            ("{:.9}", "\x1b[1mhello\x1b[0m"),
+        ];
+        let pretty = PrettyString::new("hello", style);
+        let compact = CompactPrettyString::new("hello", style);
+        let lazy = LazyPrettyString::new("hello", style);
+        let actual = [
+            [
+                format!("{pretty:>7}"),
+                format!("{compact:>7}"),
+                format!("{lazy:>7}"),
+            ],
+            [
+                format!("{pretty:-^8}"),
+                format!("{compact:-^8}"),
+                format!("{lazy:-^8}"),
+            ],
+            [
+                format!("{pretty:6.3}"),
+                format!("{compact:6.3}"),
+                format!("{lazy:6.3}"),
+            ],
+            [
+                format!("{pretty:.9}"),
+                format!("{compact:.9}"),
+                format!("{lazy:.9}"),
+            ],
+        ];
+        for ((format, expected), actual) in expected.iter().zip(actual) {
+            for value in actual {
+                assert_eq!(&value, expected, "{format}");
+            }
+        }
+        assert_eq!(format!("{:<4}|", PrettyString::plain("ab")), "ab  |");
+    }
+
     #[test]
     #[ignore]
     fn compare_all() {
@@ -1,4 +1,7 @@
-use crate::{Ansi, IntoAnsi};
+use crate::{
+    styled::width::{truncate_to_precision, write_padded},
+    Ansi, IntoAnsi,
+};
 
 /// A string and some styling.
 ///
@@ -87,10 +90,11 @@ impl PrettyString {
 
 impl std::fmt::Display for PrettyString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.1 {
-            Some(ref style) => write!(f, "{}", style.paint_text(self.0.as_str())),
-            None => write!(f, "{}", self.0),
-        }
+        let text = truncate_to_precision(f, &self.0);
+        write_padded(f, text, |f, text| match self.1 {
+            Some(ref style) => f.write_str(&style.paint_text(text)),
+            None => f.write_str(text),
+        })
     }
 }
 
@@ -0,0 +1,248 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{style_text, Ansi, IntoAnsi, StyleLint};
+
+/// Prefix of the environment variables read by [`Theme::env_overrides`].
+pub const STYLE_ENV_PREFIX: &str = "ANSIRS_STYLE_";
+
+/// An ordered collection of styles looked up by semantic keys, like `error` or `path`, so an
+/// application's styling is defined in one place.
+///
+/// Applications can opt into letting users recolor them with [`Theme::env_overrides`].
+///
+/// ## Example
+/// ```
+/// # use ansirs::{Ansi, Theme};
+/// let theme = Theme::new()
+///     .with("error", Ansi::red().bold())
+///     .with("path", Ansi::new().underline());
+///
+/// assert_eq!(theme.get("error"), Some(Ansi::red().bold()));
+/// assert_eq!(theme.paint("path", "src/lib.rs"), "\x1b[4msrc/lib.rs\x1b[0m");
+/// assert_eq!(theme.paint("unknown", "plain"), "plain");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    entries: Vec<(String, Ansi)>,
+}
+
+impl Theme {
+    /// Creates a new, empty, [`Theme`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder function to set the style of `key`.
+    #[must_use]
+    pub fn with(mut self, key: impl Into<String>, style: impl IntoAnsi) -> Self {
+        self.set(key, style);
+        self
+    }
+
+    /// Sets the style of `key`, replacing its current style if it has one.
+    pub fn set(&mut self, key: impl Into<String>, style: impl IntoAnsi) {
+        let key = key.into();
+        let style = style.into_ansi();
+        match self.entries.iter_mut().find(|(entry, _)| *entry == key) {
+            Some((_, current)) => *current = style,
+            None => self.entries.push((key, style)),
+        }
+    }
+
+    /// Gets the style of `key`.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<Ansi> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == key)
+            .map(|(_, style)| *style)
+    }
+
+    /// Styles `text` with the style of `key`, or leaves it unstyled if `key` has no style.
+    #[must_use]
+    pub fn paint(&self, key: &str, text: impl std::fmt::Display) -> String {
+        style_text(text, self.get(key).unwrap_or_default())
+    }
+
+    /// Gets the number of styles in this theme.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if this theme has no styles.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over the `(key, style)` entries of this theme, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Ansi)> + '_ {
+        self.entries
+            .iter()
+            .map(|(key, style)| (key.as_str(), *style))
+    }
+
+    /// Checks every style of this theme with [`Ansi::lint`], returning the problems found
+    /// together with their keys, in order.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Ansi, StyleLint, Theme};
+    /// let theme = Theme::new()
+    ///     .with("ok", Ansi::green())
+    ///     .with("muted", Ansi::new().bold().dim());
+    /// assert_eq!(theme.lint(), vec![("muted", StyleLint::BoldAndDim)]);
+    /// ```
+    #[must_use]
+    pub fn lint(&self) -> Vec<(&str, StyleLint)> {
+        self.entries
+            .iter()
+            .flat_map(|(key, style)| style.lint().into_iter().map(|lint| (key.as_str(), lint)))
+            .collect()
+    }
+
+    /// Builder function that applies style overrides from the environment, giving end users a
+    /// standard way to recolor the application. Call it once at startup, after the defaults
+    /// are set.
+    ///
+    /// Every `ANSIRS_STYLE_<KEY>` variable holding a valid [style spec](Ansi::parse_spec)
+    /// replaces the style of the matching key. Keys match when they are equal after upper
+    /// casing and turning anything but letters and digits into `_`, so `ANSIRS_STYLE_LINE_NO`
+    /// overrides `line-no`. Variables for keys the theme does not have add a new, lowercase,
+    /// key. Variables with an invalid spec are ignored.
+    ///
+    /// ## Example
+    /// ```
+    /// # use ansirs::{Ansi, Theme};
+    /// // ANSIRS_STYLE_ERROR="bold #ff5555"
+    /// # std::env::set_var("ANSIRS_STYLE_ERROR", "bold #ff5555");
+    /// let theme = Theme::new().with("error", Ansi::red()).env_overrides();
+    /// assert_eq!(theme.get("error"), Some(Ansi::from_fg((255, 85, 85)).bold()));
+    /// ```
+    #[must_use]
+    pub fn env_overrides(self) -> Self {
+        self.with_overrides(std::env::vars_os().filter_map(|(name, value)| {
+            Some((name.into_string().ok()?, value.into_string().ok()?))
+        }))
+    }
+
+    /// Applies `(variable, spec)` overrides, see [`Theme::env_overrides`].
+    fn with_overrides(mut self, vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        for (name, spec) in vars {
+            let Some(var_key) = name.strip_prefix(STYLE_ENV_PREFIX) else {
+                continue;
+            };
+            let Ok(style) = Ansi::parse_spec(&spec) else {
+                continue;
+            };
+            match self
+                .entries
+                .iter_mut()
+                .find(|(key, _)| env_key(key) == var_key)
+            {
+                Some((_, current)) => *current = style,
+                None if !var_key.is_empty() => {
+                    self.entries.push((var_key.to_lowercase(), style));
+                }
+                None => {}
+            }
+        }
+        self
+    }
+}
+
+/// Gets the name `key` has in environment variables, e.g. `line-no` becomes `LINE_NO`.
+fn env_key(key: &str) -> String {
+    key.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+impl<S: Into<String>> FromIterator<(S, Ansi)> for Theme {
+    fn from_iter<T: IntoIterator<Item = (S, Ansi)>>(iter: T) -> Self {
+        let mut theme = Self::default();
+        theme.extend(iter);
+        theme
+    }
+}
+
+impl<S: Into<String>> Extend<(S, Ansi)> for Theme {
+    fn extend<T: IntoIterator<Item = (S, Ansi)>>(&mut self, iter: T) {
+        for (key, style) in iter {
+            self.set(key, style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn build() {
+        let mut theme = [("a", Ansi::new().bold()), ("b", Ansi::red())]
+            .into_iter()
+            .collect::<Theme>();
+        theme.set("a", Ansi::new().dim());
+        theme.extend([("c", Ansi::new())]);
+        assert_eq!(theme.len(), 3);
+        assert!(!theme.is_empty());
+        assert_eq!(
+            theme.iter().collect::<Vec<_>>(),
+            vec![
+                ("a", Ansi::new().dim()),
+                ("b", Ansi::red()),
+                ("c", Ansi::new())
+            ]
+        );
+        assert_eq!(theme.get("d"), None);
+        assert_eq!(theme.paint("a", 1), "\x1b[2m1\x1b[0m");
+    }
+
+    #[test]
+    fn overrides() {
+        let vars = [
+            ("ANSIRS_STYLE_LINE_NO", "dim"),
+            ("ANSIRS_STYLE_ERROR", "not a style"),
+            ("ANSIRS_STYLE_HINT", "italic cyan"),
+            ("ANSIRS_STYLE_", "bold"),
+            ("OTHER_ERROR", "bold"),
+        ];
+        let theme = Theme::new()
+            .with("error", Ansi::red())
+            .with("line-no", Ansi::new().bold())
+            .with_overrides(
+                vars.iter()
+                    .map(|(name, spec)| ((*name).to_string(), (*spec).to_string())),
+            );
+        assert_eq!(
+            theme.iter().collect::<Vec<_>>(),
+            vec![
+                ("error", Ansi::red()),
+                ("line-no", Ansi::new().dim()),
+                ("hint", Ansi::new().italic().fg_indexed(6)),
+            ]
+        );
+    }
+
+    #[test]
+    fn env_keys() {
+        assert_eq!(env_key("error"), "ERROR");
+        assert_eq!(env_key("line-no"), "LINE_NO");
+        assert_eq!(env_key("diff.added"), "DIFF_ADDED");
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::buffer::StyledBuffer;
+use super::width::visible_width;
+use crate::{Ansi, IntoAnsi};
+
+/// Number of spaces between the widest label and the details column.
+const DETAIL_GAP: usize = 2;
+
+/// A node of a styled tree, rendered with box-drawing connectors like `cargo tree` or
+/// `tree` do.
+///
+/// Every node has its own label style, and can have a detail (e.g. a size or a version) which
+/// is shown in a column lined up after the labels. Widths are measured with
+/// [`visible_width`](crate::visible_width), so labels that are already styled, or that contain
+/// wide characters, line up too. Labels with several lines are indented under their first
+/// line. The connector and detail styles of the root are used for the whole tree.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{strip_ansi, Ansi, Tree};
+/// let tree = Tree::new("src")
+///     .style(Ansi::new().bold())
+///     .child(Tree::new("lib.rs").detail("2 KB"))
+///     .child(Tree::new("styled").child(Tree::new("mod.rs").detail("12 KB")));
+///
+/// assert_eq!(
+///     strip_ansi(&tree.to_string()),
+///     "src\n├── lib.rs      2 KB\n└── styled\n    └── mod.rs  12 KB"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tree {
+    label: String,
+    style: Ansi,
+    detail: Option<String>,
+    children: Vec<Tree>,
+    connectors: Ansi,
+    details: Ansi,
+}
+
+/// A node together with the connector prefixes of its first and following label lines.
+struct Row<'a> {
+    node: &'a Tree,
+    prefix: String,
+    continuation: String,
+}
+
+impl Tree {
+    /// Creates a new, unstyled, node with no children. Connectors and details are dimmed by
+    /// default.
+    #[must_use]
+    pub fn new(label: impl std::fmt::Display) -> Self {
+        Self {
+            label: label.to_string(),
+            style: Ansi::new(),
+            detail: None,
+            children: Vec::new(),
+            connectors: Ansi::new().dim(),
+            details: Ansi::new().dim(),
+        }
+    }
+
+    /// Builder function to set the style of this node's label.
+    #[must_use]
+    pub fn style(self, style: impl IntoAnsi) -> Self {
+        Self {
+            style: style.into_ansi(),
+            ..self
+        }
+    }
+
+    /// Builder function to set the detail shown after this node's label.
+    #[must_use]
+    pub fn detail(self, detail: impl std::fmt::Display) -> Self {
+        Self {
+            detail: Some(detail.to_string()),
+            ..self
+        }
+    }
+
+    /// Builder function to set the style of the connectors. Only used on the root.
+    #[must_use]
+    pub fn connectors(self, style: impl IntoAnsi) -> Self {
+        Self {
+            connectors: style.into_ansi(),
+            ..self
+        }
+    }
+
+    /// Builder function to set the style of the details. Only used on the root.
+    #[must_use]
+    pub fn details(self, style: impl IntoAnsi) -> Self {
+        Self {
+            details: style.into_ansi(),
+            ..self
+        }
+    }
+
+    /// Builder function to add a child node.
+    #[must_use]
+    pub fn child(mut self, child: Tree) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Builder function to add several child nodes.
+    #[must_use]
+    pub fn children(mut self, children: impl IntoIterator<Item = Tree>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Adds a child node.
+    pub fn push(&mut self, child: Tree) {
+        self.children.push(child);
+    }
+
+    /// Renders the tree into a [`String`], one line per label line, without a trailing newline.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut rows = vec![Row {
+            node: self,
+            prefix: String::new(),
+            continuation: String::new(),
+        }];
+        self.collect_rows("", &mut rows);
+
+        let column = rows
+            .iter()
+            .filter(|row| row.node.detail.is_some())
+            .map(|row| visible_width(&row.prefix) + visible_width(row.node.first_line()))
+            .max()
+            .unwrap_or(0)
+            + DETAIL_GAP;
+
+        let mut buffer = StyledBuffer::new();
+        for (idx, row) in rows.iter().enumerate() {
+            for (line_idx, line) in row.node.label.split('\n').enumerate() {
+                if idx > 0 || line_idx > 0 {
+                    buffer.push("\n", Ansi::new());
+                }
+                let prefix = if line_idx == 0 {
+                    &row.prefix
+                } else {
+                    &row.continuation
+                };
+                buffer.push(prefix, self.connectors);
+                buffer.push(line, row.node.style);
+
+                if let (0, Some(detail)) = (line_idx, &row.node.detail) {
+                    let used = visible_width(prefix) + visible_width(line);
+                    buffer.push(&" ".repeat(column - used), Ansi::new());
+                    buffer.push(detail, self.details);
+                }
+            }
+        }
+        buffer.finish()
+    }
+
+    /// Gets the first line of the label.
+    fn first_line(&self) -> &str {
+        self.label.split('\n').next().unwrap_or_default()
+    }
+
+    /// Adds the rows of every descendant of this node, where `indent` is the connector prefix
+    /// of this node's children.
+    fn collect_rows<'a>(&'a self, indent: &str, rows: &mut Vec<Row<'a>>) {
+        let count = self.children.len();
+        for (idx, child) in self.children.iter().enumerate() {
+            let last = idx + 1 == count;
+            let (connector, child_indent) = if last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            let child_indent = format!("{indent}{child_indent}");
+            rows.push(Row {
+                node: child,
+                prefix: format!("{indent}{connector}"),
+                continuation: child_indent.clone(),
+            });
+            child.collect_rows(&child_indent, rows);
+        }
+    }
+}
+
+impl std::fmt::Display for Tree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strip_ansi;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn layout() {
+        let tree = Tree::new("root")
+            .child(
+                Tree::new("a")
+                    .child(Tree::new("a1"))
+                    .child(Tree::new("a2").child(Tree::new("x"))),
+            )
+            .child(Tree::new("b\nsecond line").child(Tree::new("b1")))
+            .children([Tree::new("c")]);
+        assert_eq!(
+            strip_ansi(&tree.render()),
+            "root\n├── a\n│   ├── a1\n│   └── a2\n│       └── x\n├── b\n│   second line\n│   └── b1\n└── c"
+        );
+        assert_eq!(Tree::new("alone").render(), "alone");
+    }
+
+    #[test]
+    fn styles() {
+        let mut tree = Tree::new("r")
+            .style(Ansi::new().bold())
+            .connectors(Ansi::new().italic());
+        tree.push(Tree::new("x").style(Ansi::new().bold()));
+        assert_eq!(
+            tree.to_string(),
+            "\x1b[1mr\x1b[0m\n\x1b[3m└── \x1b[0;1mx\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn details() {
+        let tree = Tree::new(Ansi::red().paint_text("root"))
+            .details(Ansi::new())
+            .connectors(Ansi::new())
+            .detail("1")
+            .child(Tree::new("ab").detail("22"))
+            .child(Tree::new("long name"));
+        assert_eq!(
+            strip_ansi(&tree.render()),
+            "root    1\n├── ab  22\n└── long name"
+        );
+    }
+}
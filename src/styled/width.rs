@@ -0,0 +1,309 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::{
+    borrow::Cow,
+    fmt::{self, Alignment, Formatter, Write as _},
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{terminal, Ansi, AnsiTokenizer, Token};
+
+/// Removes every escape sequence (styling, hyperlinks, cursor movement, etc.) from `text`.
+/// The input is returned as-is, without allocating, when it contains no escape sequences.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{strip_ansi, Ansi};
+/// let styled = Ansi::red().bold().paint_text("error");
+/// assert_eq!(strip_ansi(&styled), "error");
+/// assert_eq!(strip_ansi("plain"), "plain");
+/// ```
+#[must_use]
+pub fn strip_ansi(text: &str) -> Cow<'_, str> {
+    if !text.contains('\x1b') {
+        return Cow::Borrowed(text);
+    }
+
+    Cow::Owned(
+        AnsiTokenizer::new(text)
+            .filter(Token::is_text)
+            .map(|token| token.as_str())
+            .collect(),
+    )
+}
+
+/// Gets the number of terminal columns `text` takes up when printed, ignoring escape sequences.
+///
+/// Without the `unicode` feature every character counts as one column. With it, grapheme
+/// clusters (e.g. `e` + combining accent, or emoji joined with ZWJ) count once, and wide
+/// characters such as CJK ideographs and emoji count as two columns, so padding and alignment
+/// line up for international text.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{visible_width, Ansi};
+/// let styled = Ansi::red().paint_text("café");
+/// assert_eq!(visible_width(&styled), 4);
+/// ```
+#[must_use]
+pub fn visible_width(text: &str) -> usize {
+    AnsiTokenizer::new(text)
+        .filter(Token::is_text)
+        .map(|token| text_width(token.as_str()))
+        .sum()
+}
+
+/// Gets the width of plain text, without escape sequences.
+fn text_width(text: &str) -> usize {
+    columns(text).map(|(_, width)| width).sum()
+}
+
+/// Splits plain text into the pieces that take up columns, with their widths. Control
+/// characters (e.g. a stray `BEL`) take up no columns.
+#[cfg(not(feature = "unicode"))]
+pub(super) fn columns(text: &str) -> impl Iterator<Item = (&str, usize)> {
+    text.char_indices()
+        .map(|(idx, c)| (&text[idx..idx + c.len_utf8()], usize::from(!c.is_control())))
+}
+
+/// Splits plain text into the pieces that take up columns, with their widths. Control
+/// characters (e.g. a stray `BEL`) take up no columns.
+#[cfg(feature = "unicode")]
+pub(super) fn columns(text: &str) -> impl Iterator<Item = (&str, usize)> {
+    use unicode_segmentation::UnicodeSegmentation;
+    use unicode_width::UnicodeWidthStr;
+
+    text.graphemes(true).map(|grapheme| {
+        if grapheme.chars().all(char::is_control) {
+            (grapheme, 0)
+        } else {
+            (grapheme, grapheme.width().min(2))
+        }
+    })
+}
+
+/// Cuts the visible columns in `range` out of the styled `text`, e.g. to scroll a styled line
+/// horizontally in a viewport.
+///
+/// The result starts with the styling that is active at the start of the range and ends with a
+/// reset if any styling is still active at its end, so it can be printed on its own. Escape
+/// sequences are never split. Grapheme clusters are not split either (see [`visible_width`]):
+/// a wide character that only partly falls inside the range is replaced by spaces for the
+/// columns inside it, so the result keeps the width of the range. Hyperlinks (OSC 8) are
+/// handled like styling: a link that is open at the start of the range is reopened, and one
+/// that is still open at its end is closed. Other escape sequences are only kept inside the
+/// range.
+///
+/// ## Example
+/// ```
+/// # use ansirs::{slice_visible, Ansi};
+/// let line = format!("{} {}", Ansi::red().paint_text("error:"), "disk full");
+/// assert_eq!(slice_visible(&line, 2..11), "\x1b[38;2;255;0;0mror:\x1b[0m disk");
+/// assert_eq!(slice_visible(&line, 7..), "disk full");
+/// ```
+#[must_use]
+pub fn slice_visible(text: &str, range: impl RangeBounds<usize>) -> String {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => usize::MAX,
+    };
+
+    let mut out = String::new();
+    let mut active = Ansi::new();
+    let mut link = None;
+    let mut column = 0;
+    let mut started = false;
+    for token in AnsiTokenizer::new(text) {
+        if column >= end {
+            break;
+        }
+        let Token::Text(text) = token else {
+            if let Some(params) = token.sgr_params() {
+                active.apply_sgr_params(params);
+            }
+            if let Some(opens) = hyperlink_opens(&token) {
+                link = opens.then_some(token.as_str());
+            }
+            if started {
+                out.push_str(token.as_str());
+            }
+            continue;
+        };
+
+        for (piece, width) in columns(text) {
+            let inside = column.max(start)..(column + width).min(end);
+            if column >= start && column + width <= end || !inside.is_empty() {
+                if !started {
+                    started = true;
+                    // Writing to a `String` never fails.
+                    let _ = active.write_sequence(&mut out);
+                    out.push_str(link.unwrap_or_default());
+                }
+                if column >= start && column + width <= end {
+                    out.push_str(piece);
+                } else {
+                    out.extend(inside.map(|_| ' '));
+                }
+            }
+            column += width;
+        }
+    }
+    if started && link.is_some() {
+        out.push_str(HYPERLINK_END);
+    }
+    if started && !active.is_default() {
+        out.push_str(Ansi::reset());
+    }
+    out
+}
+
+/// Closes an OSC 8 hyperlink.
+const HYPERLINK_END: &str = "\x1b]8;;\x1b\\";
+
+/// If `token` is an OSC 8 hyperlink sequence, gets whether it opens a link (`true`) or closes
+/// the current one (`false`).
+fn hyperlink_opens(token: &Token<'_>) -> Option<bool> {
+    let Token::Osc(seq) = token else {
+        return None;
+    };
+    let (_params, uri) = seq.strip_prefix("\x1b]8;")?.split_once(';')?;
+    Some(!uri.starts_with(['\x07', '\x1b']))
+}
+
+/// Gets the width of the terminal in columns, if it is known. See [`terminal::width`] for how
+/// it is detected.
+///
+/// ## Example
+/// ```
+/// # use ansirs::terminal_width;
+/// let width = terminal_width().unwrap_or(80);
+/// # assert!(width > 0);
+/// ```
+#[must_use]
+pub fn terminal_width() -> Option<usize> {
+    terminal::width().map(usize::from)
+}
+
+/// Shortens `text` to the formatter's precision, in characters, like `{:.3}` does for a `str`.
+pub(crate) fn truncate_to_precision<'a>(f: &Formatter<'_>, text: &'a str) -> &'a str {
+    match f.precision().and_then(|max| text.char_indices().nth(max)) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+/// Writes `text` using `paint`, surrounded by enough of the formatter's fill character to reach
+/// its width. The padding is based on the visible width of `text` and written outside of the
+/// styling. Text is left aligned unless the formatter asks otherwise, like a `str`.
+pub(crate) fn write_padded(
+    f: &mut Formatter<'_>,
+    text: &str,
+    paint: impl FnOnce(&mut Formatter<'_>, &str) -> fmt::Result,
+) -> fmt::Result {
+    let padding = f
+        .width()
+        .map_or(0, |width| width.saturating_sub(visible_width(text)));
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    paint(f, text)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn strip() {
+        let text = "\u{1b}[1ma\u{1b}]8;;url\u{7}b\u{1b}]8;;\u{7}\u{1b}[2Kc\u{1b}[0m";
+        assert_eq!(strip_ansi(text), "abc");
+        assert!(matches!(strip_ansi("abc"), Cow::Borrowed("abc")));
+        assert_eq!(strip_ansi("\u{1b}[1"), "");
+    }
+
+    #[test]
+    fn width() {
+        assert_eq!(visible_width(""), 0);
+        assert_eq!(visible_width("\u{1b}[1m\u{1b}[0m"), 0);
+        assert_eq!(visible_width("\u{1b}[38;5;1mhello\u{1b}[0m, wörld"), 12);
+        assert_eq!(visible_width("a\u{7}b\r"), 2);
+    }
+
+    #[test]
+    fn osc_sequences() {
+        let link = "\u{1b}]8;id=1;https://example.com\u{1b}\\docs\u{1b}]8;;\u{1b}\\";
+        let text = format!("\u{1b}]0;title\u{7}see {link}\u{1b}]4;1;rgb:ff/00/00\u{7}!");
+        assert_eq!(strip_ansi(&text), "see docs!");
+        assert_eq!(visible_width(&text), 9);
+        assert_eq!(strip_ansi("\u{1b}_Ga=T;AAAA\u{1b}\\img"), "img");
+        assert_eq!(visible_width("\u{1b}Pq#0;2;0;0;0\u{1b}\\"), 0);
+        assert_eq!(
+            slice_visible(&format!("x{link}"), 2..4),
+            "\u{1b}]8;id=1;https://example.com\u{1b}\\oc\u{1b}]8;;\u{1b}\\"
+        );
+        assert_eq!(slice_visible(&format!("{link}x"), 4..), "x");
+    }
+
+    #[test]
+    fn slices() {
+        let line = "ab\u{1b}[1mcd\u{1b}[31mef\u{1b}[0mgh";
+        assert_eq!(
+            slice_visible(line, ..),
+            "ab\u{1b}[1mcd\u{1b}[31mef\u{1b}[0mgh"
+        );
+        assert_eq!(slice_visible(line, 0..3), "ab\u{1b}[1mc\u{1b}[0m");
+        assert_eq!(slice_visible(line, 3..5), "\u{1b}[1md\u{1b}[31me\u{1b}[0m");
+        assert_eq!(slice_visible(line, 5..=6), "\u{1b}[1;38;5;1mf\u{1b}[0mg");
+        assert_eq!(slice_visible(line, 6..), "gh");
+        assert_eq!(slice_visible(line, 8..), "");
+        assert_eq!(slice_visible(line, 4..4), "");
+        assert_eq!(slice_visible("wörld", 1..3), "ör");
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn unicode_slices() {
+        assert_eq!(slice_visible("cafe\u{301}!", 3..), "e\u{301}!");
+        assert_eq!(slice_visible("日本語", 1..5), " 本 ");
+        assert_eq!(
+            slice_visible("\u{1b}[1m日本語", 2..4),
+            "\u{1b}[1m本\u{1b}[0m"
+        );
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn unicode_width() {
+        // `e` followed by a combining acute accent.
+        assert_eq!(visible_width("cafe\u{301}"), 4);
+        assert_eq!(visible_width("\u{1b}[1m日本語\u{1b}[0m"), 6);
+        assert_eq!(visible_width("👍🏽 ok"), 5);
+        // Family emoji joined with zero width joiners.
+        assert_eq!(
+            visible_width("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"),
+            2
+        );
+    }
+}
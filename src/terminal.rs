@@ -0,0 +1,454 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+use crate::{Ansi, IntoAnsi};
+
+static STYLE_ACTIVE: AtomicBool = AtomicBool::new(false);
+static CURSOR_HIDDEN: AtomicBool = AtomicBool::new(false);
+static ALTERNATE_SCREEN: AtomicBool = AtomicBool::new(false);
+
+/// Gets the size of the terminal as `(columns, rows)`, if it is known.
+///
+/// The size is asked from the operating system for whichever of stdout, stderr or stdin is
+/// attached to a terminal (an `ioctl` on Unix, the console API on Windows). When none of them
+/// are, e.g. when all output is piped, the `COLUMNS` and `LINES` environment variables are used
+/// instead, if both are set.
+///
+/// ## Example
+/// ```
+/// # use ansirs::terminal;
+/// let (columns, _rows) = terminal::size().unwrap_or((80, 24));
+/// # assert!(columns > 0);
+/// ```
+#[must_use]
+pub fn size() -> Option<(u16, u16)> {
+    sys::size().or_else(|| Some((env_size("COLUMNS")?, env_size("LINES")?)))
+}
+
+/// Gets the width of the terminal in columns, if it is known.
+///
+/// This is the same as the first value of [`size`], but falls back to `COLUMNS` on its own
+/// when the number of rows is not known.
+#[must_use]
+pub fn width() -> Option<u16> {
+    sys::size()
+        .map(|(columns, _)| columns)
+        .or_else(|| env_size("COLUMNS"))
+}
+
+/// Reads a size from the environment variable `name`.
+fn env_size(name: &str) -> Option<u16> {
+    parse_size(std::env::var(name).ok()?.as_str())
+}
+
+/// Parses a size from an environment variable, ignoring zero and invalid values.
+fn parse_size(value: &str) -> Option<u16> {
+    value.trim().parse().ok().filter(|&size| size > 0)
+}
+
+/// Starts styling everything written to `out` after it with `style`, by writing the escape
+/// sequence of `style`, e.g. to color a whole section of output written by other code. The
+/// style stays active until [`reset_style`] or [`restore`], or until the hooks of
+/// [`install_reset_on_exit`] and [`install_reset_on_panic`] end it. Setting the default style
+/// ends it too.
+///
+/// ## Errors
+/// Any error from writing to `out`.
+pub fn set_style(out: &mut impl Write, style: impl IntoAnsi) -> io::Result<()> {
+    let style = style.into_ansi();
+    if style.is_default() {
+        return reset_style(out);
+    }
+    // Reset first, so nothing bleeds from a style that is still active.
+    if STYLE_ACTIVE.load(Ordering::SeqCst) {
+        out.write_all(Ansi::reset().as_bytes())?;
+    }
+    write!(out, "{style}")?;
+    STYLE_ACTIVE.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Ends the style started by [`set_style`] by writing a full [reset](Ansi::reset) to `out`.
+///
+/// ## Errors
+/// Any error from writing to `out`.
+pub fn reset_style(out: &mut impl Write) -> io::Result<()> {
+    out.write_all(Ansi::reset().as_bytes())?;
+    STYLE_ACTIVE.store(false, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Hides the cursor by writing `ESC[?25l` to `out`. The cursor is shown again by
+/// [`show_cursor`], [`restore`], or the hooks of [`install_reset_on_exit`] and
+/// [`install_reset_on_panic`].
+///
+/// ## Errors
+/// Any error from writing to `out`.
+pub fn hide_cursor(out: &mut impl Write) -> io::Result<()> {
+    out.write_all(b"\x1b[?25l")?;
+    CURSOR_HIDDEN.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Shows the cursor again by writing `ESC[?25h` to `out`.
+///
+/// ## Errors
+/// Any error from writing to `out`.
+pub fn show_cursor(out: &mut impl Write) -> io::Result<()> {
+    out.write_all(b"\x1b[?25h")?;
+    CURSOR_HIDDEN.store(false, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Switches to the alternate screen by writing `ESC[?1049h` to `out`. The normal screen is
+/// brought back by [`leave_alternate_screen`], [`restore`], or the hooks of
+/// [`install_reset_on_exit`] and [`install_reset_on_panic`].
+///
+/// ## Errors
+/// Any error from writing to `out`.
+pub fn enter_alternate_screen(out: &mut impl Write) -> io::Result<()> {
+    out.write_all(b"\x1b[?1049h")?;
+    ALTERNATE_SCREEN.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Switches back to the normal screen by writing `ESC[?1049l` to `out`.
+///
+/// ## Errors
+/// Any error from writing to `out`.
+pub fn leave_alternate_screen(out: &mut impl Write) -> io::Result<()> {
+    out.write_all(b"\x1b[?1049l")?;
+    ALTERNATE_SCREEN.store(false, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Gets the escape sequence that undoes whatever of [`set_style`], [`hide_cursor`] and
+/// [`enter_alternate_screen`] is still in effect: a full [reset](Ansi::reset) of the text
+/// style, showing the cursor and leaving the alternate screen, in that order. This is empty
+/// when none of them is.
+///
+/// ## Example
+/// ```
+/// # use ansirs::terminal;
+/// assert_eq!(terminal::restore_sequence(), "");
+/// let mut out = Vec::new();
+/// terminal::hide_cursor(&mut out)?;
+/// assert_eq!(terminal::restore_sequence(), "\x1b[?25h");
+/// # terminal::show_cursor(&mut out)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[must_use]
+pub fn restore_sequence() -> String {
+    let mut sequence = String::new();
+    if STYLE_ACTIVE.load(Ordering::SeqCst) {
+        sequence.push_str(Ansi::reset());
+    }
+    if CURSOR_HIDDEN.load(Ordering::SeqCst) {
+        sequence.push_str("\x1b[?25h");
+    }
+    if ALTERNATE_SCREEN.load(Ordering::SeqCst) {
+        sequence.push_str("\x1b[?1049l");
+    }
+    sequence
+}
+
+/// Writes the [`restore_sequence`] to `out`, and marks the style as ended, the cursor as shown
+/// and the alternate screen as left. Nothing is written when there is nothing to restore.
+///
+/// ## Errors
+/// Any error from writing to `out`.
+pub fn restore(out: &mut impl Write) -> io::Result<()> {
+    let sequence = restore_sequence();
+    if sequence.is_empty() {
+        return Ok(());
+    }
+    out.write_all(sequence.as_bytes())?;
+    out.flush()?;
+    STYLE_ACTIVE.store(false, Ordering::SeqCst);
+    CURSOR_HIDDEN.store(false, Ordering::SeqCst);
+    ALTERNATE_SCREEN.store(false, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Restores whichever of stdout or stderr is a terminal, if anything needs restoring, ignoring
+/// errors since there is no one left to report them to.
+fn restore_terminal() {
+    if restore_sequence().is_empty() {
+        return;
+    }
+    if io::stdout().is_terminal() {
+        let _ = restore(&mut io::stdout());
+    } else if io::stderr().is_terminal() {
+        let _ = restore(&mut io::stderr());
+    }
+}
+
+/// Makes sure the terminal is [restored](restore) when the process exits, whether by returning
+/// from `main`, calling [`std::process::exit`] or panicking, so a crash does not leave the user
+/// with a style from [`set_style`], a hidden cursor or the alternate screen. This also installs
+/// the hook of [`install_reset_on_panic`]. Calling it more than once has no further effect.
+///
+/// Only what is still in effect is undone, so nothing is written at exit by a program that
+/// did not use those functions or already undid them. The terminal is restored through
+/// stdout, or stderr if only that is a terminal, and nothing is written when neither is.
+/// Exiting through a signal or [`std::process::abort`] skips the exit hook, but a panic still
+/// restores the terminal before the process aborts.
+///
+/// ## Example
+/// ```no_run
+/// # use ansirs::{install_reset_on_exit, terminal};
+/// install_reset_on_exit();
+/// terminal::enter_alternate_screen(&mut std::io::stdout())?;
+/// terminal::hide_cursor(&mut std::io::stdout())?;
+/// // ...
+/// std::process::exit(1); // The cursor and the normal screen come back.
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn install_reset_on_exit() {
+    /// Runs from the C runtime's `atexit`, where a panic would abort.
+    extern "C" fn on_exit() {
+        restore_terminal();
+    }
+
+    static INSTALLED: Once = Once::new();
+    install_reset_on_panic();
+    INSTALLED.call_once(|| sys::at_exit(on_exit));
+}
+
+/// Makes sure the terminal is [restored](restore) when any thread panics while a style from
+/// [`set_style`], a hidden cursor or the alternate screen is in effect. The previously
+/// installed panic hook runs first, then only what is still in effect is undone, so panics
+/// in programs that did not change the terminal write nothing extra. Calling it more than once
+/// has no further effect.
+///
+/// The hook can not know whether the panic is caught later with
+/// [`catch_unwind`](std::panic::catch_unwind), so a caught panic still ends the style, shows
+/// the cursor and leaves the alternate screen. A program that keeps running after catching
+/// one has to set up the terminal again, e.g. with [`enter_alternate_screen`].
+pub fn install_reset_on_panic() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            previous(info);
+            restore_terminal();
+        }));
+    });
+}
+
+#[cfg(unix)]
+mod sys {
+    use std::os::raw::{c_int, c_ulong};
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    const TIOCGWINSZ: c_ulong = 0x5413;
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "dragonfly"
+    ))]
+    const TIOCGWINSZ: c_ulong = 0x4008_7468;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct WinSize {
+        rows: u16,
+        columns: u16,
+        x_pixels: u16,
+        y_pixels: u16,
+    }
+
+    extern "C" {
+        fn ioctl(fd: c_int, request: c_ulong, ...) -> c_int;
+        fn atexit(callback: extern "C" fn()) -> c_int;
+    }
+
+    pub(super) fn at_exit(callback: extern "C" fn()) {
+        // SAFETY: `atexit` only stores the function pointer, which is valid for the whole
+        // program, and the callback does not call `exit` itself.
+        unsafe { atexit(callback) };
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "dragonfly"
+    ))]
+    pub(super) fn size() -> Option<(u16, u16)> {
+        // Stdout, stderr, then stdin.
+        [1, 2, 0].into_iter().find_map(|fd| {
+            let mut size = WinSize::default();
+            // SAFETY: `TIOCGWINSZ` only writes a `winsize` struct, which `WinSize` matches, to
+            // the pointer, and fails without touching it for descriptors that aren't terminals.
+            let result = unsafe { ioctl(fd, TIOCGWINSZ, std::ptr::addr_of_mut!(size)) };
+            (result == 0 && size.columns > 0 && size.rows > 0).then_some((size.columns, size.rows))
+        })
+    }
+
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "dragonfly"
+    )))]
+    pub(super) fn size() -> Option<(u16, u16)> {
+        None
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::os::raw::c_void;
+
+    // `(DWORD)-10`, `(DWORD)-11` and `(DWORD)-12`.
+    const STD_INPUT_HANDLE: u32 = 0xFFFF_FFF6;
+    const STD_OUTPUT_HANDLE: u32 = 0xFFFF_FFF5;
+    const STD_ERROR_HANDLE: u32 = 0xFFFF_FFF4;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct Coord {
+        x: i16,
+        y: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct SmallRect {
+        left: i16,
+        top: i16,
+        right: i16,
+        bottom: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct ConsoleScreenBufferInfo {
+        size: Coord,
+        cursor_position: Coord,
+        attributes: u16,
+        window: SmallRect,
+        maximum_window_size: Coord,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetStdHandle(handle: u32) -> *mut c_void;
+        fn GetConsoleScreenBufferInfo(
+            console: *mut c_void,
+            info: *mut ConsoleScreenBufferInfo,
+        ) -> i32;
+    }
+
+    extern "C" {
+        fn atexit(callback: extern "C" fn()) -> i32;
+    }
+
+    pub(super) fn at_exit(callback: extern "C" fn()) {
+        // SAFETY: The C runtime's `atexit` only stores the function pointer, which is valid for
+        // the whole program, and the callback does not call `exit` itself.
+        unsafe { atexit(callback) };
+    }
+
+    pub(super) fn size() -> Option<(u16, u16)> {
+        [STD_OUTPUT_HANDLE, STD_ERROR_HANDLE, STD_INPUT_HANDLE]
+            .into_iter()
+            .find_map(|handle| {
+                let mut info = ConsoleScreenBufferInfo::default();
+                // SAFETY: `GetStdHandle` has no preconditions, and `GetConsoleScreenBufferInfo`
+                // only writes to `info`, failing for handles that are not consoles.
+                let result = unsafe {
+                    GetConsoleScreenBufferInfo(GetStdHandle(handle), std::ptr::addr_of_mut!(info))
+                };
+                let window = info.window;
+                let columns = u16::try_from(window.right - window.left + 1).ok()?;
+                let rows = u16::try_from(window.bottom - window.top + 1).ok()?;
+                (result != 0 && columns > 0 && rows > 0).then_some((columns, rows))
+            })
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod sys {
+    pub(super) fn size() -> Option<(u16, u16)> {
+        None
+    }
+
+    pub(super) fn at_exit(_callback: extern "C" fn()) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_size("120"), Some(120));
+        assert_eq!(parse_size(" 80\n"), Some(80));
+        assert_eq!(parse_size("0"), None);
+        assert_eq!(parse_size("70000"), None);
+        assert_eq!(parse_size("wide"), None);
+        assert_eq!(parse_size(""), None);
+    }
+
+    #[test]
+    fn restoring() {
+        let mut out = Vec::new();
+        assert_eq!(restore_sequence(), "");
+        restore(&mut out).unwrap();
+        assert!(out.is_empty());
+
+        set_style(&mut out, Ansi::new().bold()).unwrap();
+        hide_cursor(&mut out).unwrap();
+        enter_alternate_screen(&mut out).unwrap();
+        assert_eq!(restore_sequence(), "\x1b[0m\x1b[?25h\x1b[?1049l");
+        restore(&mut out).unwrap();
+        assert_eq!(restore_sequence(), "");
+        assert_eq!(
+            out,
+            b"\x1b[1m\x1b[?25l\x1b[?1049h\x1b[0m\x1b[?25h\x1b[?1049l"
+        );
+
+        out.clear();
+        set_style(&mut out, Ansi::new().dim()).unwrap();
+        set_style(&mut out, Ansi::red()).unwrap();
+        set_style(&mut out, Ansi::new()).unwrap();
+        hide_cursor(&mut out).unwrap();
+        show_cursor(&mut out).unwrap();
+        enter_alternate_screen(&mut out).unwrap();
+        leave_alternate_screen(&mut out).unwrap();
+        assert_eq!(
+            out,
+            b"\x1b[2m\x1b[0m\x1b[38;2;255;0;0m\x1b[0m\x1b[?25l\x1b[?25h\x1b[?1049h\x1b[?1049l"
+        );
+        assert_eq!(restore_sequence(), "");
+    }
+
+    #[test]
+    fn width_matches_size() {
+        if let Some((columns, _)) = sys::size() {
+            assert_eq!(width(), Some(columns));
+            assert_eq!(size().map(|(columns, _)| columns), Some(columns));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use crate::{debug_escapes, AnsiTokenizer, Token};
+
+/// Asserts that two styled strings are equal. Both arguments can be anything implementing
+/// [`ToString`].
+///
+/// On failure, both values are printed with their escape sequences made visible and again
+/// with every escape sequence decoded by [`debug_escapes`], followed by a description of each
+/// sequence, which is a lot easier to read than the default output of [`assert_eq!`].
+///
+/// ## Example
+/// ```
+/// # use ansirs::{assert_styled_eq, style_text, Ansi};
+/// assert_styled_eq!(style_text("hi", Ansi::new().bold()), "\x1b[1mhi\x1b[0m");
+/// ```
+#[macro_export]
+macro_rules! assert_styled_eq {
+    ($actual:expr, $expected:expr $(,)?) => {{
+        let actual = ::std::string::ToString::to_string(&$actual);
+        let expected = ::std::string::ToString::to_string(&$expected);
+        if actual != expected {
+            ::std::panic!(
+                "{}",
+                $crate::test_util::styled_mismatch(&actual, &expected, None)
+            );
+        }
+    }};
+    ($actual:expr, $expected:expr, $($arg:tt)+) => {{
+        let actual = ::std::string::ToString::to_string(&$actual);
+        let expected = ::std::string::ToString::to_string(&$expected);
+        if actual != expected {
+            ::std::panic!(
+                "{}",
+                $crate::test_util::styled_mismatch(
+                    &actual,
+                    &expected,
+                    Some(::std::format!($($arg)+))
+                )
+            );
+        }
+    }};
+}
+
+/// Replaces the escape character in `text` with `\e` (and a literal backslash with `\\`) so
+/// that escape sequences are visible when printed.
+#[must_use]
+pub fn visible_escapes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 8);
+    for c in text.chars() {
+        match c {
+            '\x1b' => out.push_str("\\e"),
+            '\\' => out.push_str("\\\\"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Decodes every escape sequence in `text`, returning a line for each sequence in the form
+/// `\e[1;31m = ⟨bold red⟩`.
+#[must_use]
+pub fn describe_styles(text: &str) -> Vec<String> {
+    AnsiTokenizer::new(text)
+        .filter(|token| !token.is_text())
+        .map(|token| match token {
+            Token::Esc(sequence) => format!("{} = <unterminated>", visible_escapes(sequence)),
+            sequence => format!(
+                "{} = {}",
+                visible_escapes(sequence.as_str()),
+                debug_escapes(sequence.as_str())
+            ),
+        })
+        .collect()
+}
+
+/// Builds the failure message used by [`assert_styled_eq!`].
+#[doc(hidden)]
+#[must_use]
+pub fn styled_mismatch(actual: &str, expected: &str, message: Option<String>) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::from("assertion `actual == expected` failed");
+    if let Some(message) = message {
+        write!(out, ": {message}").expect("Failed to write! to string");
+    }
+    writeln!(out).expect("Failed to write! to string");
+    for (label, value) in [("actual", actual), ("expected", expected)] {
+        writeln!(out, "{label:>9}: {}", visible_escapes(value))
+            .expect("Failed to write! to string");
+        if value.contains('\x1b') {
+            writeln!(out, "{:>9}: {}", "decoded", debug_escapes(value))
+                .expect("Failed to write! to string");
+        }
+        for description in describe_styles(value) {
+            writeln!(out, "{:>9}  {description}", "").expect("Failed to write! to string");
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Ansi;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn visible() {
+        assert_eq!(
+            visible_escapes("\u{1b}[1mbold\\\u{1b}[0m"),
+            "\\e[1mbold\\\\\\e[0m"
+        );
+    }
+
+    #[test]
+    fn describe() {
+        let styled = Ansi::new().bold().paint_text("x");
+        assert_eq!(
+            describe_styles(&styled),
+            vec![
+                "\\e[1m = ⟨bold⟩".to_string(),
+                "\\e[0m = ⟨reset⟩".to_string(),
+            ]
+        );
+        assert!(describe_styles("plain").is_empty());
+    }
+
+    #[test]
+    fn passing_assertion() {
+        crate::assert_styled_eq!(Ansi::new().bold().paint_text("x"), "\u{1b}[1mx\u{1b}[0m");
+        crate::assert_styled_eq!("same", "same", "with a message {}", 1);
+    }
+
+    #[test]
+    fn failing_assertion() {
+        let result = std::panic::catch_unwind(|| {
+            crate::assert_styled_eq!(Ansi::new().bold().paint_text("x"), "x", "case {}", 7);
+        });
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("assertion `actual == expected` failed: case 7\n"));
+        assert!(message.contains("   actual: \\e[1mx\\e[0m\n"));
+        assert!(message.contains("expected: x\n"));
+        assert!(message.contains("  decoded: ⟨bold⟩x⟨reset⟩\n"));
+        assert!(message.contains("\\e[1m = ⟨bold⟩"));
+    }
+}
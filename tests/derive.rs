@@ -0,0 +1,46 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use ansirs::{Ansi, Theme};
+use pretty_assertions::assert_eq;
+
+#[derive(Debug, PartialEq, Theme)]
+struct AppTheme {
+    #[style("bold red on black")]
+    error: Ansi,
+    #[style("italic #ff8800")]
+    derive_test_warning: Ansi,
+    plain: Ansi,
+}
+
+#[test]
+fn defaults() {
+    let theme = AppTheme::default();
+    assert_eq!(theme.error, Ansi::new().fg_indexed(1).bg_indexed(0).bold());
+    assert_eq!(
+        theme.derive_test_warning,
+        Ansi::from_fg((255, 136, 0)).italic()
+    );
+    assert_eq!(theme.plain, Ansi::new());
+}
+
+#[test]
+fn into_theme() {
+    let theme = Theme::from(&AppTheme::default());
+    assert_eq!(
+        theme.iter().map(|(key, _)| key).collect::<Vec<_>>(),
+        vec!["error", "derive_test_warning", "plain"]
+    );
+    assert_eq!(theme.get("plain"), Some(Ansi::new()));
+}
+
+#[test]
+fn env_overrides() {
+    std::env::set_var("ANSIRS_STYLE_DERIVE_TEST_WARNING", "underline");
+    let theme = AppTheme::default().env_overrides();
+    assert_eq!(theme.derive_test_warning, Ansi::new().underline());
+    assert_eq!(theme.error, Ansi::new().fg_indexed(1).bg_indexed(0).bold());
+}
@@ -0,0 +1,82 @@
+// Copyright (c) 2022 Tony Barbitta
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Property tests making sure that styles survive being written out and parsed back in.
+
+use ansirs::{from_tags, to_tags, Ansi, AnsiColor, Color};
+use proptest::prelude::*;
+
+fn any_color() -> impl Strategy<Value = Option<AnsiColor>> {
+    prop_oneof![
+        Just(None),
+        any::<u8>().prop_map(|idx| Some(AnsiColor::Indexed(idx))),
+        any::<(u8, u8, u8)>().prop_map(|(r, g, b)| Some(AnsiColor::Rgb(Color::from_rgb(r, g, b)))),
+    ]
+}
+
+fn any_ansi() -> impl Strategy<Value = Ansi> {
+    (
+        any_color(),
+        any_color(),
+        any_color(),
+        any::<[bool; 8]>(),
+        0..=10u8,
+    )
+        .prop_map(|(fg, bg, ul, flags, font)| {
+            let mut ansi = Ansi::new().font(font);
+            ansi = match fg {
+                Some(AnsiColor::Indexed(idx)) => ansi.fg_indexed(idx),
+                Some(AnsiColor::Rgb(color)) => ansi.fg(color),
+                None => ansi,
+            };
+            ansi = match bg {
+                Some(AnsiColor::Indexed(idx)) => ansi.bg_indexed(idx),
+                Some(AnsiColor::Rgb(color)) => ansi.bg(color),
+                None => ansi,
+            };
+            ansi = match ul {
+                Some(AnsiColor::Indexed(idx)) => ansi.ul_indexed(idx),
+                Some(AnsiColor::Rgb(color)) => ansi.ul(color),
+                None => ansi,
+            };
+            let builders: [fn(Ansi) -> Ansi; 8] = [
+                Ansi::bold,
+                Ansi::dim,
+                Ansi::italic,
+                Ansi::underline,
+                Ansi::blink,
+                Ansi::reverse,
+                Ansi::strike,
+                Ansi::conceal,
+            ];
+            for (set, builder) in flags.into_iter().zip(builders) {
+                if set {
+                    ansi = builder(ansi);
+                }
+            }
+            ansi
+        })
+}
+
+proptest! {
+    #[test]
+    fn display_then_parse(style in any_ansi()) {
+        prop_assert_eq!(Ansi::parse(&style.to_string()), Ok(style));
+    }
+
+    #[test]
+    fn later_styles_replace_earlier_ones(first in any_ansi(), second in any_ansi()) {
+        let text = format!("{first}{}{second}", Ansi::reset());
+        prop_assert_eq!(Ansi::parse(&text), Ok(second));
+    }
+
+    #[test]
+    fn painted_text_survives_tags(style in any_ansi(), text in "[a-z <]{1,12}") {
+        let painted = style.paint_text(&text);
+        let restored = from_tags(&to_tags(&painted));
+        prop_assert_eq!(to_tags(&restored), to_tags(&painted));
+    }
+}